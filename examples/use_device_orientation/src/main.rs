@@ -1,22 +1,38 @@
 use leptos::prelude::*;
 use leptos_use::docs::demo_or_body;
-use leptos_use::use_device_orientation;
+use leptos_use::{use_device_orientation, PermissionState};
 
 #[component]
 fn Demo() -> impl IntoView {
     let orientation = use_device_orientation();
 
+    let enable = {
+        let request_permission = orientation.request_permission.clone();
+
+        move |_| {
+            let request_permission = request_permission.clone();
+            leptos::task::spawn_local(async move {
+                request_permission().await;
+            });
+        }
+    };
+
     view! {
+    <Show when=move || orientation.permission.get() == PermissionState::Prompt>
+        <button on:click=enable.clone()>Enable Device Orientation</button>
+    </Show>
     <pre>
         {move || format!(
             concat!(
                 "is_supported: {}\n",
+                "permission: {}\n",
                 "absolute: {}\n",
                 "alpha: {:?}\n",
                 "beta: {:?}\n",
                 "gamma: {:?}\n",
             ),
             orientation.is_supported.get(),
+            orientation.permission.get(),
             orientation.absolute.get(),
             orientation.alpha.get(),
             orientation.beta.get(),
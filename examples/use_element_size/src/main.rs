@@ -7,7 +7,7 @@ use leptos_use::{use_element_size, UseElementSizeReturn};
 fn Demo() -> impl IntoView {
     let el = NodeRef::<Textarea>::new();
 
-    let UseElementSizeReturn { width, height } = use_element_size(el);
+    let UseElementSizeReturn { width, height, .. } = use_element_size(el);
 
     let text = move || format!("width: {}\nheight: {}", width.get(), height.get());
 
@@ -10,6 +10,7 @@ fn Demo() -> impl IntoView {
         error,
         resume,
         pause,
+        ..
     } = use_geolocation();
 
     view! {
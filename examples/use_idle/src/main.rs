@@ -5,7 +5,10 @@ use leptos_use::{use_idle, use_timestamp_with_options, UseIdleReturn, UseTimesta
 #[component]
 fn Demo() -> impl IntoView {
     let UseIdleReturn {
-        idle, last_active, ..
+        idle,
+        last_active,
+        seconds_until_idle,
+        ..
     } = use_idle(5000);
 
     let now = use_timestamp_with_options(UseTimestampOptions::default().interval(1000));
@@ -18,7 +21,8 @@ fn Demo() -> impl IntoView {
             in this demo (default 1min).
         </Note>
         <div class="mb-2">Idle: <BooleanDisplay value=idle/></div>
-        <div>Inactive: <b>{idled_for} s</b></div>
+        <div class="mb-2">Inactive: <b>{idled_for} s</b></div>
+        <div>Idle in: <b>{move || seconds_until_idle.get().ceil()} s</b></div>
     }
 }
 
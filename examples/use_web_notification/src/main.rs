@@ -1,14 +1,18 @@
 use leptos::prelude::*;
 use leptos_use::docs::{demo_or_body, BooleanDisplay};
 use leptos_use::{
-    use_web_notification_with_options, NotificationDirection, ShowOptions,
-    UseWebNotificationOptions, UseWebNotificationReturn,
+    use_web_notification_with_options, NotificationDirection, NotificationPermission,
+    ShowOptions, UseWebNotificationOptions, UseWebNotificationReturn,
 };
 
 #[component]
 fn Demo() -> impl IntoView {
     let UseWebNotificationReturn {
-        is_supported, show, ..
+        is_supported,
+        show,
+        permission,
+        request_permission,
+        ..
     } = use_web_notification_with_options(
         UseWebNotificationOptions::default()
             .title("Hello World from leptos-use")
@@ -22,9 +26,17 @@ fn Demo() -> impl IntoView {
         show(ShowOptions::default());
     };
 
+    let enable = move |_| {
+        let request_permission = request_permission.clone();
+        leptos::task::spawn_local(async move {
+            request_permission().await;
+        });
+    };
+
     view! {
         <div>
             <p>Supported: <BooleanDisplay value=is_supported/></p>
+            <p>Permission: {move || format!("{:?}", permission.get())}</p>
         </div>
 
         <Show
@@ -34,6 +46,10 @@ fn Demo() -> impl IntoView {
             }
         >
 
+            <Show when=move || permission.get() != NotificationPermission::Granted>
+                <button on:click=enable.clone()>Enable Notifications</button>
+            </Show>
+
             <button on:click={
                 let show = show.clone();
                 move |_| show()
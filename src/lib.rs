@@ -36,20 +36,46 @@ mod signal_throttled;
 mod sync_signal;
 #[cfg(feature = "use_active_element")]
 mod use_active_element;
+#[cfg(feature = "use_ambient_light_sensor")]
+mod use_ambient_light_sensor;
+#[cfg(feature = "use_array_filter")]
+mod use_array_filter;
+#[cfg(feature = "use_array_find")]
+mod use_array_find;
+#[cfg(feature = "use_array_map")]
+mod use_array_map;
+#[cfg(feature = "use_array_reduce")]
+mod use_array_reduce;
+#[cfg(feature = "use_array_unique")]
+mod use_array_unique;
+#[cfg(feature = "use_base64")]
+mod use_base64;
 #[cfg(feature = "use_breakpoints")]
 mod use_breakpoints;
 #[cfg(feature = "use_broadcast_channel")]
 mod use_broadcast_channel;
+#[cfg(feature = "use_cached")]
+mod use_cached;
 #[cfg(feature = "use_calendar")]
 mod use_calendar;
 #[cfg(feature = "use_clipboard")]
 mod use_clipboard;
+#[cfg(feature = "use_clipboard_items")]
+mod use_clipboard_items;
 #[cfg(feature = "use_color_mode")]
 mod use_color_mode;
+#[cfg(feature = "use_confirm_dialog")]
+mod use_confirm_dialog;
 #[cfg(feature = "use_cookie")]
 mod use_cookie;
+#[cfg(feature = "use_cookie_store")]
+mod use_cookie_store;
+#[cfg(feature = "use_countdown")]
+mod use_countdown;
 #[cfg(feature = "use_css_var")]
 mod use_css_var;
+#[cfg(feature = "use_css_vars")]
+mod use_css_vars;
 #[cfg(feature = "use_cycle_list")]
 mod use_cycle_list;
 #[cfg(feature = "use_debounce_fn")]
@@ -58,6 +84,8 @@ mod use_debounce_fn;
 mod use_device_orientation;
 #[cfg(feature = "use_device_pixel_ratio")]
 mod use_device_pixel_ratio;
+#[cfg(feature = "use_devices_list")]
+mod use_devices_list;
 #[cfg(feature = "use_display_media")]
 mod use_display_media;
 #[cfg(feature = "use_document")]
@@ -66,10 +94,16 @@ mod use_document;
 mod use_document_visibility;
 #[cfg(feature = "use_draggable")]
 mod use_draggable;
+#[cfg(feature = "use_draggable_html5")]
+mod use_draggable_html5;
 #[cfg(feature = "use_drop_zone")]
 mod use_drop_zone;
+#[cfg(feature = "use_droppable")]
+mod use_droppable;
 #[cfg(feature = "use_element_bounding")]
 mod use_element_bounding;
+#[cfg(feature = "use_element_by_id")]
+mod use_element_by_id;
 #[cfg(feature = "use_element_hover")]
 mod use_element_hover;
 #[cfg(feature = "use_element_size")]
@@ -82,8 +116,22 @@ mod use_event_listener;
 mod use_event_source;
 #[cfg(feature = "use_favicon")]
 mod use_favicon;
+#[cfg(feature = "use_file_dialog")]
+mod use_file_dialog;
+#[cfg(feature = "use_file_system_access")]
+mod use_file_system_access;
+#[cfg(feature = "use_focus")]
+mod use_focus;
+#[cfg(feature = "use_focus_within")]
+mod use_focus_within;
+#[cfg(feature = "use_forced_colors")]
+mod use_forced_colors;
+#[cfg(feature = "use_fps")]
+mod use_fps;
 #[cfg(feature = "use_geolocation")]
 mod use_geolocation;
+#[cfg(feature = "use_hash")]
+mod use_hash;
 #[cfg(feature = "use_idle")]
 mod use_idle;
 #[cfg(feature = "use_infinite_scroll")]
@@ -102,51 +150,91 @@ mod use_locale;
 mod use_locales;
 #[cfg(feature = "use_media_query")]
 mod use_media_query;
+#[cfg(feature = "use_memory")]
+mod use_memory;
 #[cfg(feature = "use_mouse")]
 mod use_mouse;
 #[cfg(feature = "use_mouse_in_element")]
 mod use_mouse_in_element;
 #[cfg(feature = "use_mutation_observer")]
 mod use_mutation_observer;
+#[cfg(feature = "use_object_url")]
+mod use_object_url;
+#[cfg(feature = "use_offset_pagination")]
+mod use_offset_pagination;
 #[cfg(feature = "use_permission")]
 mod use_permission;
+#[cfg(feature = "use_permissions")]
+mod use_permissions;
+#[cfg(feature = "use_pointer_lock")]
+mod use_pointer_lock;
 #[cfg(feature = "use_preferred_contrast")]
 mod use_preferred_contrast;
 #[cfg(feature = "use_preferred_dark")]
 mod use_preferred_dark;
+#[cfg(feature = "use_prefers_reduced_data")]
+mod use_prefers_reduced_data;
 #[cfg(feature = "use_prefers_reduced_motion")]
 mod use_prefers_reduced_motion;
+#[cfg(feature = "use_prefers_reduced_transparency")]
+mod use_prefers_reduced_transparency;
 #[cfg(feature = "use_raf_fn")]
 mod use_raf_fn;
 #[cfg(feature = "use_resize_observer")]
 mod use_resize_observer;
+#[cfg(feature = "use_screen_orientation")]
+mod use_screen_orientation;
 #[cfg(feature = "use_scroll")]
 mod use_scroll;
+#[cfg(feature = "use_scroll_hide_on_scroll")]
+mod use_scroll_hide_on_scroll;
+#[cfg(feature = "use_scroll_spy")]
+mod use_scroll_spy;
 #[cfg(feature = "use_service_worker")]
 mod use_service_worker;
+#[cfg(feature = "use_sortable")]
+mod use_sortable;
 #[cfg(feature = "use_sorted")]
 mod use_sorted;
+#[cfg(feature = "use_stepper")]
+mod use_stepper;
 #[cfg(feature = "use_supported")]
 mod use_supported;
 #[cfg(feature = "use_textarea_autosize")]
 mod use_textarea_autosize;
 #[cfg(feature = "use_throttle_fn")]
 mod use_throttle_fn;
+#[cfg(feature = "use_timeago")]
+mod use_timeago;
 #[cfg(feature = "use_timeout_fn")]
 mod use_timeout_fn;
 #[cfg(feature = "use_timestamp")]
 mod use_timestamp;
+#[cfg(feature = "use_title")]
+mod use_title;
 #[cfg(feature = "use_to_string")]
 mod use_to_string;
 #[cfg(feature = "use_toggle")]
 mod use_toggle;
+#[cfg(feature = "use_toggle_persisted")]
+mod use_toggle_persisted;
+#[cfg(feature = "use_touches")]
+mod use_touches;
+#[cfg(feature = "use_transition")]
+mod use_transition;
+#[cfg(feature = "use_url_search_params")]
+mod use_url_search_params;
 #[cfg(feature = "use_user_media")]
 mod use_user_media;
+#[cfg(feature = "use_virtual_list")]
+mod use_virtual_list;
 #[cfg(feature = "use_web_lock")]
 #[cfg(web_sys_unstable_apis)]
 mod use_web_lock;
 #[cfg(feature = "use_web_notification")]
 mod use_web_notification;
+#[cfg(feature = "use_web_worker")]
+mod use_web_worker;
 #[cfg(feature = "use_websocket")]
 mod use_websocket;
 #[cfg(feature = "use_window")]
@@ -186,20 +274,46 @@ pub use signal_throttled::*;
 pub use sync_signal::*;
 #[cfg(feature = "use_active_element")]
 pub use use_active_element::*;
+#[cfg(feature = "use_ambient_light_sensor")]
+pub use use_ambient_light_sensor::*;
+#[cfg(feature = "use_array_filter")]
+pub use use_array_filter::*;
+#[cfg(feature = "use_array_find")]
+pub use use_array_find::*;
+#[cfg(feature = "use_array_map")]
+pub use use_array_map::*;
+#[cfg(feature = "use_array_reduce")]
+pub use use_array_reduce::*;
+#[cfg(feature = "use_array_unique")]
+pub use use_array_unique::*;
+#[cfg(feature = "use_base64")]
+pub use use_base64::*;
 #[cfg(feature = "use_breakpoints")]
 pub use use_breakpoints::*;
 #[cfg(feature = "use_broadcast_channel")]
 pub use use_broadcast_channel::*;
+#[cfg(feature = "use_cached")]
+pub use use_cached::*;
 #[cfg(feature = "use_calendar")]
 pub use use_calendar::*;
 #[cfg(feature = "use_clipboard")]
 pub use use_clipboard::*;
+#[cfg(feature = "use_clipboard_items")]
+pub use use_clipboard_items::*;
 #[cfg(feature = "use_color_mode")]
 pub use use_color_mode::*;
+#[cfg(feature = "use_confirm_dialog")]
+pub use use_confirm_dialog::*;
 #[cfg(feature = "use_cookie")]
 pub use use_cookie::*;
+#[cfg(feature = "use_cookie_store")]
+pub use use_cookie_store::*;
+#[cfg(feature = "use_countdown")]
+pub use use_countdown::*;
 #[cfg(feature = "use_css_var")]
 pub use use_css_var::*;
+#[cfg(feature = "use_css_vars")]
+pub use use_css_vars::*;
 #[cfg(feature = "use_cycle_list")]
 pub use use_cycle_list::*;
 #[cfg(feature = "use_debounce_fn")]
@@ -208,6 +322,8 @@ pub use use_debounce_fn::*;
 pub use use_device_orientation::*;
 #[cfg(feature = "use_device_pixel_ratio")]
 pub use use_device_pixel_ratio::*;
+#[cfg(feature = "use_devices_list")]
+pub use use_devices_list::*;
 #[cfg(feature = "use_display_media")]
 pub use use_display_media::*;
 #[cfg(feature = "use_document")]
@@ -216,10 +332,16 @@ pub use use_document::*;
 pub use use_document_visibility::*;
 #[cfg(feature = "use_draggable")]
 pub use use_draggable::*;
+#[cfg(feature = "use_draggable_html5")]
+pub use use_draggable_html5::*;
 #[cfg(feature = "use_drop_zone")]
 pub use use_drop_zone::*;
+#[cfg(feature = "use_droppable")]
+pub use use_droppable::*;
 #[cfg(feature = "use_element_bounding")]
 pub use use_element_bounding::*;
+#[cfg(feature = "use_element_by_id")]
+pub use use_element_by_id::*;
 #[cfg(feature = "use_element_hover")]
 pub use use_element_hover::*;
 #[cfg(feature = "use_element_size")]
@@ -232,8 +354,22 @@ pub use use_event_listener::*;
 pub use use_event_source::*;
 #[cfg(feature = "use_favicon")]
 pub use use_favicon::*;
+#[cfg(feature = "use_file_dialog")]
+pub use use_file_dialog::*;
+#[cfg(feature = "use_file_system_access")]
+pub use use_file_system_access::*;
+#[cfg(feature = "use_focus")]
+pub use use_focus::*;
+#[cfg(feature = "use_focus_within")]
+pub use use_focus_within::*;
+#[cfg(feature = "use_forced_colors")]
+pub use use_forced_colors::*;
+#[cfg(feature = "use_fps")]
+pub use use_fps::*;
 #[cfg(feature = "use_geolocation")]
 pub use use_geolocation::*;
+#[cfg(feature = "use_hash")]
+pub use use_hash::*;
 #[cfg(feature = "use_idle")]
 pub use use_idle::*;
 #[cfg(feature = "use_infinite_scroll")]
@@ -252,51 +388,91 @@ pub use use_locale::*;
 pub use use_locales::*;
 #[cfg(feature = "use_media_query")]
 pub use use_media_query::*;
+#[cfg(feature = "use_memory")]
+pub use use_memory::*;
 #[cfg(feature = "use_mouse")]
 pub use use_mouse::*;
 #[cfg(feature = "use_mouse_in_element")]
 pub use use_mouse_in_element::*;
 #[cfg(feature = "use_mutation_observer")]
 pub use use_mutation_observer::*;
+#[cfg(feature = "use_object_url")]
+pub use use_object_url::*;
+#[cfg(feature = "use_offset_pagination")]
+pub use use_offset_pagination::*;
 #[cfg(feature = "use_permission")]
 pub use use_permission::*;
+#[cfg(feature = "use_permissions")]
+pub use use_permissions::*;
+#[cfg(feature = "use_pointer_lock")]
+pub use use_pointer_lock::*;
 #[cfg(feature = "use_preferred_contrast")]
 pub use use_preferred_contrast::*;
 #[cfg(feature = "use_preferred_dark")]
 pub use use_preferred_dark::*;
+#[cfg(feature = "use_prefers_reduced_data")]
+pub use use_prefers_reduced_data::*;
 #[cfg(feature = "use_prefers_reduced_motion")]
 pub use use_prefers_reduced_motion::*;
+#[cfg(feature = "use_prefers_reduced_transparency")]
+pub use use_prefers_reduced_transparency::*;
 #[cfg(feature = "use_raf_fn")]
 pub use use_raf_fn::*;
 #[cfg(feature = "use_resize_observer")]
 pub use use_resize_observer::*;
+#[cfg(feature = "use_screen_orientation")]
+pub use use_screen_orientation::*;
 #[cfg(feature = "use_scroll")]
 pub use use_scroll::*;
+#[cfg(feature = "use_scroll_hide_on_scroll")]
+pub use use_scroll_hide_on_scroll::*;
+#[cfg(feature = "use_scroll_spy")]
+pub use use_scroll_spy::*;
 #[cfg(feature = "use_service_worker")]
 pub use use_service_worker::*;
+#[cfg(feature = "use_sortable")]
+pub use use_sortable::*;
 #[cfg(feature = "use_sorted")]
 pub use use_sorted::*;
+#[cfg(feature = "use_stepper")]
+pub use use_stepper::*;
 #[cfg(feature = "use_supported")]
 pub use use_supported::*;
 #[cfg(feature = "use_textarea_autosize")]
 pub use use_textarea_autosize::*;
 #[cfg(feature = "use_throttle_fn")]
 pub use use_throttle_fn::*;
+#[cfg(feature = "use_timeago")]
+pub use use_timeago::*;
 #[cfg(feature = "use_timeout_fn")]
 pub use use_timeout_fn::*;
 #[cfg(feature = "use_timestamp")]
 pub use use_timestamp::*;
+#[cfg(feature = "use_title")]
+pub use use_title::*;
 #[cfg(feature = "use_to_string")]
 pub use use_to_string::*;
 #[cfg(feature = "use_toggle")]
 pub use use_toggle::*;
+#[cfg(feature = "use_toggle_persisted")]
+pub use use_toggle_persisted::*;
+#[cfg(feature = "use_touches")]
+pub use use_touches::*;
+#[cfg(feature = "use_transition")]
+pub use use_transition::*;
+#[cfg(feature = "use_url_search_params")]
+pub use use_url_search_params::*;
 #[cfg(feature = "use_user_media")]
 pub use use_user_media::*;
+#[cfg(feature = "use_virtual_list")]
+pub use use_virtual_list::*;
 #[cfg(feature = "use_web_lock")]
 #[cfg(web_sys_unstable_apis)]
 pub use use_web_lock::*;
 #[cfg(feature = "use_web_notification")]
 pub use use_web_notification::*;
+#[cfg(feature = "use_web_worker")]
+pub use use_web_worker::*;
 #[cfg(feature = "use_websocket")]
 pub use use_websocket::*;
 #[cfg(feature = "use_window")]
@@ -1,4 +1,4 @@
-use crate::utils::{signal_filtered, signal_filtered_local};
+use crate::utils::{signal_filtered, signal_filtered_local, signal_filtered_with_controls};
 use crate::{use_debounce_fn_with_options, DebounceOptions};
 use leptos::prelude::*;
 use leptos::reactive::wrappers::read::Signal;
@@ -60,6 +60,9 @@ signal_filtered!(
     /// # }
     /// ```
     ///
+    /// To know whether a change is still pending, e.g. to show a "typing..." indicator, or to apply
+    /// or drop a pending change manually, use [`signal_debounced_with_controls`].
+    ///
     /// ## Recommended Reading
     ///
     /// - [**Debounce vs Throttle**: Definitive Visual Guide](https://redd.one/blog/debounce-vs-throttle)
@@ -68,7 +71,7 @@ signal_filtered!(
     /// ## Server-Side Rendering
     ///
     /// Internally this uses `setTimeout` which is not supported on the server. So usually
-    /// a throttled signal on the server will simply be ignored.
+    /// a debounced signal on the server will simply be ignored.
     debounce
     /// [`signal_debounced`]
     /// [`DebounceOptions`]
@@ -82,3 +85,51 @@ signal_filtered_local!(
     /// [`signal_debounced_local`]
     /// [`DebounceOptions`]
 );
+
+signal_filtered_with_controls!(
+    /// Debounce changing of a `Signal` value and tell if a change is pending.
+    ///
+    /// Like [`signal_debounced`] but it also returns `is_pending` which is `true` from the first
+    /// change of the source until the debounced value has settled. This way you can show an
+    /// indicator while the user is still typing. A pending change can also be applied right away
+    /// with `flush` or dropped with `cancel`.
+    ///
+    /// ## Usage
+    ///
+    /// ```
+    /// # use leptos::prelude::*;
+    /// # use leptos_use::{signal_debounced_with_controls, SignalDebouncedReturn};
+    /// #
+    /// # #[component]
+    /// # fn Demo() -> impl IntoView {
+    /// let (input, set_input) = signal(String::new());
+    ///
+    /// let SignalDebouncedReturn {
+    ///     value: search,
+    ///     is_pending,
+    ///     cancel,
+    ///     ..
+    /// } = signal_debounced_with_controls::<_, String>(input, 500.0);
+    ///
+    /// view! {
+    ///     <input prop:value=input on:input=move |ev| set_input.set(event_target_value(&ev)) />
+    ///     <Show when=move || is_pending.get()>
+    ///         <span>"typing..."</span>
+    ///     </Show>
+    ///     <button on:click=move |_| cancel()>"Cancel"</button>
+    ///     <p>"Searching for " {search}</p>
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// The option `max_wait` is available through [`signal_debounced_with_controls_and_options`].
+    /// Use `*_local` variants for values that are not `Send + Sync`.
+    ///
+    /// ## Server-Side Rendering
+    ///
+    /// Internally this uses `setTimeout` which is not supported on the server. So usually
+    /// changes on the server are simply ignored. `flush` and `cancel` can be called safely.
+    debounce
+    /// [`signal_debounced_with_controls`]
+    /// [`DebounceOptions`]
+);
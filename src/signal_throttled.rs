@@ -1,4 +1,4 @@
-use crate::utils::{signal_filtered, signal_filtered_local};
+use crate::utils::{signal_filtered, signal_filtered_local, signal_filtered_with_controls};
 use crate::{use_throttle_fn_with_options, ThrottleOptions};
 use leptos::prelude::*;
 use leptos::reactive::wrappers::read::Signal;
@@ -60,6 +60,9 @@ signal_filtered!(
     /// # }
     /// ```
     ///
+    /// If you need to apply or drop a pending change manually, use
+    /// [`signal_throttled_with_controls`].
+    ///
     /// ## Recommended Reading
     ///
     /// - [**Debounce vs Throttle**: Definitive Visual Guide](https://redd.one/blog/debounce-vs-throttle)
@@ -82,3 +85,56 @@ signal_filtered_local!(
     /// [`signal_throttled_local`]
     /// [`ThrottledOptions`]
 );
+
+signal_filtered_with_controls!(
+    /// Throttle changing of a `Signal` value that can be flushed and cancelled.
+    ///
+    /// Like [`signal_throttled`] but with escape hatches. `flush` applies a pending change right
+    /// away and `cancel` drops it. `is_pending` is `true` while a change of the source hasn't been
+    /// applied yet.
+    ///
+    /// ## Usage
+    ///
+    /// ```
+    /// # use leptos::prelude::*;
+    /// # use leptos_use::{signal_throttled_with_controls, SignalThrottledReturn};
+    /// #
+    /// # #[component]
+    /// # fn Demo() -> impl IntoView {
+    /// let (position, set_position) = signal((0.0, 0.0));
+    ///
+    /// let SignalThrottledReturn {
+    ///     value: throttled_position,
+    ///     flush,
+    ///     ..
+    /// } = signal_throttled_with_controls(position, 100.0);
+    ///
+    /// Effect::new(move || {
+    ///     let (x, y) = throttled_position.get();
+    ///     // send the position to the server
+    /// });
+    ///
+    /// view! {
+    ///     <div
+    ///         on:pointermove=move |ev| set_position.set((ev.client_x() as f64, ev.client_y() as f64))
+    ///         // make sure the last position isn't lost inside the throttle window
+    ///         on:pointerup=move |_| flush()
+    ///     >
+    ///         "Drag me"
+    ///     </div>
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// The options `leading` and `trailing` are available through
+    /// [`signal_throttled_with_controls_and_options`]. Use `*_local` variants for values that are
+    /// not `Send + Sync`.
+    ///
+    /// ## Server-Side Rendering
+    ///
+    /// Internally this uses `setTimeout` which is not supported on the server. On the server only
+    /// leading changes are applied. `flush` and `cancel` can be called safely.
+    throttle
+    /// [`signal_throttled_with_controls`]
+    /// [`ThrottleOptions`]
+);
@@ -81,6 +81,32 @@ const INTERNAL_STORAGE_EVENT: &str = "leptos-use-storage";
 /// }
 /// ```
 ///
+/// ## Cross-tab Synchronization
+///
+/// When using local storage the returned signal is also updated when another tab or window of the
+/// same origin writes to the same key. This is done by listening to the window's
+/// [`storage` event](https://developer.mozilla.org/en-US/docs/Web/API/Window/storage_event) and
+/// reading and decoding the new value. Events for the same key in a different storage area are ignored.
+///
+/// If you don't need this you can disable it with the option `listen_to_storage_changes`. Then the
+/// value is neither updated by other tabs nor by other calls with the same key on the same page.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::storage::{use_local_storage_with_options, UseStorageOptions};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # pub fn Example() -> impl IntoView {
+/// let (count, set_count, _) = use_local_storage_with_options::<i32, FromToStringCodec>(
+///     "my-count",
+///     UseStorageOptions::default().listen_to_storage_changes(false),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// On the server the returned signals will just read/manipulate the `initial_value` without persistence.
@@ -364,11 +390,15 @@ where
             // Listen to global storage events
             let _ = use_event_listener(use_window(), leptos::ev::storage, {
                 let notify = notify.clone();
+                let storage = storage.to_owned();
 
                 move |ev| {
-                    let ev_key = ev.key();
-                    // Key matches or all keys deleted (None)
-                    if ev_key == Some(key.get_untracked()) || ev_key.is_none() {
+                    if is_storage_event_for(
+                        storage.as_ref().ok(),
+                        &key.get_untracked(),
+                        ev.storage_area().as_ref(),
+                        ev.key().as_deref(),
+                    ) {
                         notify.notify()
                     }
                 }
@@ -445,7 +475,9 @@ where
     // Callback for when an error occurs
     #[builder(skip)]
     on_error: Arc<dyn Fn(UseStorageError<E, D>) + Send + Sync>,
-    // Whether to continuously listen to changes from browser storage
+    /// Whether to continuously listen to changes from browser storage. This keeps the value in sync
+    /// with other calls using the same key on the same page and, for local storage, with other tabs.
+    /// Defaults to `true`.
     listen_to_storage_changes: bool,
     // Initial value to use when the storage key is not set
     #[builder(skip)]
@@ -459,6 +491,28 @@ where
     delay_during_hydration: bool,
 }
 
+/// Whether a `storage` event concerns the value stored under `key` in `storage`.
+#[cfg(not(feature = "ssr"))]
+fn is_storage_event_for<S: PartialEq>(
+    storage: Option<&S>,
+    key: &str,
+    event_storage_area: Option<&S>,
+    event_key: Option<&str>,
+) -> bool {
+    // Ignore changes to the same key in a different storage area
+    if let (Some(storage), Some(event_storage_area)) = (storage, event_storage_area) {
+        if storage != event_storage_area {
+            return false;
+        }
+    }
+
+    // Key matches or all keys deleted (None)
+    match event_key {
+        Some(event_key) => event_key == key,
+        None => true,
+    }
+}
+
 /// Calls the on_error callback with the given error. Removes the error from the Result to avoid double error handling.
 #[cfg(not(feature = "ssr"))]
 fn handle_error<T, E, D>(
@@ -506,3 +560,46 @@ where
         }
     }
 }
+
+#[cfg(all(test, not(feature = "ssr")))]
+mod tests {
+    use super::is_storage_event_for;
+
+    #[test]
+    fn storage_event_from_other_storage_area_is_ignored() {
+        assert!(!is_storage_event_for(
+            Some(&"local"),
+            "key",
+            Some(&"session"),
+            Some("key"),
+        ));
+        assert!(!is_storage_event_for(
+            Some(&"local"),
+            "key",
+            Some(&"session"),
+            None,
+        ));
+    }
+
+    #[test]
+    fn storage_event_from_same_storage_area_is_handled() {
+        assert!(is_storage_event_for(
+            Some(&"local"),
+            "key",
+            Some(&"local"),
+            Some("key"),
+        ));
+        assert!(is_storage_event_for(
+            Some(&"local"),
+            "key",
+            Some(&"local"),
+            None
+        ));
+        assert!(!is_storage_event_for(
+            Some(&"local"),
+            "key",
+            Some(&"local"),
+            Some("other"),
+        ));
+    }
+}
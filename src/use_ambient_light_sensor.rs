@@ -0,0 +1,213 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{use_permission, use_supported_immediately, PermissionState};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive [AmbientLightSensor](https://developer.mozilla.org/en-US/docs/Web/API/AmbientLightSensor).
+///
+/// Reads the current light level around the device, e.g. to adjust the brightness of the theme
+/// automatically. This is part of the [Generic Sensor API](https://developer.mozilla.org/en-US/docs/Web/API/Sensor_APIs)
+/// which is only available in secure contexts and only supported by a few browsers.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_ambient_light_sensor, UseAmbientLightSensorReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseAmbientLightSensorReturn {
+///     is_supported,
+///     illuminance,
+///     ..
+/// } = use_ambient_light_sensor();
+///
+/// let is_dim = move || illuminance.get().is_some_and(|lux| lux < 50.0);
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// `illuminance` is measured in [lux](https://en.wikipedia.org/wiki/Lux) and is `None` until
+/// the first reading is available.
+///
+/// ### Permission
+///
+/// The sensor requires the `ambient-light-sensor` permission. Its state is returned as
+/// `permission`. If the permission is denied the sensor reports an error which is returned
+/// as `error`. When the permission is granted later, the sensor is restarted automatically.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_ambient_light_sensor_with_options, PermissionState, UseAmbientLightSensorOptions, UseAmbientLightSensorReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseAmbientLightSensorReturn {
+///     illuminance,
+///     permission,
+///     error,
+///     ..
+/// } = use_ambient_light_sensor_with_options(
+///     UseAmbientLightSensorOptions::default().frequency(1.0),
+/// );
+///
+/// view! {
+///     <Show when=move || permission.get() == PermissionState::Denied>
+///         "Please allow access to the ambient light sensor in the site settings"
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is `false`, `illuminance` and `error` are always `None` and
+/// `permission` is `PermissionState::Unknown`.
+pub fn use_ambient_light_sensor() -> UseAmbientLightSensorReturn {
+    use_ambient_light_sensor_with_options(UseAmbientLightSensorOptions::default())
+}
+
+/// Version of [`use_ambient_light_sensor`] that takes a `UseAmbientLightSensorOptions`. See [`use_ambient_light_sensor`] for how to use.
+pub fn use_ambient_light_sensor_with_options(
+    options: UseAmbientLightSensorOptions,
+) -> UseAmbientLightSensorReturn {
+    let UseAmbientLightSensorOptions { frequency } = options;
+
+    let is_supported = use_supported_immediately(|| crate::js!("AmbientLightSensor" in &window()));
+    let permission = use_permission("ambient-light-sensor");
+
+    let (illuminance, set_illuminance) = signal(None::<f64>);
+    let (error, set_error) = signal_local(None::<web_sys::DomException>);
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        use crate::{js, use_event_listener};
+        use leptos::ev::{Custom, Event};
+        use wasm_bindgen::JsCast;
+
+        let sensor = match create_sensor(frequency) {
+            Ok(sensor) => Some(sensor),
+            Err(err) => {
+                set_error.set(err.dyn_into::<web_sys::DomException>().ok());
+                None
+            }
+        };
+
+        if let Some(sensor) = sensor {
+            let _ = use_event_listener(sensor.clone(), Custom::<Event>::new("reading"), move |e| {
+                let illuminance = e
+                    .target()
+                    .and_then(|sensor| js!(sensor["illuminance"]).ok())
+                    .and_then(|illuminance| illuminance.as_f64());
+
+                set_illuminance.set(illuminance);
+                set_error.set(None);
+            });
+
+            let _ = use_event_listener(sensor.clone(), Custom::<Event>::new("error"), move |e| {
+                let error = js!(e["error"])
+                    .ok()
+                    .and_then(|error| error.dyn_into::<web_sys::DomException>().ok());
+
+                set_error.set(error);
+            });
+
+            call_sensor_method(&sensor, "start");
+
+            let _ = Effect::watch(
+                move || permission.get(),
+                {
+                    let sensor = sensor.clone();
+
+                    move |permission, prev_permission, _| {
+                        if *permission == PermissionState::Granted
+                            && matches!(
+                                prev_permission,
+                                Some(PermissionState::Denied | PermissionState::Prompt)
+                            )
+                        {
+                            // the sensor has been stopped by the browser with an error
+                            call_sensor_method(&sensor, "stop");
+                            call_sensor_method(&sensor, "start");
+                        }
+                    }
+                },
+                false,
+            );
+
+            on_cleanup({
+                let sensor = send_wrapper::SendWrapper::new(sensor);
+                move || call_sensor_method(&sensor, "stop")
+            });
+        }
+    }
+
+    UseAmbientLightSensorReturn {
+        is_supported,
+        illuminance: illuminance.into(),
+        error: error.into(),
+        permission,
+    }
+}
+
+/// Creates a new `AmbientLightSensor` with the given frequency.
+#[cfg(not(feature = "ssr"))]
+fn create_sensor(frequency: Option<f64>) -> Result<web_sys::EventTarget, wasm_bindgen::JsValue> {
+    use crate::js;
+    use wasm_bindgen::JsCast;
+
+    let window = window();
+    let constructor = js!(window["AmbientLightSensor"])?.dyn_into::<js_sys::Function>()?;
+
+    let sensor_options = js_sys::Object::new();
+    if let Some(frequency) = frequency {
+        js!(sensor_options["frequency"] = frequency);
+    }
+
+    let sensor = js_sys::Reflect::construct(&constructor, &js_sys::Array::of1(&sensor_options))?;
+
+    Ok(sensor.unchecked_into())
+}
+
+/// Calls a method like `start` or `stop` on the sensor.
+#[cfg(not(feature = "ssr"))]
+fn call_sensor_method(sensor: &web_sys::EventTarget, method: &str) {
+    use wasm_bindgen::JsCast;
+
+    if let Some(method) = js_sys::Reflect::get(sensor, &method.into())
+        .ok()
+        .and_then(|method| method.dyn_into::<js_sys::Function>().ok())
+    {
+        let _ = method.call0(sensor);
+    }
+}
+
+/// Options for [`use_ambient_light_sensor_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseAmbientLightSensorOptions {
+    /// The number of readings per second. If `None` the default frequency of the browser is used.
+    /// Defaults to `None`.
+    #[builder(into)]
+    frequency: Option<f64>,
+}
+
+/// Return type of [`use_ambient_light_sensor`].
+#[derive(Clone, Copy)]
+pub struct UseAmbientLightSensorReturn {
+    /// Whether the `AmbientLightSensor` is supported by the browser
+    pub is_supported: Signal<bool>,
+
+    /// The current light level in lux. `None` until the first reading is available.
+    pub illuminance: Signal<Option<f64>>,
+
+    /// The last error reported by the sensor, e.g. a `NotAllowedError` if the permission has
+    /// been denied. Reset with the next successful reading.
+    pub error: Signal<Option<web_sys::DomException>, LocalStorage>,
+
+    /// The state of the `ambient-light-sensor` permission
+    pub permission: Signal<PermissionState>,
+}
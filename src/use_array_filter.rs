@@ -0,0 +1,44 @@
+use leptos::prelude::*;
+
+/// Reactive `Iterator::filter` of a list.
+///
+/// The returned signal only notifies its subscribers if the filtered list actually changed.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_array_filter;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (list, set_list) = signal(vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+///
+/// let even = use_array_filter(list, |n| n % 2 == 0); // [2, 4, 6, 8]
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## See also
+///
+/// * [`fn@crate::use_array_find`]
+/// * [`fn@crate::use_array_map`]
+pub fn use_array_filter<S, T, F>(list: S, predicate: F) -> Signal<Vec<T>>
+where
+    S: Into<Signal<Vec<T>>>,
+    T: Clone + PartialEq + Send + Sync + 'static,
+    F: Fn(&T) -> bool + Send + Sync + 'static,
+{
+    let list = list.into();
+
+    Memo::new(move |_| {
+        list.with(|list| {
+            list.iter()
+                .filter(|item| predicate(item))
+                .cloned()
+                .collect()
+        })
+    })
+    .into()
+}
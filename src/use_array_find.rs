@@ -0,0 +1,35 @@
+use leptos::prelude::*;
+
+/// Reactive `Iterator::find` of a list.
+///
+/// The returned signal only notifies its subscribers if the found item actually changed.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_array_find;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (list, set_list) = signal(vec![1, 2, 3, 4]);
+///
+/// let first_even = use_array_find(list, |n| n % 2 == 0); // Some(2)
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## See also
+///
+/// * [`fn@crate::use_array_filter`]
+pub fn use_array_find<S, T, F>(list: S, predicate: F) -> Signal<Option<T>>
+where
+    S: Into<Signal<Vec<T>>>,
+    T: Clone + PartialEq + Send + Sync + 'static,
+    F: Fn(&T) -> bool + Send + Sync + 'static,
+{
+    let list = list.into();
+
+    Memo::new(move |_| list.with(|list| list.iter().find(|item| predicate(item)).cloned())).into()
+}
@@ -0,0 +1,37 @@
+use leptos::prelude::*;
+
+/// Reactive `Iterator::map` of a list.
+///
+/// The returned signal only notifies its subscribers if the mapped list actually changed.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_array_map;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (list, set_list) = signal(vec![1, 2, 3]);
+///
+/// let doubled = use_array_map(list, |n| n * 2); // [2, 4, 6]
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## See also
+///
+/// * [`fn@crate::use_array_filter`]
+/// * [`fn@crate::use_array_reduce`]
+pub fn use_array_map<S, T, U, F>(list: S, map_fn: F) -> Signal<Vec<U>>
+where
+    S: Into<Signal<Vec<T>>>,
+    T: Send + Sync + 'static,
+    U: PartialEq + Send + Sync + 'static,
+    F: Fn(&T) -> U + Send + Sync + 'static,
+{
+    let list = list.into();
+
+    Memo::new(move |_| list.with(|list| list.iter().map(&map_fn).collect())).into()
+}
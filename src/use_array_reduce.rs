@@ -0,0 +1,36 @@
+use leptos::prelude::*;
+
+/// Reactive `Iterator::fold` of a list.
+///
+/// The returned signal only notifies its subscribers if the result actually changed.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_array_reduce;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (list, set_list) = signal(vec![1, 2, 3]);
+///
+/// let sum = use_array_reduce(list, |sum, n| sum + n, 0); // 6
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## See also
+///
+/// * [`fn@crate::use_array_map`]
+pub fn use_array_reduce<S, T, U, F>(list: S, reducer: F, initial_value: U) -> Signal<U>
+where
+    S: Into<Signal<Vec<T>>>,
+    T: Send + Sync + 'static,
+    U: Clone + PartialEq + Send + Sync + 'static,
+    F: Fn(U, &T) -> U + Send + Sync + 'static,
+{
+    let list = list.into();
+
+    Memo::new(move |_| list.with(|list| list.iter().fold(initial_value.clone(), &reducer))).into()
+}
@@ -0,0 +1,48 @@
+use leptos::prelude::*;
+
+/// Reactive list without duplicates.
+///
+/// The order is preserved and only the first occurrence of an item is kept.
+/// The returned signal only notifies its subscribers if the result actually changed.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_array_unique;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (list, set_list) = signal(vec![1, 2, 2, 3, 1]);
+///
+/// let unique = use_array_unique(list); // [1, 2, 3]
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## See also
+///
+/// * [`fn@crate::use_array_filter`]
+pub fn use_array_unique<S, T>(list: S) -> Signal<Vec<T>>
+where
+    S: Into<Signal<Vec<T>>>,
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    let list = list.into();
+
+    Memo::new(move |_| {
+        list.with(|list| {
+            let mut unique = Vec::<T>::with_capacity(list.len());
+
+            for item in list {
+                if !unique.contains(item) {
+                    unique.push(item.clone());
+                }
+            }
+
+            unique
+        })
+    })
+    .into()
+}
@@ -0,0 +1,287 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::sendwrap_fn;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use wasm_bindgen::JsValue;
+
+/// Reactive base64 encoding of strings, bytes, `Blob`s, `File`s and `ArrayBuffer`s.
+///
+/// The source is converted into a `Blob` and read with a
+/// [`FileReader`](https://developer.mozilla.org/en-US/docs/Web/API/FileReader) asynchronously.
+/// By default the result is a data URL that can be used directly as the `src` of an image.
+/// The result is updated whenever the source changes.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_base64, UseBase64Return};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (text, set_text) = signal_local("Hello World".to_string());
+///
+/// let UseBase64Return { base64, .. } = use_base64::<String, _>(text);
+/// // data:text/plain;base64,SGVsbG8gV29ybGQ=
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// Since `Blob`s and `File`s can't be sent between threads, the source is a local signal. Use
+/// [`signal_local`] or convert a normal signal with `Signal::derive_local`. The type of the source
+/// value can't be inferred from a signal, so it has to be specified.
+///
+/// ### Preview of a file
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_base64, use_drop_zone_with_options, UseBase64Return, UseDropZoneOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let drop_zone = NodeRef::<Div>::new();
+///
+/// let (image, set_image) = signal_local(None::<web_sys::File>);
+///
+/// use_drop_zone_with_options(
+///     drop_zone,
+///     UseDropZoneOptions::default().on_drop(move |event| {
+///         set_image.set(event.files.into_iter().next());
+///     }),
+/// );
+///
+/// let UseBase64Return { base64, .. } = use_base64::<Option<web_sys::File>, _>(image);
+///
+/// view! {
+///     <div node_ref=drop_zone>"Drop an image here"</div>
+///     <img src=base64 />
+/// }
+/// # }
+/// ```
+///
+/// ### Raw base64
+///
+/// To get only the base64 encoded data without the `data:...;base64,` prefix, set the option
+/// `data_url` to `false`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_base64_with_options, UseBase64Options, UseBase64Return};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseBase64Return { base64, .. } = use_base64_with_options::<Vec<u8>, _>(
+///     vec![1_u8, 2, 3],
+///     UseBase64Options::default().data_url(false),
+/// );
+/// // AQID
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `execute` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_base64`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `base64` is always an empty string, `error` is always `None` and `execute` is a no-op.
+pub fn use_base64<T, S>(source: S) -> UseBase64Return<impl Fn() + Clone + Send + Sync>
+where
+    T: Base64Source + 'static,
+    S: Into<Signal<T, LocalStorage>>,
+{
+    use_base64_with_options(source, UseBase64Options::default())
+}
+
+/// Version of [`use_base64`] that takes a `UseBase64Options`. See [`use_base64`] for how to use.
+pub fn use_base64_with_options<T, S>(
+    source: S,
+    options: UseBase64Options,
+) -> UseBase64Return<impl Fn() + Clone + Send + Sync>
+where
+    T: Base64Source + 'static,
+    S: Into<Signal<T, LocalStorage>>,
+{
+    let UseBase64Options { data_url } = options;
+
+    let source = source.into();
+
+    let (base64, set_base64) = signal(String::new());
+    let (error, set_error) = signal_local(None::<JsValue>);
+
+    // Used to make sure that only the result of the latest read is applied.
+    let read_id = StoredValue::new(0_usize);
+
+    let execute = sendwrap_fn!(move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            read_id.update_value(|id| *id += 1);
+            let id = read_id.get_value();
+
+            let Some(blob) = source.with_untracked(|source| source.to_blob()) else {
+                set_base64.set(String::new());
+                set_error.set(None);
+                return;
+            };
+
+            leptos::task::spawn_local(async move {
+                let result = read_as_data_url(&blob).await;
+
+                if read_id.try_get_value() != Some(id) {
+                    return;
+                }
+
+                let result = match result {
+                    Ok(result) => {
+                        set_error.try_set(None);
+                        result
+                    }
+                    Err(err) => {
+                        set_base64.try_set(String::new());
+                        set_error.try_set(Some(err));
+                        return;
+                    }
+                };
+
+                let result = if data_url {
+                    result
+                } else {
+                    result
+                        .split_once(',')
+                        .map(|(_, data)| data.to_string())
+                        .unwrap_or_default()
+                };
+
+                set_base64.try_set(result);
+            });
+        }
+    });
+
+    Effect::watch(
+        move || source.track(),
+        {
+            let execute = execute.clone();
+            move |_, _, _| execute()
+        },
+        true,
+    );
+
+    UseBase64Return {
+        base64: base64.into(),
+        error: error.into(),
+        execute,
+    }
+}
+
+async fn read_as_data_url(blob: &web_sys::Blob) -> Result<String, JsValue> {
+    let reader = web_sys::FileReader::new()?;
+
+    let promise = js_sys::Promise::new(&mut |resolve, reject| {
+        reader.set_onload(Some(&resolve));
+        reader.set_onerror(Some(&reject));
+    });
+
+    reader.read_as_data_url(blob)?;
+    wasm_bindgen_futures::JsFuture::from(promise).await?;
+
+    reader
+        .result()?
+        .as_string()
+        .ok_or_else(|| JsValue::from_str("result is not a string"))
+}
+
+/// Values that can be encoded by [`use_base64`].
+pub trait Base64Source {
+    /// Converts the value into a `Blob` that is then read as data URL.
+    /// `None` results in an empty string.
+    fn to_blob(&self) -> Option<web_sys::Blob>;
+}
+
+fn blob_from_parts(parts: &JsValue, mime_type: &str) -> Option<web_sys::Blob> {
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+
+    web_sys::Blob::new_with_u8_array_sequence_and_options(&js_sys::Array::of1(parts), &options).ok()
+}
+
+impl Base64Source for String {
+    fn to_blob(&self) -> Option<web_sys::Blob> {
+        let options = web_sys::BlobPropertyBag::new();
+        options.set_type("text/plain");
+
+        web_sys::Blob::new_with_str_sequence_and_options(
+            &js_sys::Array::of1(&self.into()),
+            &options,
+        )
+        .ok()
+    }
+}
+
+impl Base64Source for Vec<u8> {
+    fn to_blob(&self) -> Option<web_sys::Blob> {
+        blob_from_parts(
+            &js_sys::Uint8Array::from(self.as_slice()).into(),
+            "application/octet-stream",
+        )
+    }
+}
+
+impl Base64Source for js_sys::ArrayBuffer {
+    fn to_blob(&self) -> Option<web_sys::Blob> {
+        blob_from_parts(self, "application/octet-stream")
+    }
+}
+
+impl Base64Source for web_sys::Blob {
+    fn to_blob(&self) -> Option<web_sys::Blob> {
+        Some(self.clone())
+    }
+}
+
+impl Base64Source for web_sys::File {
+    fn to_blob(&self) -> Option<web_sys::Blob> {
+        Some(self.clone().into())
+    }
+}
+
+impl<T> Base64Source for Option<T>
+where
+    T: Base64Source,
+{
+    fn to_blob(&self) -> Option<web_sys::Blob> {
+        self.as_ref().and_then(Base64Source::to_blob)
+    }
+}
+
+/// Options for [`use_base64_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseBase64Options {
+    /// If `true` the result is a data URL like `data:text/plain;base64,SGVsbG8=`. Otherwise it's
+    /// only the base64 encoded data. Defaults to `true`.
+    data_url: bool,
+}
+
+impl Default for UseBase64Options {
+    fn default() -> Self {
+        Self { data_url: true }
+    }
+}
+
+/// Return type of [`use_base64`].
+pub struct UseBase64Return<F>
+where
+    F: Fn() + Clone + Send + Sync,
+{
+    /// The base64 encoded source
+    pub base64: Signal<String>,
+    /// The error of the last read if the `FileReader` failed. `base64` is empty in that case.
+    pub error: Signal<Option<JsValue>, LocalStorage>,
+    /// Encodes the current source again
+    pub execute: F,
+}
@@ -1,4 +1,5 @@
-use crate::{use_media_query, use_window};
+use crate::{use_media_query, use_window, use_window_size};
+use default_struct_builder::DefaultBuilder;
 use leptos::logging::error;
 use leptos::prelude::*;
 use leptos::reactive::wrappers::read::Signal;
@@ -46,6 +47,10 @@ use std::hash::Hash;
 /// * [`breakpoints_quasar`]
 /// * [`breakpoints_semantic`]
 /// * [`breakpoints_master_css`]
+/// * [`breakpoints_vuetify_v2`]
+/// * [`breakpoints_vuetify_v3`]
+/// * [`breakpoints_prime_flex`]
+/// * [`breakpoints_element_plus`]
 ///
 /// You can also provide your own breakpoints.
 ///
@@ -83,6 +88,40 @@ use std::hash::Hash;
 /// }
 /// ```
 ///
+/// ## Units
+///
+/// Breakpoints can also be defined in `em` or `rem` by using [`BreakpointValue`] as the value type.
+/// These are resolved against the font size of the root element whenever a query is created.
+/// Later changes of the root font size are not tracked, i.e. queries that have already been
+/// created keep their pixel width.
+///
+/// ```
+/// # use std::collections::HashMap;
+/// use leptos::prelude::*;
+/// # use leptos_use::{use_breakpoints, BreakpointValue};
+/// #
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// enum MyBreakpoints {
+///     Narrow,
+///     Wide,
+/// }
+///
+/// #[component]
+/// fn Demo() -> impl IntoView {
+///     use MyBreakpoints::*;
+///
+///     let screen_width = use_breakpoints(HashMap::from([
+///         (Narrow, BreakpointValue::Em(30.0)),
+///         (Wide, BreakpointValue::Em(60.0)),
+///     ]));
+///
+///     let is_wide = screen_width.ge(Wide);
+///     let current_width = screen_width.current_width();
+///
+///     view! { }
+/// }
+/// ```
+///
 /// ## Non-reactive methods
 ///
 /// For every reactive method there is also a non-reactive variant that is prefixed with `is_`
@@ -110,37 +149,155 @@ use std::hash::Hash;
 /// ## Server-Side Rendering
 ///
 /// Since internally this uses [`fn@crate::use_media_query`], which returns always `false` on the server,
-/// the returned methods also will return `false`.
-pub fn use_breakpoints<K: Eq + Hash + Debug + Clone + Send + Sync>(
-    breakpoints: HashMap<K, u32>,
-) -> UseBreakpointsReturn<K> {
-    UseBreakpointsReturn { breakpoints }
+/// the returned methods also will return `false`. `em` and `rem` values are resolved against a root
+/// font size of `16px` on the server.
+///
+/// To render the correct layout on the server you can provide the option `ssr_width`, for example
+/// guessed from the user agent or a client hint. All comparisons are then made against this width
+/// on the server and on the client until it is hydrated. This avoids a layout flash after hydration.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_breakpoints_with_options, BreakpointsTailwind, breakpoints_tailwind, UseBreakpointsOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let screen_width = use_breakpoints_with_options(
+///     breakpoints_tailwind(),
+///     UseBreakpointsOptions::default().ssr_width(768.0),
+/// );
+///
+/// // true on the server
+/// let md_and_larger = screen_width.ge(BreakpointsTailwind::Md);
+/// #
+/// # view! { }
+/// # }
+/// ```
+pub fn use_breakpoints<K, V>(breakpoints: HashMap<K, V>) -> UseBreakpointsReturn<K>
+where
+    K: Eq + Hash + Debug + Clone + Send + Sync,
+    V: Into<BreakpointValue>,
+{
+    use_breakpoints_with_options(breakpoints, UseBreakpointsOptions::default())
+}
+
+/// Version of [`use_breakpoints`] that takes a `UseBreakpointsOptions`. See [`use_breakpoints`] for how to use.
+pub fn use_breakpoints_with_options<K, V>(
+    breakpoints: HashMap<K, V>,
+    options: UseBreakpointsOptions,
+) -> UseBreakpointsReturn<K>
+where
+    K: Eq + Hash + Debug + Clone + Send + Sync,
+    V: Into<BreakpointValue>,
+{
+    let UseBreakpointsOptions { ssr_width } = options;
+
+    UseBreakpointsReturn {
+        breakpoints: breakpoints
+            .into_iter()
+            .map(|(key, value)| (key, value.into()))
+            .collect(),
+        ssr_width,
+    }
+}
+
+/// Options for [`use_breakpoints_with_options`].
+#[derive(DefaultBuilder, Default, Clone, Copy)]
+pub struct UseBreakpointsOptions {
+    /// Viewport width in px that is used for all comparisons on the server and on the client
+    /// before hydration. Defaults to `None` which means that everything is `false` on the server.
+    #[builder(into)]
+    ssr_width: Option<f64>,
 }
 
 /// Return type of [`use_breakpoints`]
 #[derive(Clone)]
 pub struct UseBreakpointsReturn<K: Eq + Hash + Debug + Clone + Send + Sync> {
-    breakpoints: HashMap<K, u32>,
+    breakpoints: HashMap<K, BreakpointValue>,
+    ssr_width: Option<f64>,
 }
 
-macro_rules! query_suffix {
-    (>) => {
-        ".1"
-    };
-    (<) => {
-        ".9"
-    };
-    (=) => {
-        ""
+/// A breakpoint width together with its CSS unit.
+///
+/// Plain numbers like `u32`, `usize` or `f64` convert into [`BreakpointValue::Px`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BreakpointValue {
+    /// Width in CSS pixels
+    Px(f64),
+    /// Width in `em`, relative to the font size of the root element
+    Em(f64),
+    /// Width in `rem`, relative to the font size of the root element
+    Rem(f64),
+}
+
+impl BreakpointValue {
+    /// Returns the width in CSS pixels. `em` and `rem` are resolved against the current
+    /// font size of the root element. This isn't reactive.
+    pub fn to_px(&self) -> f64 {
+        match self {
+            Self::Px(value) => *value,
+            Self::Em(value) | Self::Rem(value) => *value * root_font_size(),
+        }
+    }
+}
+
+macro_rules! impl_from_px {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BreakpointValue {
+                fn from(value: $t) -> Self {
+                    Self::Px(value as f64)
+                }
+            }
+        )*
     };
 }
 
+impl_from_px!(u16, u32, u64, usize, i32, i64, f32, f64);
+
+/// Returns `ssr_value` until the first effect has run, i.e. on the server and during hydration.
+fn before_hydration<T>(value: Signal<T>, ssr_value: T) -> Signal<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let (is_hydrated, set_hydrated) = signal(false);
+    Effect::new(move |_| set_hydrated.set(true));
+
+    Signal::derive(move || {
+        if is_hydrated.get() {
+            value.get()
+        } else {
+            ssr_value.clone()
+        }
+    })
+}
+
+/// Computed font size of the root element in px. Falls back to the browser default of `16px`.
+fn root_font_size() -> f64 {
+    const DEFAULT_FONT_SIZE: f64 = 16.0;
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        if let Some(el) = document().document_element() {
+            if let Ok(Some(style)) = window().get_computed_style(&el) {
+                if let Ok(font_size) = style.get_property_value("font-size") {
+                    if let Ok(font_size) = font_size.trim_end_matches("px").parse::<f64>() {
+                        return font_size;
+                    }
+                }
+            }
+        }
+    }
+
+    DEFAULT_FONT_SIZE
+}
+
 macro_rules! value_expr {
     ($v:ident, >) => {
-        $v
+        $v + 0.1
     };
     ($v:ident, <) => {
-        $v - 1
+        $v - 0.1
     };
     ($v:ident, =) => {
         $v
@@ -149,24 +306,23 @@ macro_rules! value_expr {
 
 macro_rules! format_media_query {
     ($cmp:tt, $suffix:tt, $v:ident) => {
-        format!(
-            "({}-width: {}{}px)",
-            $cmp,
-            value_expr!($v, $suffix),
-            query_suffix!($suffix)
-        )
+        format!("({}-width: {:.1}px)", $cmp, value_expr!($v, $suffix))
     };
 }
 
 macro_rules! impl_cmp_reactively {
     (   #[$attr:meta]
-        $fn:ident, $cmp:tt, $suffix:tt) => {
+        $fn:ident, $cmp:tt, $suffix:tt, $op:tt) => {
         paste! {
             // Reactive check if
             #[$attr]
             pub fn $fn(&self, key: K) -> Signal<bool> {
                 if let Some(value) = self.breakpoints.get(&key) {
-                    use_media_query(format_media_query!($cmp, $suffix, value))
+                    let value = value.to_px();
+                    self.media_query(
+                        format_media_query!($cmp, $suffix, value),
+                        move |width| width $op value,
+                    )
                 } else {
                     self.not_found_signal(key)
                 }
@@ -176,7 +332,8 @@ macro_rules! impl_cmp_reactively {
             #[$attr]
             pub fn [<is_ $fn>](&self, key: K) -> bool {
                 if let Some(value) = self.breakpoints.get(&key) {
-                    Self::match_(&format_media_query!($cmp, $suffix, value))
+                    let value = value.to_px();
+                    self.match_(&format_media_query!($cmp, $suffix, value), |width| width $op value)
                 } else {
                     self.not_found(key)
                 }
@@ -189,7 +346,13 @@ impl<K> UseBreakpointsReturn<K>
 where
     K: Eq + Hash + Debug + Clone + Send + Sync + 'static,
 {
-    fn match_(query: &str) -> bool {
+    #[cfg_attr(not(feature = "ssr"), allow(unused_variables))]
+    fn match_(&self, query: &str, width_matches: impl Fn(f64) -> bool) -> bool {
+        #[cfg(feature = "ssr")]
+        if let Some(ssr_width) = self.ssr_width {
+            return width_matches(ssr_width);
+        }
+
         if let Ok(Some(query_list)) = use_window().match_media(query) {
             return query_list.matches();
         }
@@ -197,6 +360,20 @@ where
         false
     }
 
+    /// Uses the media query but resolves it against `ssr_width` on the server and before hydration.
+    fn media_query(
+        &self,
+        query: String,
+        width_matches: impl Fn(f64) -> bool + Send + Sync + 'static,
+    ) -> Signal<bool> {
+        let matches = use_media_query(query);
+
+        match self.ssr_width {
+            Some(ssr_width) => before_hydration(matches, width_matches(ssr_width)),
+            None => matches,
+        }
+    }
+
     fn not_found_signal(&self, key: K) -> Signal<bool> {
         error!("Breakpoint \"{:?}\" not found", key);
         Signal::derive(|| false)
@@ -209,30 +386,37 @@ where
 
     impl_cmp_reactively!(
         /// `[screen size]` > `key`
-        gt, "min", >
+        gt, "min", >, >
     );
     impl_cmp_reactively!(
         /// `[screen size]` >= `key`
-        ge, "min", =
+        ge, "min", =, >=
     );
     impl_cmp_reactively!(
         /// `[screen size]` < `key`
-        lt, "max", <
+        lt, "max", <, <
     );
     impl_cmp_reactively!(
         /// `[screen size]` <= `key`
-        le, "max", =
+        le, "max", =, <=
     );
 
-    fn between_media_query(min: &u32, max: &u32) -> String {
-        format!("(min-width: {min}px) and (max-width: {}.9px)", max - 1)
+    fn between_media_query(min: &BreakpointValue, max: &BreakpointValue) -> String {
+        format!(
+            "(min-width: {:.1}px) and (max-width: {:.1}px)",
+            min.to_px(),
+            max.to_px() - 0.1
+        )
     }
 
     /// Reactive check if `min_key` <= `[screen size]` <= `max_key`
     pub fn between(&self, min_key: K, max_key: K) -> Signal<bool> {
         if let Some(min) = self.breakpoints.get(&min_key) {
             if let Some(max) = self.breakpoints.get(&max_key) {
-                use_media_query(Self::between_media_query(min, max))
+                let (min_px, max_px) = (min.to_px(), max.to_px());
+                self.media_query(Self::between_media_query(min, max), move |width| {
+                    min_px <= width && width < max_px
+                })
             } else {
                 self.not_found_signal(max_key)
             }
@@ -245,7 +429,10 @@ where
     pub fn is_between(&self, min_key: K, max_key: K) -> bool {
         if let Some(min) = self.breakpoints.get(&min_key) {
             if let Some(max) = self.breakpoints.get(&max_key) {
-                Self::match_(&Self::between_media_query(min, max))
+                let (min_px, max_px) = (min.to_px(), max.to_px());
+                self.match_(&Self::between_media_query(min, max), |width| {
+                    min_px <= width && width < max_px
+                })
             } else {
                 self.not_found(max_key)
             }
@@ -259,15 +446,18 @@ where
         let breakpoints = self.breakpoints.clone();
         let keys: Vec<_> = breakpoints.keys().cloned().collect();
 
-        let ge = move |key: &K| {
+        let ge = |key: &K| {
             let value = breakpoints
                 .get(key)
-                .expect("only used with keys() from the HashMap");
+                .expect("only used with keys() from the HashMap")
+                .to_px();
 
-            use_media_query(format_media_query!("min", =, value))
+            self.media_query(format_media_query!("min", =, value), move |width| {
+                width >= value
+            })
         };
 
-        let signals: Vec<_> = keys.iter().map(ge.clone()).collect();
+        let signals: Vec<_> = keys.iter().map(ge).collect();
 
         Signal::derive(move || {
             keys.iter()
@@ -277,6 +467,18 @@ where
                 .collect::<Vec<_>>()
         })
     }
+
+    /// Reactive width of the viewport in px that the breakpoints are matched against.
+    ///
+    /// On the server this is `ssr_width` if provided or `f64::INFINITY` otherwise.
+    pub fn current_width(&self) -> Signal<f64> {
+        let width = use_window_size().width;
+
+        match self.ssr_width {
+            Some(ssr_width) => before_hydration(width, ssr_width),
+            None => width,
+        }
+    }
 }
 
 /// Breakpoint keys for Tailwind V2
@@ -463,3 +665,103 @@ pub fn breakpoints_master_css() -> HashMap<BreakpointsMasterCss, u32> {
         (BreakpointsMasterCss::Xxxxl, 2560),
     ])
 }
+
+/// Breakpoint keys for Vuetify V2
+///
+/// See <https://v2.vuetifyjs.com/en/features/breakpoints/>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BreakpointsVuetifyV2 {
+    Xs,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+/// Breakpoint definitions for Vuetify V2
+///
+/// See <https://v2.vuetifyjs.com/en/features/breakpoints/>
+pub fn breakpoints_vuetify_v2() -> HashMap<BreakpointsVuetifyV2, u32> {
+    HashMap::from([
+        (BreakpointsVuetifyV2::Xs, 1),
+        (BreakpointsVuetifyV2::Sm, 600),
+        (BreakpointsVuetifyV2::Md, 960),
+        (BreakpointsVuetifyV2::Lg, 1264),
+        (BreakpointsVuetifyV2::Xl, 1904),
+    ])
+}
+
+/// Breakpoint keys for Vuetify V3
+///
+/// See <https://vuetifyjs.com/en/features/display-and-platform/>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BreakpointsVuetifyV3 {
+    Xs,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+    Xxl,
+}
+
+/// Breakpoint definitions for Vuetify V3
+///
+/// See <https://vuetifyjs.com/en/features/display-and-platform/>
+pub fn breakpoints_vuetify_v3() -> HashMap<BreakpointsVuetifyV3, u32> {
+    HashMap::from([
+        (BreakpointsVuetifyV3::Xs, 1),
+        (BreakpointsVuetifyV3::Sm, 600),
+        (BreakpointsVuetifyV3::Md, 960),
+        (BreakpointsVuetifyV3::Lg, 1280),
+        (BreakpointsVuetifyV3::Xl, 1920),
+        (BreakpointsVuetifyV3::Xxl, 2560),
+    ])
+}
+
+/// Breakpoint keys for PrimeFlex
+///
+/// See <https://primeflex.org/installation>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BreakpointsPrimeFlex {
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+/// Breakpoint definitions for PrimeFlex
+///
+/// See <https://primeflex.org/installation>
+pub fn breakpoints_prime_flex() -> HashMap<BreakpointsPrimeFlex, u32> {
+    HashMap::from([
+        (BreakpointsPrimeFlex::Sm, 576),
+        (BreakpointsPrimeFlex::Md, 768),
+        (BreakpointsPrimeFlex::Lg, 992),
+        (BreakpointsPrimeFlex::Xl, 1200),
+    ])
+}
+
+/// Breakpoint keys for Element Plus
+///
+/// See <https://element-plus.org/en-US/component/layout.html#resonsive-layout>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BreakpointsElementPlus {
+    Xs,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+/// Breakpoint definitions for Element Plus
+///
+/// See <https://element-plus.org/en-US/component/layout.html#resonsive-layout>
+pub fn breakpoints_element_plus() -> HashMap<BreakpointsElementPlus, u32> {
+    HashMap::from([
+        (BreakpointsElementPlus::Xs, 1),
+        (BreakpointsElementPlus::Sm, 768),
+        (BreakpointsElementPlus::Md, 992),
+        (BreakpointsElementPlus::Lg, 1200),
+        (BreakpointsElementPlus::Xl, 1920),
+    ])
+}
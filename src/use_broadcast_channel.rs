@@ -1,10 +1,15 @@
 use crate::sendwrap_fn;
 use crate::{
-    js, use_event_listener, use_event_listener_with_options, use_supported, UseEventListenerOptions,
+    js, use_event_listener, use_event_listener_with_options, use_supported_immediately, use_window,
+    UseEventListenerOptions,
 };
 use codee::{CodecError, Decoder, Encoder};
+use default_struct_builder::DefaultBuilder;
 use leptos::ev::messageerror;
 use leptos::prelude::*;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::time::Duration;
 use thiserror::Error;
 use wasm_bindgen::JsValue;
 
@@ -72,6 +77,12 @@ use wasm_bindgen::JsValue;
 /// # }
 /// ```
 ///
+/// If you need to ask other tabs something and wait for their answer, have a look at
+/// [`use_broadcast_channel_request`].
+///
+/// When the page is restored from the back/forward cache the channel is re-created so that
+/// messaging resumes. This is not done if the channel has been closed by calling `close`.
+///
 /// ## SendWrapped Return
 ///
 /// The returned closures `post` and `close` are sendwrapped functions. They can
@@ -90,7 +101,7 @@ where
     <C as Encoder<T>>::Error: Send + Sync,
     <C as Decoder<T>>::Error: Send + Sync,
 {
-    let is_supported = use_supported(|| js!("BroadcastChannel" in &window()));
+    let is_supported = use_supported_immediately(|| js!("BroadcastChannel" in &window()));
 
     let (is_closed, set_closed) = signal(false);
     let (channel, set_channel) = signal_local(None::<web_sys::BroadcastChannel>);
@@ -121,51 +132,74 @@ where
         })
     };
 
+    let closed_manually = StoredValue::new(false);
+
     let close = {
         sendwrap_fn!(move || {
             if let Some(channel) = channel.get_untracked() {
                 channel.close();
             }
+            closed_manually.set_value(true);
             set_closed.set(true);
         })
     };
 
     if is_supported.get_untracked() {
-        let channel_val = web_sys::BroadcastChannel::new(name).ok();
-        set_channel.set(channel_val.clone());
-
-        if let Some(channel) = channel_val {
-            let _ = use_event_listener_with_options(
-                channel.clone(),
-                leptos::ev::message,
-                move |event| {
-                    if let Some(data) = event.data().as_string() {
-                        match C::decode(&data) {
-                            Ok(msg) => {
-                                set_message.set(Some(msg));
-                            }
-                            Err(err) => set_error.set(Some(UseBroadcastChannelError::Codec(
-                                CodecError::Decode(err),
-                            ))),
+        let open = {
+            let name = name.to_owned();
+
+            move || {
+                if let Some(channel) = channel.get_untracked() {
+                    channel.close();
+                }
+                set_channel.set(web_sys::BroadcastChannel::new(&name).ok());
+                set_closed.set(false);
+            }
+        };
+
+        open();
+
+        let _ = use_event_listener_with_options(
+            channel,
+            leptos::ev::message,
+            move |event| {
+                if let Some(data) = event.data().as_string() {
+                    match C::decode(&data) {
+                        Ok(msg) => {
+                            set_message.set(Some(msg));
                         }
-                    } else {
-                        set_error.set(Some(UseBroadcastChannelError::ValueNotString));
+                        Err(err) => set_error.set(Some(UseBroadcastChannelError::Codec(
+                            CodecError::Decode(err),
+                        ))),
                     }
-                },
-                UseEventListenerOptions::default().passive(true),
-            );
+                } else if !is_envelope(&event.data()) {
+                    set_error.set(Some(UseBroadcastChannelError::ValueNotString));
+                }
+            },
+            UseEventListenerOptions::default().passive(true),
+        );
 
-            let _ = use_event_listener_with_options(
-                channel.clone(),
-                messageerror,
-                move |event| {
-                    set_error.set(Some(UseBroadcastChannelError::MessageEvent(event)));
-                },
-                UseEventListenerOptions::default().passive(true),
-            );
+        let _ = use_event_listener_with_options(
+            channel,
+            messageerror,
+            move |event| {
+                set_error.set(Some(UseBroadcastChannelError::MessageEvent(event)));
+            },
+            UseEventListenerOptions::default().passive(true),
+        );
 
-            let _ = use_event_listener(channel, leptos::ev::close, move |_| set_closed.set(true));
-        }
+        let _ = use_event_listener(channel, leptos::ev::close, move |_| set_closed.set(true));
+
+        // A page restored from the back/forward cache might have a stale channel.
+        let _ = use_event_listener(
+            use_window(),
+            leptos::ev::Custom::<web_sys::PageTransitionEvent>::new("pageshow"),
+            move |event| {
+                if event.persisted() && !closed_manually.get_value() {
+                    open();
+                }
+            },
+        );
     }
 
     on_cleanup({
@@ -230,3 +264,335 @@ pub enum UseBroadcastChannelError<E, D> {
     #[error("received value is not a string")]
     ValueNotString,
 }
+
+/// Request/reply layer on top of the [BroadcastChannel API](https://developer.mozilla.org/en-US/docs/Web/API/BroadcastChannel).
+///
+/// While [`use_broadcast_channel`] only publishes messages, this lets one tab ask the other tabs
+/// something and wait for the answer. Requests and replies are correlated by a unique id. Every tab
+/// that calls this function with the same channel name answers incoming requests with `on_request`.
+/// If it returns `None` the tab doesn't answer. The first reply that arrives wins.
+///
+/// Closes the broadcast channel automatically when the component is cleaned up. When the page is
+/// restored from the back/forward cache the channel is re-created so that requests keep working.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::task::spawn_local;
+/// # use leptos_use::use_broadcast_channel_request;
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (token, set_token) = signal(None::<String>);
+///
+/// let channel = use_broadcast_channel_request::<String, String, FromToStringCodec, _>(
+///     "auth",
+///     move |question: String| {
+///         if question == "who-holds-the-token" {
+///             token.get_untracked()
+///         } else {
+///             None
+///         }
+///     },
+/// );
+///
+/// spawn_local(async move {
+///     if let Ok(token) = channel.request(&"who-holds-the-token".to_string()).await {
+///         set_token.set(Some(token));
+///     }
+/// });
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// If no tab answers within the `timeout` the request fails with
+/// [`UseBroadcastChannelRequestError::Timeout`]. It defaults to 5 seconds.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_broadcast_channel_request_with_options, UseBroadcastChannelRequestOptions};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let channel = use_broadcast_channel_request_with_options::<String, String, FromToStringCodec, _>(
+///     "auth",
+///     |_| None,
+///     UseBroadcastChannelRequestOptions::default().timeout(500.0),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// Requests and replies are encoded with the same codec. Messages of this layer are ignored by
+/// [`use_broadcast_channel`] listening on the same channel name.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is `false` and every request fails with
+/// [`UseBroadcastChannelRequestError::NotSupported`].
+pub fn use_broadcast_channel_request<Req, Rep, C, F>(
+    name: &str,
+    on_request: F,
+) -> UseBroadcastChannelRequestReturn<Req, Rep, C>
+where
+    C: Encoder<Req, Encoded = String>
+        + Decoder<Req, Encoded = str>
+        + Encoder<Rep, Encoded = String>
+        + Decoder<Rep, Encoded = str>,
+    F: Fn(Req) -> Option<Rep> + 'static,
+{
+    use_broadcast_channel_request_with_options(
+        name,
+        on_request,
+        UseBroadcastChannelRequestOptions::default(),
+    )
+}
+
+/// Version of [`use_broadcast_channel_request`] that takes a `UseBroadcastChannelRequestOptions`. See [`use_broadcast_channel_request`] for how to use.
+pub fn use_broadcast_channel_request_with_options<Req, Rep, C, F>(
+    name: &str,
+    on_request: F,
+    options: UseBroadcastChannelRequestOptions,
+) -> UseBroadcastChannelRequestReturn<Req, Rep, C>
+where
+    C: Encoder<Req, Encoded = String>
+        + Decoder<Req, Encoded = str>
+        + Encoder<Rep, Encoded = String>
+        + Decoder<Rep, Encoded = str>,
+    F: Fn(Req) -> Option<Rep> + 'static,
+{
+    let UseBroadcastChannelRequestOptions { timeout } = options;
+
+    let is_supported = use_supported_immediately(|| js!("BroadcastChannel" in &window()));
+
+    let (channel, set_channel) = signal_local(None::<web_sys::BroadcastChannel>);
+    let pending = StoredValue::new_local(HashMap::<String, js_sys::Function>::new());
+
+    if is_supported.get_untracked() {
+        let open = {
+            let name = name.to_owned();
+
+            move || {
+                if let Some(channel) = channel.get_untracked() {
+                    channel.close();
+                }
+                set_channel.set(web_sys::BroadcastChannel::new(&name).ok());
+            }
+        };
+
+        open();
+
+        let _ = use_event_listener_with_options(
+            channel,
+            leptos::ev::message,
+            move |event| {
+                let Some((kind, id, payload)) = read_envelope(&event.data()) else {
+                    return;
+                };
+
+                match kind.as_str() {
+                    ENVELOPE_REQUEST => {
+                        // Requests that can't be decoded are most likely meant for someone else.
+                        let Ok(request) = <C as Decoder<Req>>::decode(&payload) else {
+                            return;
+                        };
+
+                        let reply = on_request(request)
+                            .and_then(|reply| <C as Encoder<Rep>>::encode(&reply).ok());
+
+                        if let (Some(reply), Some(channel)) = (reply, channel.get_untracked()) {
+                            let _ =
+                                channel.post_message(&create_envelope(ENVELOPE_REPLY, &id, &reply));
+                        }
+                    }
+                    ENVELOPE_REPLY => {
+                        if let Some(resolve) = pending
+                            .try_update_value(|pending| pending.remove(&id))
+                            .flatten()
+                        {
+                            let _ = resolve.call1(&JsValue::NULL, &JsValue::from_str(&payload));
+                        }
+                    }
+                    _ => {}
+                }
+            },
+            UseEventListenerOptions::default().passive(true),
+        );
+
+        // The browser closes the channel when the page is put into the back/forward cache.
+        let _ = use_event_listener(
+            use_window(),
+            leptos::ev::Custom::<web_sys::PageTransitionEvent>::new("pageshow"),
+            move |event| {
+                if event.persisted() {
+                    open();
+                }
+            },
+        );
+    }
+
+    on_cleanup(move || {
+        if let Some(channel) = channel.try_get_untracked().flatten() {
+            channel.close();
+        }
+    });
+
+    UseBroadcastChannelRequestReturn {
+        is_supported,
+        channel,
+        pending,
+        timeout,
+        _marker: PhantomData,
+    }
+}
+
+/// Options for [`use_broadcast_channel_request_with_options`].
+#[derive(DefaultBuilder, Clone, Copy)]
+pub struct UseBroadcastChannelRequestOptions {
+    /// Time in milliseconds to wait for a reply before a request fails. Defaults to `5000.0`.
+    timeout: f64,
+}
+
+impl Default for UseBroadcastChannelRequestOptions {
+    fn default() -> Self {
+        Self { timeout: 5000.0 }
+    }
+}
+
+/// Return type of [`use_broadcast_channel_request`].
+pub struct UseBroadcastChannelRequestReturn<Req, Rep, C> {
+    /// `true` if this browser supports `BroadcastChannel`s.
+    pub is_supported: Signal<bool>,
+
+    channel: ReadSignal<Option<web_sys::BroadcastChannel>, LocalStorage>,
+    pending: StoredValue<HashMap<String, js_sys::Function>, LocalStorage>,
+    timeout: f64,
+    _marker: PhantomData<fn(Req, Rep, C)>,
+}
+
+impl<Req, Rep, C> Clone for UseBroadcastChannelRequestReturn<Req, Rep, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Req, Rep, C> Copy for UseBroadcastChannelRequestReturn<Req, Rep, C> {}
+
+impl<Req, Rep, C> UseBroadcastChannelRequestReturn<Req, Rep, C>
+where
+    C: Encoder<Req, Encoded = String> + Decoder<Rep, Encoded = str>,
+{
+    /// Sends `payload` to the other tabs and resolves with the first reply.
+    pub async fn request(
+        &self,
+        payload: &Req,
+    ) -> Result<
+        Rep,
+        UseBroadcastChannelRequestError<<C as Encoder<Req>>::Error, <C as Decoder<Rep>>::Error>,
+    > {
+        let Some(channel) = self.channel.try_get_untracked().flatten() else {
+            return Err(UseBroadcastChannelRequestError::NotSupported);
+        };
+
+        let payload = C::encode(payload)
+            .map_err(|err| UseBroadcastChannelRequestError::Codec(CodecError::Encode(err)))?;
+
+        let id = format!(
+            "{}-{}",
+            crate::core::now(),
+            js_sys::Math::random().to_string().trim_start_matches("0.")
+        );
+
+        let pending = self.pending;
+        let timeout = self.timeout;
+
+        let reply = js_sys::Promise::new(&mut |resolve, reject| {
+            pending.update_value(|pending| {
+                pending.insert(id.clone(), resolve);
+            });
+
+            let id = id.clone();
+            set_timeout(
+                move || {
+                    if pending
+                        .try_update_value(|pending| pending.remove(&id))
+                        .flatten()
+                        .is_some()
+                    {
+                        let _ = reject.call0(&JsValue::NULL);
+                    }
+                },
+                Duration::from_millis(timeout as u64),
+            );
+        });
+
+        if let Err(err) = channel.post_message(&create_envelope(ENVELOPE_REQUEST, &id, &payload)) {
+            pending.update_value(|pending| {
+                pending.remove(&id);
+            });
+            return Err(UseBroadcastChannelRequestError::PostMessage(err));
+        }
+
+        let reply = wasm_bindgen_futures::JsFuture::from(reply)
+            .await
+            .map_err(|_| UseBroadcastChannelRequestError::Timeout)?;
+
+        let reply = reply
+            .as_string()
+            .ok_or(UseBroadcastChannelRequestError::ValueNotString)?;
+
+        C::decode(&reply)
+            .map_err(|err| UseBroadcastChannelRequestError::Codec(CodecError::Decode(err)))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum UseBroadcastChannelRequestError<E, D> {
+    #[error("BroadcastChannel is not supported")]
+    NotSupported,
+    #[error("failed to post message")]
+    PostMessage(JsValue),
+    #[error("failed to (de)encode value")]
+    Codec(CodecError<E, D>),
+    #[error("received value is not a string")]
+    ValueNotString,
+    #[error("no reply received in time")]
+    Timeout,
+}
+
+const ENVELOPE_KEY: &str = "__leptos_use_broadcast_request";
+const ENVELOPE_REQUEST: &str = "request";
+const ENVELOPE_REPLY: &str = "reply";
+
+fn create_envelope(kind: &str, id: &str, payload: &str) -> JsValue {
+    let envelope = js_sys::Object::new();
+
+    let _ = js_sys::Reflect::set(&envelope, &ENVELOPE_KEY.into(), &kind.into());
+    let _ = js_sys::Reflect::set(&envelope, &"id".into(), &id.into());
+    let _ = js_sys::Reflect::set(&envelope, &"payload".into(), &payload.into());
+
+    envelope.into()
+}
+
+fn read_envelope(data: &JsValue) -> Option<(String, String, String)> {
+    if !data.is_object() {
+        return None;
+    }
+
+    let get = |key: &str| {
+        js_sys::Reflect::get(data, &key.into())
+            .ok()
+            .and_then(|value| value.as_string())
+    };
+
+    Some((get(ENVELOPE_KEY)?, get("id")?, get("payload")?))
+}
+
+fn is_envelope(data: &JsValue) -> bool {
+    read_envelope(data).is_some()
+}
@@ -0,0 +1,103 @@
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Cache a value and only notify subscribers when it changed according to a custom comparator.
+///
+/// Leptos' `Memo` uses `PartialEq` to decide if a value changed. Use this for types where you want
+/// to ignore certain fields like timestamps, or where the derived equality is not what you need.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_cached;
+/// #
+/// #[derive(Clone)]
+/// struct Data {
+///     value: i32,
+///     fetched_at: f64,
+/// }
+///
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (data, set_data) = signal(Data { value: 42, fetched_at: 0.0 });
+///
+/// // `cached` only changes when `value` changes
+/// let cached = use_cached(data, |a: &Data, b: &Data| a.value == b.value);
+///
+/// set_data.set(Data { value: 42, fetched_at: 1000.0 }); // `cached` doesn't change
+/// set_data.set(Data { value: 43, fetched_at: 2000.0 }); // `cached` changes
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// The comparator returns `true` if the two values are considered equal.
+/// In that case the returned signal keeps the old value.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_cached;
+/// #
+/// # #[derive(Clone)]
+/// # struct Data {
+/// #     value: i32,
+/// #     fetched_at: f64,
+/// # }
+/// #
+/// # let owner = Owner::new();
+/// # owner.set();
+/// let (data, set_data) = signal(Data { value: 42, fetched_at: 0.0 });
+/// let cached = use_cached(data, |a: &Data, b: &Data| a.value == b.value);
+/// assert_eq!(cached.get_untracked().fetched_at, 0.0);
+///
+/// set_data.set(Data { value: 42, fetched_at: 1000.0 });
+/// assert_eq!(cached.get_untracked().fetched_at, 0.0);
+///
+/// set_data.set(Data { value: 43, fetched_at: 2000.0 });
+/// assert_eq!(cached.get_untracked().fetched_at, 2000.0);
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// This works the same on the server.
+pub fn use_cached<S, T, C>(source: S, comparator: C) -> Signal<T>
+where
+    S: Into<Signal<T>>,
+    T: Clone + Send + Sync + 'static,
+    C: Fn(&T, &T) -> bool + Send + Sync + 'static,
+{
+    let source = source.into();
+    let comparator = Arc::new(comparator) as ComparatorFn<T>;
+
+    let cached = Memo::new(move |prev: Option<&Compared<T>>| {
+        let value = source.get();
+
+        // keep the old value if the new one is considered equal so readers never see the new one
+        let value = match prev {
+            Some(prev) if comparator(&prev.value, &value) => prev.value.clone(),
+            _ => value,
+        };
+
+        Compared {
+            value,
+            comparator: Arc::clone(&comparator),
+        }
+    });
+
+    Signal::derive(move || cached.with(|cached| cached.value.clone()))
+}
+
+type ComparatorFn<T> = Arc<dyn Fn(&T, &T) -> bool + Send + Sync>;
+
+/// Wrapper that implements `PartialEq` with a custom comparator so it can be used in a `Memo`.
+struct Compared<T> {
+    value: T,
+    comparator: ComparatorFn<T>,
+}
+
+impl<T> PartialEq for Compared<T> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.comparator)(&self.value, &other.value)
+    }
+}
@@ -1,8 +1,12 @@
-use crate::{js, js_fut, sendwrap_fn, use_event_listener, use_supported, UseTimeoutFnReturn};
+use crate::{
+    js, js_fut, sendwrap_fn, use_event_listener, use_permission, use_supported_immediately,
+    PermissionState, UseTimeoutFnReturn,
+};
 use default_struct_builder::DefaultBuilder;
-use leptos::ev::{copy, cut};
+use leptos::ev::{copy, cut, focus, paste};
 use leptos::prelude::*;
 use leptos::reactive::wrappers::read::Signal;
+use wasm_bindgen::JsCast;
 
 /// Reactive [Clipboard API](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard_API).
 ///
@@ -44,6 +48,21 @@ use leptos::reactive::wrappers::read::Signal;
 /// # }
 /// ```
 ///
+/// To keep `text` updated when the clipboard is changed outside your app, enable the option `read`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_clipboard_with_options, UseClipboardOptions, UseClipboardReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseClipboardReturn { text, .. } =
+///     use_clipboard_with_options(UseClipboardOptions::default().read(true));
+///
+/// view! { <p>"Clipboard: " {text}</p> }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
 /// The returned closures `copy` is a sendwrapped function. It can
@@ -65,7 +84,7 @@ pub fn use_clipboard_with_options(
         read,
     } = options;
 
-    let is_supported = use_supported(|| {
+    let is_supported = use_supported_immediately(|| {
         js!("clipboard" in &window()
             .navigator())
     });
@@ -80,7 +99,7 @@ pub fn use_clipboard_with_options(
         copied_reset_delay,
     );
 
-    let update_text = move |_| {
+    let update_text = move || {
         if is_supported.get() {
             leptos::task::spawn_local(async move {
                 let clipboard = window().navigator().clipboard();
@@ -92,8 +111,40 @@ pub fn use_clipboard_with_options(
     };
 
     if is_supported.get() && read {
-        let _ = use_event_listener(window(), copy, update_text);
-        let _ = use_event_listener(window(), cut, update_text);
+        let _ = use_event_listener(window(), copy, move |_| update_text());
+        let _ = use_event_listener(window(), cut, move |_| update_text());
+
+        let _ = use_event_listener(window(), paste, move |event| {
+            if let Some(text) = event
+                .dyn_ref::<web_sys::ClipboardEvent>()
+                .and_then(|event| event.clipboard_data())
+                .and_then(|data| data.get_data("text").ok())
+            {
+                set_text.set(Some(text));
+            }
+        });
+
+        // Only read on focus if already permitted. Otherwise the user would be prompted every time.
+        let read_permission = use_permission("clipboard-read");
+
+        let _ = use_event_listener(window(), focus, move |_| {
+            if read_permission.get_untracked() == PermissionState::Granted {
+                update_text();
+            }
+        });
+
+        // Start reading as soon as the permission is granted without having to remount.
+        Effect::watch(
+            move || read_permission.get(),
+            move |permission, prev_permission, _| {
+                if *permission == PermissionState::Granted
+                    && prev_permission != Some(&PermissionState::Granted)
+                {
+                    update_text();
+                }
+            },
+            false,
+        );
     }
 
     let do_copy = {
@@ -130,7 +181,11 @@ pub struct UseClipboardOptions {
     /// When `true` event handlers are added so that the returned signal `text` is updated whenever the clipboard changes.
     /// Defaults to `false`.
     ///
-    /// > Please note that clipboard changes are only detected when copying or cutting text inside the same document.
+    /// The clipboard is read when text is copied, cut or pasted inside the document. To detect changes
+    /// made outside of the document it is also read whenever the window gains focus. This only
+    /// happens if the `clipboard-read` permission has already been granted, to avoid repeated prompts.
+    /// The permission is watched, so reading starts as soon as it is granted and stops when it is
+    /// revoked.
     read: bool,
 
     /// After how many milliseconds after copying should the returned signal `copied` be set to `false`?
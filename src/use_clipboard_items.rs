@@ -0,0 +1,335 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{
+    js, js_fut, sendwrap_fn, use_event_listener, use_permission, use_supported_immediately,
+    PermissionState, UseTimeoutFnReturn,
+};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::{copy, cut, focus, paste};
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use wasm_bindgen::JsCast;
+
+/// Reactive [Clipboard API](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard_API) for
+/// full clipboard items.
+///
+/// In contrast to [`fn@crate::use_clipboard`] which only handles plain text, this gives access to
+/// all representations of the clipboard items, e.g. `text/html` together with a `text/plain`
+/// fallback or images. Every representation is identified by its MIME type.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_clipboard_items, ClipboardItemData, UseClipboardItemsReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseClipboardItemsReturn { is_supported, copied, copy, .. } = use_clipboard_items();
+///
+/// let copy_rich_text = move |_| {
+///     copy(&[ClipboardItemData::default()
+///         .with_text("text/html", "<b>Hello!</b>")
+///         .with_text("text/plain", "Hello!")]);
+/// };
+///
+/// view! {
+///     <Show when=move || is_supported.get()>
+///         <button on:click=copy_rich_text.clone()>
+///             <Show when=move || copied.get() fallback=move || "Copy">
+///                 "Copied!"
+///             </Show>
+///         </button>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// To keep `content` updated when the clipboard changes, enable the option `read`. It works like
+/// the option `read` of [`fn@crate::use_clipboard`].
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_clipboard_items_with_options, UseClipboardItemsOptions, UseClipboardItemsReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseClipboardItemsReturn { content, .. } =
+///     use_clipboard_items_with_options(UseClipboardItemsOptions::default().read(true));
+///
+/// let pasted_html = move || {
+///     content.with(|items| {
+///         items
+///             .iter()
+///             .find_map(|item| item.get_text("text/html"))
+///             .unwrap_or_default()
+///     })
+/// };
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `copy` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_clipboard_items`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the returned `content` signal will always be empty and `copy` is a no-op.
+pub fn use_clipboard_items(
+) -> UseClipboardItemsReturn<impl Fn(&[ClipboardItemData]) + Clone + Send + Sync> {
+    use_clipboard_items_with_options(UseClipboardItemsOptions::default())
+}
+
+/// Version of [`use_clipboard_items`] that takes a `UseClipboardItemsOptions`. See [`use_clipboard_items`] for how to use.
+pub fn use_clipboard_items_with_options(
+    options: UseClipboardItemsOptions,
+) -> UseClipboardItemsReturn<impl Fn(&[ClipboardItemData]) + Clone + Send + Sync> {
+    let UseClipboardItemsOptions {
+        copied_reset_delay,
+        read,
+    } = options;
+
+    let is_supported = use_supported_immediately(|| {
+        js!("clipboard" in &window().navigator()) && js!("ClipboardItem" in &window())
+    });
+
+    let (content, set_content) = signal(Vec::<ClipboardItemData>::new());
+    let (copied, set_copied) = signal(false);
+
+    let UseTimeoutFnReturn { start, .. } = crate::use_timeout_fn::use_timeout_fn(
+        move |_: ()| {
+            set_copied.set(false);
+        },
+        copied_reset_delay,
+    );
+
+    let update_content = move || {
+        if is_supported.get() {
+            leptos::task::spawn_local(async move {
+                if let Ok(items) = read_clipboard_items().await {
+                    set_content.set(items);
+                }
+            })
+        }
+    };
+
+    if is_supported.get() && read {
+        let _ = use_event_listener(window(), copy, move |_| update_content());
+        let _ = use_event_listener(window(), cut, move |_| update_content());
+
+        let _ = use_event_listener(window(), paste, move |event| {
+            if let Some(data) = event
+                .dyn_ref::<web_sys::ClipboardEvent>()
+                .and_then(|event| event.clipboard_data())
+            {
+                set_content.set(vec![ClipboardItemData::from(&data)]);
+            }
+        });
+
+        // Only read on focus if already permitted. Otherwise the user would be prompted every time.
+        let read_permission = use_permission("clipboard-read");
+
+        let _ = use_event_listener(window(), focus, move |_| {
+            if read_permission.get_untracked() == PermissionState::Granted {
+                update_content();
+            }
+        });
+
+        Effect::watch(
+            move || read_permission.get(),
+            move |permission, prev_permission, _| {
+                if *permission == PermissionState::Granted
+                    && prev_permission != Some(&PermissionState::Granted)
+                {
+                    update_content();
+                }
+            },
+            false,
+        );
+    }
+
+    let do_copy = sendwrap_fn!(move |items: &[ClipboardItemData]| {
+        if is_supported.get() {
+            let start = start.clone();
+            let items = items.to_vec();
+
+            leptos::task::spawn_local(async move {
+                if write_clipboard_items(&items).await.is_ok() {
+                    set_content.set(items);
+                    set_copied.set(true);
+                    start(());
+                }
+            });
+        }
+    });
+
+    UseClipboardItemsReturn {
+        is_supported,
+        content: content.into(),
+        copied: copied.into(),
+        copy: do_copy,
+    }
+}
+
+async fn read_clipboard_items() -> Result<Vec<ClipboardItemData>, wasm_bindgen::JsValue> {
+    let clipboard = window().navigator().clipboard();
+    let items = js_fut!(clipboard.read()).await?;
+
+    let mut result = vec![];
+
+    for item in js_sys::Array::from(&items).iter() {
+        let item = item.unchecked_into::<web_sys::ClipboardItem>();
+        let mut data = ClipboardItemData::default();
+
+        for mime_type in item.types().iter().filter_map(|t| t.as_string()) {
+            let blob = js_fut!(item.get_type(&mime_type))
+                .await?
+                .unchecked_into::<web_sys::Blob>();
+            let buffer = js_fut!(blob.array_buffer()).await?;
+
+            data = data.with_bytes(mime_type, js_sys::Uint8Array::new(&buffer).to_vec());
+        }
+
+        result.push(data);
+    }
+
+    Ok(result)
+}
+
+async fn write_clipboard_items(items: &[ClipboardItemData]) -> Result<(), wasm_bindgen::JsValue> {
+    let clipboard_items = js_sys::Array::new();
+
+    for item in items {
+        let record = js_sys::Object::new();
+
+        for (mime_type, bytes) in &item.data {
+            let blob_options = web_sys::BlobPropertyBag::new();
+            blob_options.set_type(mime_type);
+
+            let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(
+                &js_sys::Array::of1(&js_sys::Uint8Array::from(bytes.as_slice())),
+                &blob_options,
+            )?;
+
+            js_sys::Reflect::set(&record, &mime_type.into(), &blob)?;
+        }
+
+        let clipboard_item =
+            web_sys::ClipboardItem::new_with_record_from_str_to_blob_promise(&record)?;
+        clipboard_items.push(&clipboard_item);
+    }
+
+    let clipboard = window().navigator().clipboard();
+    js_fut!(clipboard.write(&clipboard_items)).await?;
+
+    Ok(())
+}
+
+/// A clipboard item with its representations in different formats.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClipboardItemData {
+    /// Pairs of MIME type and data in the order they were added.
+    data: Vec<(String, Vec<u8>)>,
+}
+
+impl ClipboardItemData {
+    /// Adds (or replaces) the representation with the given MIME type.
+    pub fn with_bytes(mut self, mime_type: impl Into<String>, bytes: impl Into<Vec<u8>>) -> Self {
+        let mime_type = mime_type.into();
+        let bytes = bytes.into();
+
+        match self.data.iter_mut().find(|(t, _)| *t == mime_type) {
+            Some((_, data)) => *data = bytes,
+            None => self.data.push((mime_type, bytes)),
+        }
+
+        self
+    }
+
+    /// Adds (or replaces) the text representation with the given MIME type like `text/plain` or `text/html`.
+    pub fn with_text(self, mime_type: impl Into<String>, text: impl Into<String>) -> Self {
+        self.with_bytes(mime_type, text.into().into_bytes())
+    }
+
+    /// The MIME types of all representations.
+    pub fn types(&self) -> impl Iterator<Item = &str> {
+        self.data.iter().map(|(mime_type, _)| mime_type.as_str())
+    }
+
+    /// The data of the representation with the given MIME type.
+    pub fn get(&self, mime_type: &str) -> Option<&[u8]> {
+        self.data
+            .iter()
+            .find(|(t, _)| t == mime_type)
+            .map(|(_, data)| data.as_slice())
+    }
+
+    /// The data of the representation with the given MIME type as text.
+    /// Invalid UTF-8 sequences are replaced.
+    pub fn get_text(&self, mime_type: &str) -> Option<String> {
+        self.get(mime_type)
+            .map(|data| String::from_utf8_lossy(data).into_owned())
+    }
+}
+
+impl From<&web_sys::DataTransfer> for ClipboardItemData {
+    fn from(data_transfer: &web_sys::DataTransfer) -> Self {
+        data_transfer
+            .types()
+            .iter()
+            .filter_map(|t| t.as_string())
+            .filter(|t| t != "Files")
+            .fold(Self::default(), |data, mime_type| {
+                match data_transfer.get_data(&mime_type) {
+                    Ok(text) => data.with_text(mime_type, text),
+                    Err(_) => data,
+                }
+            })
+    }
+}
+
+/// Options for [`use_clipboard_items_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseClipboardItemsOptions {
+    /// When `true` event handlers are added so that the returned signal `content` is updated whenever the clipboard changes.
+    /// Defaults to `false`.
+    ///
+    /// The clipboard is read when something is copied, cut or pasted inside the document and
+    /// whenever the window gains focus if the `clipboard-read` permission has already been granted.
+    /// When pasting, only the text representations are available.
+    read: bool,
+
+    /// After how many milliseconds after copying should the returned signal `copied` be set to `false`?
+    /// Defaults to 1500.
+    copied_reset_delay: f64,
+}
+
+impl Default for UseClipboardItemsOptions {
+    fn default() -> Self {
+        Self {
+            read: false,
+            copied_reset_delay: 1500.0,
+        }
+    }
+}
+
+/// Return type of [`use_clipboard_items`].
+pub struct UseClipboardItemsReturn<CopyFn>
+where
+    CopyFn: Fn(&[ClipboardItemData]) + Clone,
+{
+    /// Whether the Clipboard API with clipboard items is supported.
+    pub is_supported: Signal<bool>,
+
+    /// The current items of the clipboard.
+    pub content: Signal<Vec<ClipboardItemData>>,
+
+    /// `true` for [`UseClipboardItemsOptions::copied_reset_delay`] milliseconds after copying.
+    pub copied: Signal<bool>,
+
+    /// Copy the given items to the clipboard.
+    pub copy: CopyFn,
+}
@@ -7,6 +7,7 @@ use crate::{
     SyncSignalOptions, UseCookieOptions, UsePreferredDarkOptions,
 };
 use codee::string::FromToStringCodec;
+use codee::{Decoder, Encoder};
 use default_struct_builder::DefaultBuilder;
 use leptos::prelude::*;
 use leptos::reactive::wrappers::read::Signal;
@@ -114,6 +115,61 @@ use wasm_bindgen::JsCast;
 ///
 /// For a working example please check out the [ssr example](https://github.com/Synphonyte/leptos-use/blob/main/examples/ssr/src/app.rs).
 ///
+/// ### Persisted value
+///
+/// The color mode is persisted under the key given by the option `storage_key` (and the cookie
+/// `cookie_name`). By default the names of the modes are written. If you need a different
+/// representation, for example because the server expects a specific encoding, you can provide
+/// your own [codec](https://docs.rs/codee/latest/codee/index.html) with the option `codec`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_color_mode_with_options, ColorMode, UseColorModeOptions, UseColorModeReturn};
+/// # use codee::{Decoder, Encoder};
+/// #
+/// /// Persists the color mode as `"0"`, `"1"` or `"2"`.
+/// struct ColorModeIndexCodec;
+///
+/// impl Encoder<ColorMode> for ColorModeIndexCodec {
+///     type Error = ();
+///     type Encoded = String;
+///
+///     fn encode(val: &ColorMode) -> Result<String, ()> {
+///         match val {
+///             ColorMode::Auto => Ok("0".to_string()),
+///             ColorMode::Light => Ok("1".to_string()),
+///             ColorMode::Dark => Ok("2".to_string()),
+///             ColorMode::Custom(_) => Err(()),
+///         }
+///     }
+/// }
+///
+/// impl Decoder<ColorMode> for ColorModeIndexCodec {
+///     type Error = ();
+///     type Encoded = str;
+///
+///     fn decode(val: &str) -> Result<ColorMode, ()> {
+///         match val {
+///             "0" => Ok(ColorMode::Auto),
+///             "1" => Ok(ColorMode::Light),
+///             "2" => Ok(ColorMode::Dark),
+///             _ => Err(()),
+///         }
+///     }
+/// }
+///
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseColorModeReturn { mode, set_mode, .. } = use_color_mode_with_options(
+///     UseColorModeOptions::default()
+///         .storage_key("theme")
+///         .codec::<ColorModeIndexCodec>(),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// On the server this will try to read the
@@ -133,8 +189,12 @@ use wasm_bindgen::JsCast;
 ///
 /// ### Cookie
 ///
-/// If `cookie_enabled` is set to `true`, a cookie will be used and if present this value will be used
-/// on the server as well as on the client. Please note that you have to add the `axum` or `actix`
+/// If `cookie_enabled` is set to `true`, the color mode is also written to a cookie named
+/// `cookie_name` whenever it changes. On the server this cookie is read to determine the initial
+/// color mode so the page is rendered with the correct theme right away. On the client the
+/// storage stays the source of truth: if storage is enabled its value is written to the cookie
+/// and not the other way around. Only if nothing has been stored yet, the cookie is used as the
+/// initial value of the storage. Please note that you have to add the `axum` or `actix`
 /// feature as described in [`fn@crate::use_cookie`].
 ///
 /// ## See also
@@ -147,10 +207,13 @@ pub fn use_color_mode() -> UseColorModeReturn {
 }
 
 /// Version of [`use_color_mode`] that takes a `UseColorModeOptions`. See [`use_color_mode`] for how to use.
-pub fn use_color_mode_with_options<El, M>(options: UseColorModeOptions<El, M>) -> UseColorModeReturn
+pub fn use_color_mode_with_options<El, M, C>(
+    options: UseColorModeOptions<El, M, C>,
+) -> UseColorModeReturn
 where
     El: IntoElementMaybeSignal<web_sys::Element, M>,
     M: ?Sized,
+    C: Encoder<ColorMode, Encoded = String> + Decoder<ColorMode, Encoded = str>,
 {
     let UseColorModeOptions {
         target,
@@ -171,6 +234,7 @@ where
         listen_to_storage_changes,
         ssr_color_header_getter,
         _marker,
+        _codec,
     } = options;
 
     let modes: Vec<String> = custom_modes
@@ -200,7 +264,20 @@ where
         }
     }
 
-    let (store, set_store) = get_store_signal(
+    let has_storage = storage_signal.is_some() || storage_enabled;
+
+    let (cookie, set_cookie) = get_cookie_signal::<C>(&cookie_name, cookie_enabled);
+
+    // The initial value is only used by the storage if nothing has been stored yet. In that case
+    // the cookie (e.g. written before storage was enabled) is a better guess than the default.
+    let initial_value = match cookie.get_untracked() {
+        Some(cookie) if storage_enabled && storage_signal.is_none() => {
+            MaybeRwSignal::Static(cookie)
+        }
+        _ => initial_value,
+    };
+
+    let (store, set_store) = get_store_signal::<C>(
         initial_value_from_url.clone().unwrap_or(initial_value),
         storage_signal,
         &storage_key,
@@ -209,23 +286,31 @@ where
         listen_to_storage_changes,
     );
 
-    let (cookie, set_cookie) = get_cookie_signal(&cookie_name, cookie_enabled);
-
     if cookie_enabled {
-        let _ = sync_signal_with_options(
-            (cookie, set_cookie),
-            (store, set_store),
-            SyncSignalOptions::with_assigns(
-                move |store: &mut ColorMode, cookie: &Option<ColorMode>| {
-                    if let Some(cookie) = cookie {
-                        *store = cookie.clone();
-                    }
-                },
-                move |cookie: &mut Option<ColorMode>, store: &ColorMode| {
-                    *cookie = Some(store.clone())
-                },
-            ),
-        );
+        let assign_store = move |store: &mut ColorMode, cookie: &Option<ColorMode>| {
+            if let Some(cookie) = cookie {
+                *store = cookie.clone();
+            }
+        };
+        let assign_cookie =
+            move |cookie: &mut Option<ColorMode>, store: &ColorMode| *cookie = Some(store.clone());
+
+        // The signal on the left wins the initial sync. On the client the storage is the source
+        // of truth and the cookie only mirrors it for the server. On the server the cookie is
+        // all there is.
+        if has_storage && !cfg!(feature = "ssr") {
+            let _ = sync_signal_with_options(
+                (store, set_store),
+                (cookie, set_cookie),
+                SyncSignalOptions::with_assigns(assign_cookie, assign_store),
+            );
+        } else {
+            let _ = sync_signal_with_options(
+                (cookie, set_cookie),
+                (store, set_store),
+                SyncSignalOptions::with_assigns(assign_store, assign_cookie),
+            );
+        }
     }
 
     if let Some(initial_value_from_url) = initial_value_from_url {
@@ -336,34 +421,37 @@ pub enum ColorMode {
     Custom(String),
 }
 
-fn get_cookie_signal(
+fn get_cookie_signal<C>(
     cookie_name: &str,
     cookie_enabled: bool,
-) -> (Signal<Option<ColorMode>>, WriteSignal<Option<ColorMode>>) {
+) -> (Signal<Option<ColorMode>>, WriteSignal<Option<ColorMode>>)
+where
+    C: Encoder<ColorMode, Encoded = String> + Decoder<ColorMode, Encoded = str>,
+{
     if cookie_enabled {
-        use_cookie_with_options::<ColorMode, FromToStringCodec>(
-            cookie_name,
-            UseCookieOptions::default().path("/"),
-        )
+        use_cookie_with_options::<ColorMode, C>(cookie_name, UseCookieOptions::default().path("/"))
     } else {
         let (value, set_value) = signal(None);
         (value.into(), set_value)
     }
 }
 
-fn get_store_signal(
+fn get_store_signal<C>(
     initial_value: MaybeRwSignal<ColorMode>,
     storage_signal: Option<RwSignal<ColorMode>>,
     storage_key: &str,
     storage_enabled: bool,
     storage: StorageType,
     listen_to_storage_changes: bool,
-) -> (Signal<ColorMode>, WriteSignal<ColorMode>) {
+) -> (Signal<ColorMode>, WriteSignal<ColorMode>)
+where
+    C: Encoder<ColorMode, Encoded = String> + Decoder<ColorMode, Encoded = str>,
+{
     if let Some(storage_signal) = storage_signal {
         let (store, set_store) = storage_signal.split();
         (store.into(), set_store)
     } else if storage_enabled {
-        let (store, set_store, _) = use_storage_with_options::<ColorMode, FromToStringCodec>(
+        let (store, set_store, _) = use_storage_with_options::<ColorMode, C>(
             storage,
             storage_key,
             UseStorageOptions::default()
@@ -416,7 +504,7 @@ impl FromStr for ColorMode {
 }
 
 #[derive(DefaultBuilder)]
-pub struct UseColorModeOptions<El, M>
+pub struct UseColorModeOptions<El, M, C>
 where
     El: IntoElementMaybeSignal<web_sys::Element, M>,
     M: ?Sized,
@@ -475,7 +563,8 @@ where
     #[builder(into)]
     cookie_name: String,
 
-    /// If the color mode should be persisted through a cookie.
+    /// If the color mode should be persisted through a cookie. This lets the server render the
+    /// correct color mode on the first paint. On the client, storage stays the source of truth.
     /// Defaults to `false`.
     cookie_enabled: bool,
 
@@ -504,11 +593,49 @@ where
 
     #[builder(skip)]
     _marker: PhantomData<M>,
+
+    #[builder(skip)]
+    _codec: PhantomData<C>,
+}
+
+impl<El, M, C> UseColorModeOptions<El, M, C>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+    M: ?Sized,
+{
+    /// Codec used to serialize the color mode when persisting it to storage or to the cookie.
+    /// Use this if the persisted value has to follow a specific encoding, e.g. because the server
+    /// reads it to render the theme. Defaults to `FromToStringCodec` which persists the
+    /// names `"auto"`, `"light"`, `"dark"` or the name of the custom mode.
+    /// See [`use_color_mode`] for an example.
+    pub fn codec<NewC>(self) -> UseColorModeOptions<El, M, NewC> {
+        UseColorModeOptions {
+            target: self.target,
+            attribute: self.attribute,
+            initial_value: self.initial_value,
+            initial_value_from_url_param: self.initial_value_from_url_param,
+            initial_value_from_url_param_to_storage: self.initial_value_from_url_param_to_storage,
+            custom_modes: self.custom_modes,
+            on_changed: self.on_changed,
+            storage_signal: self.storage_signal,
+            storage_key: self.storage_key,
+            storage: self.storage,
+            storage_enabled: self.storage_enabled,
+            cookie_name: self.cookie_name,
+            cookie_enabled: self.cookie_enabled,
+            emit_auto: self.emit_auto,
+            transition_enabled: self.transition_enabled,
+            listen_to_storage_changes: self.listen_to_storage_changes,
+            ssr_color_header_getter: self.ssr_color_header_getter,
+            _marker: PhantomData,
+            _codec: PhantomData,
+        }
+    }
 }
 
 type OnChangedFn = Arc<dyn Fn(ColorMode, Arc<dyn Fn(ColorMode) + Send + Sync>) + Send + Sync>;
 
-impl Default for UseColorModeOptions<&'static str, str> {
+impl Default for UseColorModeOptions<&'static str, str, FromToStringCodec> {
     fn default() -> Self {
         Self {
             target: "html",
@@ -535,6 +662,7 @@ impl Default for UseColorModeOptions<&'static str, str> {
                 )
             }),
             _marker: PhantomData,
+            _codec: PhantomData,
         }
     }
 }
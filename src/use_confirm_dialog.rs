@@ -0,0 +1,273 @@
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::task::{Context, Poll, Waker};
+
+/// Creates event hooks to support modals and confirmation dialog chains.
+///
+/// Calling `reveal` shows the dialog and returns a future that resolves to the choice of the user
+/// once `confirm` or `cancel` is called. This decouples the imperative "await confirmation" flow
+/// from the dialog component.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::task::spawn_local;
+/// # use leptos_use::{use_confirm_dialog, ConfirmDialogResult};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let dialog = use_confirm_dialog::<String>();
+///
+/// let delete = move |_| {
+///     spawn_local(async move {
+///         if let ConfirmDialogResult::Confirmed(reason) = dialog.reveal().await {
+///             // delete with `reason`
+///         }
+///     });
+/// };
+///
+/// view! {
+///     <button on:click=delete>"Delete"</button>
+///
+///     <Show when=move || dialog.is_revealed.get()>
+///         <div class="modal">
+///             <p>"Are you sure?"</p>
+///             <button on:click=move |_| dialog.confirm("no longer needed".to_string())>"Yes"</button>
+///             <button on:click=move |_| dialog.cancel()>"No"</button>
+///         </div>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// Together with [`fn@crate::on_click_outside`] the dialog can be canceled by clicking outside of it.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{on_click_outside, use_confirm_dialog};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let dialog = use_confirm_dialog::<()>();
+/// let modal_ref = NodeRef::<Div>::new();
+///
+/// let _ = on_click_outside(modal_ref, move |_| dialog.cancel());
+/// #
+/// # view! { <div node_ref=modal_ref></div> }
+/// # }
+/// ```
+///
+/// You can also provide callbacks that are called whenever the dialog is revealed, confirmed or canceled.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos_use::{use_confirm_dialog_with_options, UseConfirmDialogOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let dialog = use_confirm_dialog_with_options(
+///     UseConfirmDialogOptions::default()
+///         .on_confirm(|value: &i32| log!("Confirmed with {value}"))
+///         .on_cancel(|| log!("Canceled")),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server the dialog is never revealed. Nothing special has to be done.
+pub fn use_confirm_dialog<T>() -> UseConfirmDialogReturn<T>
+where
+    T: 'static,
+{
+    use_confirm_dialog_with_options(UseConfirmDialogOptions::default())
+}
+
+/// Version of [`use_confirm_dialog`] that takes a `UseConfirmDialogOptions`. See [`use_confirm_dialog`] for how to use.
+pub fn use_confirm_dialog_with_options<T>(
+    options: UseConfirmDialogOptions<T>,
+) -> UseConfirmDialogReturn<T>
+where
+    T: 'static,
+{
+    let (is_revealed, set_revealed) = signal(false);
+
+    UseConfirmDialogReturn {
+        is_revealed: is_revealed.into(),
+        set_revealed,
+        pending: StoredValue::new_local(None),
+        options: StoredValue::new_local(options),
+    }
+}
+
+/// Options for [`use_confirm_dialog_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseConfirmDialogOptions<T> {
+    /// Called when the dialog is revealed.
+    on_reveal: Arc<dyn Fn() + Send + Sync>,
+
+    /// Called with the data passed to `confirm` when the dialog is confirmed.
+    #[builder(keep_type)]
+    on_confirm: Arc<dyn Fn(&T) + Send + Sync>,
+
+    /// Called when the dialog is canceled.
+    on_cancel: Arc<dyn Fn() + Send + Sync>,
+}
+
+impl<T> Default for UseConfirmDialogOptions<T> {
+    fn default() -> Self {
+        Self {
+            on_reveal: Arc::new(|| {}),
+            on_confirm: Arc::new(|_| {}),
+            on_cancel: Arc::new(|| {}),
+        }
+    }
+}
+
+/// Return type of [`use_confirm_dialog`].
+pub struct UseConfirmDialogReturn<T>
+where
+    T: 'static,
+{
+    /// Whether the dialog is currently revealed.
+    pub is_revealed: Signal<bool>,
+
+    set_revealed: WriteSignal<bool>,
+    pending: StoredValue<Option<Rc<RefCell<PendingDialog<T>>>>, LocalStorage>,
+    options: StoredValue<UseConfirmDialogOptions<T>, LocalStorage>,
+}
+
+impl<T> Clone for UseConfirmDialogReturn<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for UseConfirmDialogReturn<T> {}
+
+impl<T> UseConfirmDialogReturn<T>
+where
+    T: 'static,
+{
+    /// Reveals the dialog. The returned future resolves when the dialog is confirmed or canceled.
+    /// If the dialog was already revealed, the previous future resolves to `Canceled`.
+    pub fn reveal(&self) -> impl Future<Output = ConfirmDialogResult<T>> {
+        let pending = Rc::new(RefCell::new(PendingDialog {
+            result: None,
+            waker: None,
+        }));
+
+        if let Some(previous) = self
+            .pending
+            .try_update_value(|p| p.replace(Rc::clone(&pending)))
+            .flatten()
+        {
+            previous.borrow_mut().resolve(ConfirmDialogResult::Canceled);
+        }
+
+        self.set_revealed.set(true);
+
+        if let Some(on_reveal) = self.options.try_with_value(|o| Arc::clone(&o.on_reveal)) {
+            on_reveal();
+        }
+
+        RevealFuture(pending)
+    }
+
+    /// Confirms the dialog with the given data and hides it. Does nothing if the dialog isn't
+    /// revealed, e.g. if it has been confirmed already.
+    pub fn confirm(&self, data: T) {
+        if !self.is_revealed.get_untracked() {
+            return;
+        }
+
+        let pending = self.hide();
+
+        if let Some(on_confirm) = self.options.try_with_value(|o| Arc::clone(&o.on_confirm)) {
+            on_confirm(&data);
+        }
+
+        if let Some(pending) = pending {
+            pending
+                .borrow_mut()
+                .resolve(ConfirmDialogResult::Confirmed(data));
+        }
+    }
+
+    /// Cancels the dialog and hides it. Does nothing if the dialog isn't revealed.
+    pub fn cancel(&self) {
+        if !self.is_revealed.get_untracked() {
+            return;
+        }
+
+        let pending = self.hide();
+
+        if let Some(on_cancel) = self.options.try_with_value(|o| Arc::clone(&o.on_cancel)) {
+            on_cancel();
+        }
+
+        if let Some(pending) = pending {
+            pending.borrow_mut().resolve(ConfirmDialogResult::Canceled);
+        }
+    }
+
+    /// Hides the dialog and takes the pending reveal. This happens before the callbacks are
+    /// called so calling `confirm` or `cancel` again from within them does nothing and a new
+    /// `reveal` from within them isn't affected.
+    fn hide(&self) -> Option<Rc<RefCell<PendingDialog<T>>>> {
+        self.set_revealed.set(false);
+        self.pending.try_update_value(Option::take).flatten()
+    }
+}
+
+/// Result of [`UseConfirmDialogReturn::reveal`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfirmDialogResult<T> {
+    /// The dialog was confirmed with the given data.
+    Confirmed(T),
+    /// The dialog was canceled.
+    Canceled,
+}
+
+struct PendingDialog<T> {
+    result: Option<ConfirmDialogResult<T>>,
+    waker: Option<Waker>,
+}
+
+impl<T> PendingDialog<T> {
+    fn resolve(&mut self, result: ConfirmDialogResult<T>) {
+        self.result = Some(result);
+
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+struct RevealFuture<T>(Rc<RefCell<PendingDialog<T>>>);
+
+impl<T> Future for RevealFuture<T> {
+    type Output = ConfirmDialogResult<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut pending = self.0.borrow_mut();
+
+        match pending.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                pending.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
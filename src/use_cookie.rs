@@ -89,6 +89,32 @@ use std::sync::Arc;
 /// # }
 /// ```
 ///
+/// ### Watch external changes
+///
+/// Changes made with the returned `WriteSignal` are synced to other tabs automatically.
+/// If the cookie can also be changed elsewhere, for example by a server response or some other
+/// JavaScript, set the option `watch` to `true` to update the signal (and run dependent effects).
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_cookie_with_options, UseCookieOptions};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (session, _) = use_cookie_with_options::<String, FromToStringCodec>(
+///     "session",
+///     UseCookieOptions::default().watch(true),
+/// );
+///
+/// Effect::new(move || {
+///     leptos::logging::log!("session changed to {:?}", session.get());
+/// });
+/// #
+/// # view! {}
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// This works equally well on the server or the client.
@@ -171,6 +197,7 @@ where
         ssr_set_cookie,
         default_value,
         readonly,
+        watch,
         on_error,
     } = options;
 
@@ -190,18 +217,25 @@ where
 
     let jar = StoredValue::new(CookieJar::new());
 
+    // `true` if the cookie isn't set yet and the signal has been initialized with `default_value`
+    let mut is_default_value = false;
+
     if !has_expired {
         let ssr_cookies_header_getter = Arc::clone(&ssr_cookies_header_getter);
 
         let new_cookie = jar.try_update_value(|jar| {
-            *jar = load_and_parse_cookie_jar(ssr_cookies_header_getter)?;
+            // without any cookies in the request the default value is used as well
+            *jar = load_and_parse_cookie_jar(ssr_cookies_header_getter).unwrap_or_default();
             jar.get(cookie_name)
                 .and_then(|c| {
                     C::decode(c.value())
                         .map_err(|err| on_error(CodecError::Decode(err)))
                         .ok()
                 })
-                .or(default_value)
+                .or_else(|| {
+                    is_default_value = default_value.is_some();
+                    default_value
+                })
         });
 
         set_cookie.set(new_cookie.flatten());
@@ -247,6 +281,8 @@ where
                 });
 
                 if let Some(value) = value {
+                    // Only write the cookie if the value actually changed. Otherwise we would
+                    // refresh its expiration.
                     if value
                         == jar.with_value(|jar| jar.get(&cookie_name).map(|c| c.value().to_owned()))
                     {
@@ -287,6 +323,69 @@ where
             }
         });
 
+        #[allow(clippy::clone_on_copy)]
+        let (pause_watch, resume_watch) = (pause.clone(), resume.clone());
+
+        if watch {
+            use crate::{js, use_event_listener, use_window};
+            use leptos::ev::{focus, visibilitychange, Custom};
+            use wasm_bindgen::JsCast;
+
+            let reread = {
+                let cookie_name = cookie_name.to_owned();
+                let ssr_cookies_header_getter = Arc::clone(&ssr_cookies_header_getter);
+                let on_error = Arc::clone(&on_error);
+
+                move || {
+                    let Some(new_jar) =
+                        load_and_parse_cookie_jar(Arc::clone(&ssr_cookies_header_getter))
+                    else {
+                        return;
+                    };
+
+                    let value = new_jar.get(&cookie_name).map(|c| c.value().to_owned());
+
+                    if value
+                        == jar.with_value(|jar| jar.get(&cookie_name).map(|c| c.value().to_owned()))
+                    {
+                        return;
+                    }
+
+                    jar.set_value(new_jar);
+
+                    let value = value.and_then(|value| {
+                        C::decode(&value)
+                            .map_err(|err| on_error(CodecError::Decode(err)))
+                            .ok()
+                    });
+
+                    pause_watch();
+                    set_cookie.set(value);
+                    resume_watch();
+                }
+            };
+
+            let window = window();
+
+            if let Some(cookie_store) = js!("cookieStore" in &window)
+                .then(|| js!(window["cookieStore"]).ok())
+                .flatten()
+            {
+                let reread = reread.clone();
+                let _ = use_event_listener(
+                    cookie_store.unchecked_into::<web_sys::EventTarget>(),
+                    Custom::<web_sys::Event>::new("change"),
+                    move |_| reread(),
+                );
+            } else {
+                let _ = use_event_listener(use_window(), focus, {
+                    let reread = reread.clone();
+                    move |_| reread()
+                });
+                let _ = use_event_listener(document(), visibilitychange, move |_| reread());
+            }
+        }
+
         // listen to cookie changes from the broadcast channel
         Effect::new({
             let ssr_cookies_header_getter = Arc::clone(&ssr_cookies_header_getter);
@@ -363,6 +462,8 @@ where
 
     #[cfg(feature = "ssr")]
     {
+        let _ = watch;
+
         if !readonly {
             Effect::new_isomorphic({
                 let cookie_name = cookie_name.to_owned();
@@ -379,7 +480,17 @@ where
                                 .ok()
                         })
                     }) {
-                        if previous_effect_value.is_some() {
+                        let value = value.flatten();
+
+                        // Only write the cookie if the value actually changed. Otherwise we would
+                        // refresh its expiration with every `Set-Cookie` header.
+                        let has_changed = jar.with_value(|jar| {
+                            jar.get(&cookie_name).map(|c| c.value()) != value.as_deref()
+                        });
+
+                        // On the first run the cookie is only written if it has been initialized
+                        // with the default value. Otherwise it is already set in the browser.
+                        if has_changed && (previous_effect_value.is_some() || is_default_value) {
                             jar.update_value({
                                 let domain = domain.clone();
                                 let path = path.clone();
@@ -388,7 +499,7 @@ where
                                 |jar| {
                                     write_server_cookie(
                                         &cookie_name,
-                                        value.flatten(),
+                                        value,
                                         jar,
                                         max_age,
                                         expires,
@@ -476,9 +587,18 @@ pub struct UseCookieOptions<T, E, D> {
     same_site: Option<SameSite>,
 
     /// The default cookie value in case the cookie is not set.
+    /// During server-side rendering the default value is sent to the browser in a
+    /// `Set-Cookie` header (unless `readonly` is `true`) so the client hydrates with the same value.
     /// Defaults to `None`.
     default_value: Option<T>,
 
+    /// If `true` the cookie is re-read when it is changed outside of this function, for example
+    /// in another tab, by JavaScript or by a server response. This uses the
+    /// [Cookie Store API](https://developer.mozilla.org/en-US/docs/Web/API/Cookie_Store_API) where
+    /// available and falls back to re-reading the cookie when the window gets focus or
+    /// the page becomes visible. This has no effect on the server. Defaults to `false`.
+    watch: bool,
+
     /// If `true` the returned `WriteSignal` will not affect the actual cookie.
     /// Default: `false`
     readonly: bool,
@@ -504,6 +624,7 @@ impl<T, E, D> Default for UseCookieOptions<T, E, D> {
             http_only: false,
             default_value: None,
             readonly: false,
+            watch: false,
             secure: false,
             domain: None,
             path: None,
@@ -0,0 +1,359 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{use_cookie_with_options, UseCookieOptions};
+use codee::{CodecError, Decoder, Encoder};
+use cookie::SameSite;
+use default_struct_builder::DefaultBuilder;
+use leptos::logging::error;
+use leptos::prelude::*;
+use std::sync::Arc;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Reactive cookie based on the [Cookie Store API](https://developer.mozilla.org/en-US/docs/Web/API/Cookie_Store_API).
+///
+/// In contrast to [`fn@crate::use_cookie`], which relies on `document.cookie`, this subscribes to the
+/// `change` event of `window.cookieStore`. So the returned signal is updated whenever the cookie is
+/// changed, no matter if this happens in another tab, through JavaScript or by a server response.
+///
+/// In browsers that don't support the Cookie Store API this falls back to
+/// [`fn@crate::use_cookie`] with the option `watch` enabled.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_cookie_store;
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (counter, set_counter) = use_cookie_store::<u32, FromToStringCodec>("counter");
+///
+/// view! {
+///     <p>Counter: {move || counter.get().map(|c| c.to_string()).unwrap_or("—".to_string())}</p>
+///     <button on:click=move |_| set_counter.set(Some(counter.get().unwrap_or_default() + 1))>
+///         "+"
+///     </button>
+///     <button on:click=move |_| set_counter.set(None)>"Delete"</button>
+/// }
+/// # }
+/// ```
+///
+/// Values are (en)decoded via the given codec. You can use any of the string codecs or a
+/// binary codec wrapped in `Base64`.
+///
+/// > Please check [the codec chapter](https://leptos-use.rs/codecs.html) to see what codecs are
+/// > available and what feature flags they require.
+///
+/// ### Options
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_cookie_store_with_options, UseCookieStoreOptions};
+/// # use codee::string::FromToStringCodec;
+/// # use cookie::SameSite;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (theme, set_theme) = use_cookie_store_with_options::<String, FromToStringCodec>(
+///     "theme",
+///     UseCookieStoreOptions::default()
+///         .path("/")
+///         .same_site(SameSite::Lax),
+/// );
+/// #
+/// # view! {}
+/// # }
+/// ```
+///
+/// Writing the cookie happens asynchronously. If the cookie store rejects it or the value can't
+/// be encoded, the error is passed to the option `on_error` which logs it by default.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this is the same as [`fn@crate::use_cookie`]. Please refer to its documentation
+/// for the required features.
+pub fn use_cookie_store<T, C>(cookie_name: &str) -> (Signal<Option<T>>, WriteSignal<Option<T>>)
+where
+    C: Encoder<T, Encoded = String> + Decoder<T, Encoded = str>,
+    T: Clone + Send + Sync + 'static,
+{
+    use_cookie_store_with_options::<T, C>(cookie_name, UseCookieStoreOptions::default())
+}
+
+/// Version of [`use_cookie_store`] that takes a `UseCookieStoreOptions`. See [`use_cookie_store`] for how to use.
+pub fn use_cookie_store_with_options<T, C>(
+    cookie_name: &str,
+    options: UseCookieStoreOptions<<C as Encoder<T>>::Error, <C as Decoder<T>>::Error>,
+) -> (Signal<Option<T>>, WriteSignal<Option<T>>)
+where
+    C: Encoder<T, Encoded = String> + Decoder<T, Encoded = str>,
+    T: Clone + Send + Sync + 'static,
+{
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::js;
+
+        let window = window();
+        let cookie_store = js!("cookieStore" in &window)
+            .then(|| js!(window["cookieStore"]).ok())
+            .flatten();
+
+        if let Some(cookie_store) = cookie_store {
+            return use_cookie_store_api::<T, C>(cookie_name, cookie_store, options);
+        }
+    }
+
+    let UseCookieStoreOptions {
+        expires,
+        domain,
+        path,
+        same_site,
+        secure,
+        on_error,
+    } = options;
+
+    use_cookie_with_options::<T, C>(
+        cookie_name,
+        UseCookieOptions::<T, <C as Encoder<T>>::Error, <C as Decoder<T>>::Error>::default()
+            .expires::<i64>(expires.map(|expires| (expires / 1000.0) as i64))
+            .domain::<String>(domain)
+            .path::<String>(path)
+            .same_site::<SameSite>(same_site)
+            .secure(secure)
+            .watch(true)
+            .on_error(Arc::new(move |err| {
+                on_error(UseCookieStoreError::Codec(err))
+            })),
+    )
+}
+
+#[cfg(not(feature = "ssr"))]
+fn use_cookie_store_api<T, C>(
+    cookie_name: &str,
+    cookie_store: JsValue,
+    options: UseCookieStoreOptions<<C as Encoder<T>>::Error, <C as Decoder<T>>::Error>,
+) -> (Signal<Option<T>>, WriteSignal<Option<T>>)
+where
+    C: Encoder<T, Encoded = String> + Decoder<T, Encoded = str>,
+    T: Clone + Send + Sync + 'static,
+{
+    use crate::{js, use_event_listener};
+    use cookie::Cookie;
+    use leptos::ev::Custom;
+    use wasm_bindgen::JsCast;
+
+    let UseCookieStoreOptions {
+        expires,
+        domain,
+        path,
+        same_site,
+        secure,
+        on_error,
+    } = options;
+
+    let decode = {
+        let on_error = Arc::clone(&on_error);
+
+        move |value: &str| {
+            C::decode(value)
+                .map_err(|err| on_error(UseCookieStoreError::Codec(CodecError::Decode(err))))
+                .ok()
+        }
+    };
+
+    // The raw value of the cookie as it is currently stored in the browser
+    let raw_value = StoredValue::new({
+        let document = document().unchecked_into::<web_sys::HtmlDocument>();
+        let cookies = document.cookie().unwrap_or_default();
+
+        Cookie::split_parse_encoded(cookies)
+            .flatten()
+            .find(|cookie| cookie.name() == cookie_name)
+            .map(|cookie| cookie.value().to_owned())
+    });
+
+    let (cookie, set_cookie) =
+        signal(raw_value.with_value(|value| value.as_deref().and_then(&decode)));
+
+    // Calls `method` of the cookie store and returns the promise it returns
+    let call = {
+        let cookie_store = cookie_store.clone();
+
+        move |method: &str, arg: &JsValue| -> Result<js_sys::Promise, JsValue> {
+            js_sys::Reflect::get(&cookie_store, &method.into())?
+                .dyn_into::<js_sys::Function>()?
+                .call1(&cookie_store, arg)?
+                .dyn_into::<js_sys::Promise>()
+        }
+    };
+
+    Effect::watch(
+        move || cookie.track(),
+        {
+            let cookie_name = cookie_name.to_owned();
+
+            let on_error = Arc::clone(&on_error);
+
+            move |_, _, _| {
+                let value = match cookie.with_untracked(|cookie| cookie.as_ref().map(C::encode)) {
+                    Some(Ok(value)) => Some(value),
+                    Some(Err(err)) => {
+                        on_error(UseCookieStoreError::Codec(CodecError::Encode(err)));
+                        return;
+                    }
+                    None => None,
+                };
+
+                if raw_value.with_value(|raw_value| raw_value == &value) {
+                    return;
+                }
+
+                let options = js_sys::Object::new();
+                js!(options["name"] = cookie_name.as_str());
+                if let Some(domain) = &domain {
+                    js!(options["domain"] = domain.as_str());
+                }
+                if let Some(path) = &path {
+                    js!(options["path"] = path.as_str());
+                }
+
+                let (promise, to_error): (_, fn(JsValue) -> _) = if let Some(value) = &value {
+                    js!(options["value"] = String::from(js_sys::encode_uri_component(value)));
+                    if let Some(expires) = expires {
+                        js!(options["expires"] = expires);
+                    }
+                    if let Some(same_site) = same_site {
+                        js!(options["sameSite"] = same_site.to_string().to_lowercase());
+                    }
+                    if secure {
+                        js!(options["secure"] = true);
+                    }
+
+                    (call("set", &options), UseCookieStoreError::SetFailed)
+                } else {
+                    (call("delete", &options), UseCookieStoreError::DeleteFailed)
+                };
+
+                let on_error = Arc::clone(&on_error);
+                leptos::task::spawn_local(async move {
+                    let result = match promise {
+                        Ok(promise) => crate::js_fut!(promise).await.map(|_| ()),
+                        Err(err) => Err(err),
+                    };
+
+                    if let Err(err) = result {
+                        on_error(to_error(err));
+                    }
+                });
+
+                raw_value.set_value(value);
+            }
+        },
+        false,
+    );
+
+    let _ = use_event_listener(
+        cookie_store.unchecked_into::<web_sys::EventTarget>(),
+        Custom::<web_sys::Event>::new("change"),
+        {
+            let cookie_name = cookie_name.to_owned();
+
+            move |event| {
+                let find = |list: Result<JsValue, JsValue>| {
+                    list.ok()
+                        .map(|list| list.unchecked_into::<js_sys::Array>())
+                        .and_then(|list| {
+                            list.iter().find(|item| {
+                                js!(item["name"]).ok().and_then(|name| name.as_string())
+                                    == Some(cookie_name.clone())
+                            })
+                        })
+                };
+
+                let value = if let Some(item) = find(js!(event["changed"])) {
+                    js!(item["value"])
+                        .ok()
+                        .and_then(|value| value.as_string())
+                        .map(|value| {
+                            js_sys::decode_uri_component(&value)
+                                .map(String::from)
+                                .unwrap_or(value)
+                        })
+                } else if find(js!(event["deleted"])).is_some() {
+                    None
+                } else {
+                    return;
+                };
+
+                if raw_value.with_value(|raw_value| raw_value == &value) {
+                    return;
+                }
+
+                raw_value.set_value(value.clone());
+                set_cookie.set(value.as_deref().and_then(&decode));
+            }
+        },
+    );
+
+    (cookie.into(), set_cookie)
+}
+
+/// Errors passed to the option `on_error` of [`use_cookie_store_with_options`].
+#[derive(Error, Debug)]
+pub enum UseCookieStoreError<E, D> {
+    #[error("failed to encode / decode cookie value")]
+    Codec(CodecError<E, D>),
+    #[error("failed to set cookie")]
+    SetFailed(JsValue),
+    #[error("failed to delete cookie")]
+    DeleteFailed(JsValue),
+}
+
+/// Options for [`use_cookie_store_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseCookieStoreOptions<E, D> {
+    /// Expiration date-time of the cookie as UNIX timestamp in milliseconds.
+    /// If `None` the cookie is a session cookie. Defaults to `None`.
+    #[builder(into)]
+    expires: Option<f64>,
+
+    /// Domain of the cookie. Defaults to `None` which means the current domain.
+    #[builder(into)]
+    domain: Option<String>,
+
+    /// Path of the cookie. Defaults to `None` which means the current path.
+    #[builder(into)]
+    path: Option<String>,
+
+    /// `SameSite` attribute of the cookie. Defaults to `None`.
+    #[builder(into)]
+    same_site: Option<SameSite>,
+
+    /// Sets the `Secure` attribute of the cookie. Defaults to `false`.
+    secure: bool,
+
+    /// Callback for encoding/decoding errors and for failing to write the cookie to the cookie
+    /// store. Defaults to logging the error to the console.
+    on_error: Arc<dyn Fn(UseCookieStoreError<E, D>) + Send + Sync>,
+}
+
+impl<E, D> Default for UseCookieStoreOptions<E, D> {
+    fn default() -> Self {
+        Self {
+            expires: None,
+            domain: None,
+            path: None,
+            same_site: None,
+            secure: false,
+            on_error: Arc::new(|err| match err {
+                UseCookieStoreError::Codec(_) => error!("cookie (de-/)serialization error"),
+                UseCookieStoreError::SetFailed(err) => error!("failed to set cookie: {err:?}"),
+                UseCookieStoreError::DeleteFailed(err) => {
+                    error!("failed to delete cookie: {err:?}")
+                }
+            }),
+        }
+    }
+}
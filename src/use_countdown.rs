@@ -0,0 +1,155 @@
+use crate::core::now;
+use crate::utils::Pausable;
+use crate::{use_interval_fn_with_options, UseIntervalFnOptions};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Reactive countdown to a target timestamp.
+///
+/// Besides the total remaining time, the remaining time is also provided split into days, hours,
+/// minutes and seconds for display. The seconds are rounded up so that the countdown shows `0`
+/// seconds only when it is actually finished.
+///
+/// ## Usage
+///
+/// The target is given as milliseconds since the Unix epoch.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_countdown_with_options, UseCountdownOptions, UseCountdownReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (sale_ends_at, set_sale_ends_at) = signal(js_sys::Date::now() + 2.0 * 24.0 * 60.0 * 60.0 * 1000.0);
+///
+/// let UseCountdownReturn {
+///     days,
+///     hours,
+///     minutes,
+///     seconds,
+///     is_finished,
+///     ..
+/// } = use_countdown_with_options(
+///     sale_ends_at,
+///     UseCountdownOptions::default().on_complete(|| leptos::logging::log!("Sale is over")),
+/// );
+///
+/// view! {
+///     <Show when=move || !is_finished.get() fallback=|| "Sale is over">
+///         {days} "d " {hours} "h " {minutes} "m " {seconds} "s"
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// The countdown stops ticking when it is finished. If the target is moved into the future again
+/// it resumes automatically.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the remaining time is calculated once and never updated. `on_complete` is never called.
+pub fn use_countdown(target: impl Into<Signal<f64>>) -> UseCountdownReturn {
+    use_countdown_with_options(target, UseCountdownOptions::default())
+}
+
+/// Version of [`use_countdown`] that takes a `UseCountdownOptions`. See [`use_countdown`] for how to use.
+pub fn use_countdown_with_options(
+    target: impl Into<Signal<f64>>,
+    options: UseCountdownOptions,
+) -> UseCountdownReturn {
+    let UseCountdownOptions {
+        interval,
+        on_complete,
+    } = options;
+
+    let target = target.into();
+
+    let (now_ts, set_now_ts) = signal(now());
+
+    let remaining_ms = Signal::derive(move || (target.get() - now_ts.get()).max(0.0));
+    let is_finished = Memo::new(move |_| remaining_ms.get() == 0.0);
+
+    let Pausable { pause, resume, .. } = use_interval_fn_with_options(
+        move || set_now_ts.set(now()),
+        interval,
+        UseIntervalFnOptions::default(),
+    );
+
+    // make sure the remaining time after changing the target isn't based on an outdated timestamp
+    Effect::watch(
+        move || target.get(),
+        move |_, _, _| set_now_ts.set(now()),
+        false,
+    );
+
+    Effect::watch(
+        move || is_finished.get(),
+        move |is_finished, prev_is_finished, _| {
+            if *is_finished {
+                pause();
+
+                if prev_is_finished == Some(&false) {
+                    #[cfg(debug_assertions)]
+                    let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+                    on_complete();
+                }
+            } else {
+                resume();
+            }
+        },
+        // also pauses the interval right away if the target is already in the past
+        true,
+    );
+
+    let total_seconds = Signal::derive(move || (remaining_ms.get() / 1000.0).ceil() as u64);
+
+    UseCountdownReturn {
+        remaining: Signal::derive(move || Duration::from_millis(remaining_ms.get() as u64)),
+        days: Signal::derive(move || total_seconds.get() / 86_400),
+        hours: Signal::derive(move || total_seconds.get() / 3_600 % 24),
+        minutes: Signal::derive(move || total_seconds.get() / 60 % 60),
+        seconds: Signal::derive(move || total_seconds.get() % 60),
+        is_finished: is_finished.into(),
+    }
+}
+
+/// Options for [`use_countdown_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseCountdownOptions {
+    /// Interval in milliseconds in which the remaining time is updated. Defaults to `1000`.
+    #[builder(into)]
+    interval: Signal<u64>,
+
+    /// Called once when the countdown reaches zero.
+    on_complete: Arc<dyn Fn() + Send + Sync>,
+}
+
+impl Default for UseCountdownOptions {
+    fn default() -> Self {
+        Self {
+            interval: Signal::stored(1000),
+            on_complete: Arc::new(|| {}),
+        }
+    }
+}
+
+/// Return type of [`use_countdown`].
+#[derive(Clone, Copy)]
+pub struct UseCountdownReturn {
+    /// The total remaining time
+    pub remaining: Signal<Duration>,
+    /// The full days of the remaining time
+    pub days: Signal<u64>,
+    /// The hours of the remaining time without the full days (`0..24`)
+    pub hours: Signal<u64>,
+    /// The minutes of the remaining time without the full hours (`0..60`)
+    pub minutes: Signal<u64>,
+    /// The seconds of the remaining time without the full minutes (`0..60`)
+    pub seconds: Signal<u64>,
+    /// Whether the target time has been reached
+    pub is_finished: Signal<bool>,
+}
@@ -2,8 +2,8 @@
 
 use crate::core::IntoElementMaybeSignal;
 use crate::{
-    use_mutation_observer_with_options, watch_with_options, UseMutationObserverOptions,
-    WatchOptions,
+    use_document, use_mutation_observer_with_options, watch_with_options,
+    UseMutationObserverOptions, WatchOptions,
 };
 use default_struct_builder::DefaultBuilder;
 use leptos::prelude::*;
@@ -33,6 +33,24 @@ use wasm_bindgen::JsCast;
 /// # }
 /// ```
 ///
+/// Without a target the variable is read from and written to the root element
+/// (`document.documentElement`) which is where global variables declared in `:root` live.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_css_var;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// // reads the theme token declared like `:root { --brand: #3b82f6; }`
+/// let (brand, set_brand) = use_css_var("--brand");
+///
+/// view! {
+///     <span style:color=move || brand.get()>"Brand color"</span>
+/// }
+/// # }
+/// ```
+///
 /// The variable name itself can be a `Signal`.
 ///
 /// ```
@@ -176,14 +194,13 @@ where
     _marker: PhantomData<M>,
 }
 
-#[cfg(feature = "ssr")]
 impl<M> Default for UseCssVarOptions<Option<web_sys::Element>, M>
 where
     Option<web_sys::Element>: IntoElementMaybeSignal<web_sys::Element, M>,
 {
     fn default() -> Self {
         Self {
-            target: None,
+            target: use_document().document_element(),
             initial_value: "".into(),
             observe: false,
             _marker: PhantomData,
@@ -191,15 +208,151 @@ where
     }
 }
 
-#[cfg(not(feature = "ssr"))]
-impl<M> Default for UseCssVarOptions<web_sys::Element, M>
+/// Manipulate a numeric CSS variable with a unit.
+///
+/// Like [`use_css_var`] but the value is a number. The `unit` is appended when the variable is
+/// written and removed when it is read. This is handy to drive a CSS variable with a numeric
+/// signal like a progress in percent.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_css_var_number_with_options, UseCssVarNumberOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let (progress, set_progress) = use_css_var_number_with_options(
+///     "--progress",
+///     UseCssVarNumberOptions::default().target(el).unit("%"),
+/// );
+///
+/// // sets `--progress: 42%`
+/// set_progress.set(42.0);
+///
+/// view! {
+///     <div node_ref=el style="width: var(--progress)">"..."</div>
+/// }
+/// # }
+/// ```
+///
+/// If the variable can't be parsed as a number, the returned signal contains the option
+/// `initial_value` which defaults to `0.0`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this simply returns `signal(options.initial_value)`.
+pub fn use_css_var_number(prop: impl Into<Signal<String>>) -> (Signal<f64>, WriteSignal<f64>) {
+    use_css_var_number_with_options(prop, UseCssVarNumberOptions::default())
+}
+
+/// Version of [`use_css_var_number`] that takes a `UseCssVarNumberOptions`. See [`use_css_var_number`] for how to use.
+pub fn use_css_var_number_with_options<P, El, M>(
+    prop: P,
+    options: UseCssVarNumberOptions<El, M>,
+) -> (Signal<f64>, WriteSignal<f64>)
 where
-    web_sys::Element: IntoElementMaybeSignal<web_sys::Element, M>,
+    P: Into<Signal<String>>,
+    El: Clone,
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UseCssVarNumberOptions {
+        target,
+        unit,
+        initial_value,
+        observe,
+        ..
+    } = options;
+
+    let (number, set_number) = signal(initial_value);
+
+    let (variable, set_variable) = use_css_var_with_options(
+        prop,
+        UseCssVarOptions {
+            target,
+            initial_value: format!("{initial_value}{unit}"),
+            observe,
+            _marker: PhantomData,
+        },
+    );
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let unit = StoredValue::new(unit);
+
+        Effect::watch(
+            move || variable.get(),
+            move |value, _, _| {
+                let value = unit.with_value(|unit| {
+                    let value = value.trim();
+                    value
+                        .strip_suffix(unit.as_str())
+                        .unwrap_or(value)
+                        .trim()
+                        .parse::<f64>()
+                        .unwrap_or(initial_value)
+                });
+
+                if number.get_untracked() != value {
+                    set_number.set(value);
+                }
+            },
+            true,
+        );
+
+        Effect::watch(
+            move || number.get(),
+            move |value, _, _| {
+                let value = unit.with_value(|unit| format!("{value}{unit}"));
+
+                if variable.with_untracked(|variable| variable != &value) {
+                    set_variable.set(value);
+                }
+            },
+            false,
+        );
+    }
+
+    (number.into(), set_number)
+}
+
+/// Options for [`use_css_var_number_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseCssVarNumberOptions<El, M>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    /// The target element to read the variable from and set the variable on.
+    /// Defaults to the `document.documentElement`.
+    target: El,
+
+    /// The unit that is appended to the number like `"px"` or `"%"`. Defaults to "".
+    #[builder(into)]
+    unit: String,
+
+    /// The initial value of the variable before it is read. Also the default value
+    /// if the variable isn't defined on the target or can't be parsed. Defaults to `0.0`.
+    initial_value: f64,
+
+    /// If `true` use a `MutationObserver` to monitor variable changes. Defaults to `false`.
+    observe: bool,
+
+    #[builder(skip)]
+    _marker: PhantomData<M>,
+}
+
+impl<M> Default for UseCssVarNumberOptions<Option<web_sys::Element>, M>
+where
+    Option<web_sys::Element>: IntoElementMaybeSignal<web_sys::Element, M>,
 {
     fn default() -> Self {
         Self {
-            target: document().document_element().expect("No document element"),
-            initial_value: "".into(),
+            target: use_document().document_element(),
+            unit: "".into(),
+            initial_value: 0.0,
             observe: false,
             _marker: PhantomData,
         }
@@ -0,0 +1,123 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::IntoElementMaybeSignal;
+use leptos::prelude::*;
+use std::collections::HashMap;
+use std::time::Duration;
+use wasm_bindgen::JsCast;
+
+/// Manipulate multiple CSS variables of the same element at once.
+///
+/// The keys of `initial` are the names of the variables. Their values are read from the `target`
+/// element and used as fallbacks if a variable is not defined. Whenever the returned map changes,
+/// all variables are written to the element in one go. Variables that are removed from the map are
+/// also removed from the element's style.
+///
+/// This is the same as [`fn@crate::use_css_var`] but for a whole set of variables like the tokens of a theme.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::use_css_vars;
+/// # use std::collections::HashMap;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let (theme, set_theme) = use_css_vars(
+///     el,
+///     HashMap::from([
+///         ("--primary".to_string(), "#3b82f6".to_string()),
+///         ("--background".to_string(), "#ffffff".to_string()),
+///     ]),
+/// );
+///
+/// let dark = move |_| {
+///     set_theme.update(|theme| {
+///         theme.insert("--primary".to_string(), "#60a5fa".to_string());
+///         theme.insert("--background".to_string(), "#111827".to_string());
+///     });
+/// };
+///
+/// view! {
+///     <div node_ref=el>
+///         <button on:click=dark>"Dark theme"</button>
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server this simply returns `signal(initial)`.
+#[allow(clippy::type_complexity)]
+pub fn use_css_vars<El, M>(
+    target: El,
+    initial: HashMap<String, String>,
+) -> (
+    ReadSignal<HashMap<String, String>>,
+    WriteSignal<HashMap<String, String>>,
+)
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let (variables, set_variables) = signal(initial.clone());
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let el_signal = target.into_element_maybe_signal();
+
+        let update_css_vars = move || {
+            if let Some(el) = el_signal.get_untracked() {
+                let style = window().get_computed_style(&el).ok().flatten();
+
+                set_variables.update(|vars| {
+                    for (prop, value) in vars.iter_mut() {
+                        *value = style
+                            .as_ref()
+                            .and_then(|style| style.get_property_value(prop).ok())
+                            .map(|value| value.trim().to_string())
+                            .filter(|value| !value.is_empty())
+                            .or_else(|| initial.get(prop).cloned())
+                            .unwrap_or_default();
+                    }
+                });
+            }
+        };
+
+        // To get around style attributes on node_refs that are not applied after the first render
+        set_timeout(update_css_vars.clone(), Duration::ZERO);
+
+        Effect::watch(
+            move || el_signal.get(),
+            move |_, _, _| update_css_vars(),
+            true,
+        );
+
+        Effect::watch(
+            move || variables.get(),
+            move |vars, prev_vars, _| {
+                if let Some(el) = el_signal.get() {
+                    let el = el.unchecked_into::<web_sys::HtmlElement>();
+                    let style = el.style();
+
+                    if let Some(prev_vars) = prev_vars {
+                        for prop in prev_vars.keys().filter(|prop| !vars.contains_key(*prop)) {
+                            let _ = style.remove_property(prop);
+                        }
+                    }
+
+                    for (prop, value) in vars {
+                        let _ = style.set_property(prop, value);
+                    }
+                }
+            },
+            false,
+        );
+    }
+
+    (variables, set_variables)
+}
@@ -30,6 +30,35 @@ use leptos::prelude::*;
 /// # view! { }
 /// # }
 /// ```
+///
+/// ### Reactive list
+///
+/// The list can be a signal so the values can change at runtime, e.g. when they are loaded
+/// from the server. If the current value is still in the new list, it stays selected. Otherwise
+/// the current index is kept and clamped to the length of the new list.
+///
+/// If the list can be empty at the beginning, provide an `initial_value`. While the list is empty
+/// the current value doesn't change and `next`, `prev` and `shift` do nothing.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// use leptos_use::{use_cycle_list_with_options, UseCycleListOptions, UseCycleListReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (slides, set_slides) = signal(Vec::<String>::new());
+///
+/// let UseCycleListReturn { state, next, prev, .. } = use_cycle_list_with_options(
+///     slides,
+///     UseCycleListOptions::default().initial_value(Some("Loading...".to_string().into())),
+/// );
+///
+/// // later, when the slides have been fetched
+/// set_slides.set(vec!["First".to_string(), "Second".to_string()]);
+/// #
+/// # view! { }
+/// # }
+/// ```
 pub fn use_cycle_list<T, L>(
     list: L,
 ) -> UseCycleListReturn<
@@ -46,6 +75,7 @@ where
     use_cycle_list_with_options(list, UseCycleListOptions::default())
 }
 
+/// Version of [`use_cycle_list`] that takes a `UseCycleListOptions`. See [`use_cycle_list`] for how to use.
 pub fn use_cycle_list_with_options<T, L>(
     list: L,
     options: UseCycleListOptions<T>,
@@ -76,7 +106,9 @@ where
             if let Some(initial_value) = initial_value {
                 initial_value
             } else {
-                MaybeRwSignal::from(first.expect("The provided list shouldn't be empty"))
+                MaybeRwSignal::from(first.expect(
+                    "The provided list shouldn't be empty if no `initial_value` is provided",
+                ))
             }
         }
     };
@@ -96,6 +128,10 @@ where
     let set = move |i: usize| {
         let length = list.read().len();
 
+        if length == 0 {
+            return state.get_untracked();
+        }
+
         let index = i % length;
         let value = list.read()[index].clone();
 
@@ -111,6 +147,10 @@ where
     let shift = move |delta: i64| {
         let length = list.read().len() as i64;
 
+        if length == 0 {
+            return state.get_untracked();
+        }
+
         let i = index.get_untracked() as i64 + delta;
         let index = (i % length) + length;
 
@@ -125,7 +165,35 @@ where
         shift(-1);
     };
 
-    let _ = Effect::watch(move || list.get(), move |_, _, _| set(index.get()), false);
+    // Last index of the current value to keep the position when the value is removed from the list
+    let last_index = StoredValue::new(index.get_untracked());
+
+    let _ = Effect::new(move || {
+        if state
+            .with(|state| get_position(state, &list.read()))
+            .is_some()
+        {
+            last_index.set_value(index.get());
+        }
+    });
+
+    let _ = Effect::watch(
+        move || list.track(),
+        move |_, _, _| {
+            let length = list.read_untracked().len();
+
+            if length == 0
+                || state
+                    .with_untracked(|state| get_position(state, &list.read_untracked()))
+                    .is_some()
+            {
+                return;
+            }
+
+            set(last_index.get_value().min(length - 1));
+        },
+        false,
+    );
 
     UseCycleListReturn {
         state,
@@ -149,8 +217,8 @@ where
     #[builder(keep_type)]
     initial_value: Option<MaybeRwSignal<T>>,
 
-    /// The default index when the current value is not found in the list.
-    /// For example when `get_index_of` returns `None`.
+    /// The index that is returned as `index` when the current value is not found in the list.
+    /// For example when `get_position` returns `None`.
     fallback_index: usize,
 
     /// Custom function to get the index of the current value. Defaults to `Iterator::position()`
@@ -77,7 +77,8 @@ use std::sync::{Arc, Mutex};
 /// ## Server-Side Rendering
 ///
 /// Internally this uses `setTimeout` which is not supported on the server. So usually calling
-/// a debounced function on the server will simply be ignored.
+/// a debounced function on the server will simply be ignored. The returned function can still be
+/// called safely and returns the last return value (if any).
 pub fn use_debounce_fn<F, R>(
     func: F,
     ms: impl Into<Signal<f64>> + 'static,
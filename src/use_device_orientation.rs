@@ -1,5 +1,8 @@
+use crate::PermissionState;
 use cfg_if::cfg_if;
 use leptos::reactive::wrappers::read::Signal;
+use std::future::Future;
+use std::pin::Pin;
 
 /// Reactive [DeviceOrientationEvent](https://developer.mozilla.org/en-US/docs/Web/API/DeviceOrientationEvent).
 ///
@@ -24,35 +27,99 @@ use leptos::reactive::wrappers::read::Signal;
 ///     alpha,
 ///     beta,
 ///     gamma,
+///     ..
 /// } = use_device_orientation();
 /// #
 /// # view! { }
 /// # }
 /// ```
 ///
+/// ### Permission
+///
+/// On iOS 13+ orientation events are only fired after the user granted the permission via
+/// `DeviceOrientationEvent.requestPermission()`. This has to be called from a user gesture like a
+/// click, so it's up to you to call the returned `request_permission`. The returned future
+/// resolves with the new permission which is also available as the signal `permission`.
+///
+/// On browsers that don't require a permission, `permission` is `PermissionState::Granted` right
+/// away and `request_permission` resolves to it immediately.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::task::spawn_local;
+/// # use leptos_use::{use_device_orientation, PermissionState, UseDeviceOrientationReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseDeviceOrientationReturn {
+///     permission,
+///     request_permission,
+///     ..
+/// } = use_device_orientation();
+///
+/// let enable = move |_| {
+///     let request_permission = request_permission.clone();
+///     spawn_local(async move {
+///         request_permission().await;
+///     });
+/// };
+///
+/// view! {
+///     <Show when=move || permission.get() == PermissionState::Prompt>
+///         <button on:click=enable.clone()>"Enable motion controls"</button>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `request_permission` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_device_orientation`.
+///
 /// ## Server-Side Rendering
 ///
 /// On the server this function returns values as if the orientation
-/// capabilties were not supported by the device.
-pub fn use_device_orientation() -> UseDeviceOrientationReturn {
+/// capabilties were not supported by the device. `permission` is `PermissionState::Unknown` and
+/// `request_permission` resolves to it.
+pub fn use_device_orientation(
+) -> UseDeviceOrientationReturn<impl Fn() -> DeviceOrientationPermissionFuture + Clone + Send + Sync>
+{
     cfg_if! { if #[cfg(feature = "ssr")] {
         let is_supported = Signal::derive(|| false);
         let absolute = Signal::derive(|| false);
         let alpha = Signal::derive(|| None);
         let beta = Signal::derive(|| None);
         let gamma = Signal::derive(|| None);
+        let permission = Signal::derive(|| PermissionState::Unknown);
+
+        let request_permission = move || {
+            Box::pin(async { PermissionState::Unknown }) as DeviceOrientationPermissionFuture
+        };
     } else {
         use leptos::prelude::*;
-        use crate::{use_event_listener_with_options, UseEventListenerOptions, use_supported, js};
+        use crate::{use_event_listener_with_options, UseEventListenerOptions, use_supported_immediately, js};
         use leptos::ev::deviceorientation;
         use send_wrapper::SendWrapper;
 
-        let is_supported = use_supported(|| js!("DeviceOrientationEvent" in &window()));
+        let is_supported = use_supported_immediately(|| js!("DeviceOrientationEvent" in &window()));
         let (absolute, set_absolute) = signal(false);
         let (alpha, set_alpha) = signal(None);
         let (beta, set_beta) = signal(None);
         let (gamma, set_gamma) = signal(None);
 
+        let requires_permission = is_supported.get_untracked()
+            && device_orientation_event()
+                .is_some_and(|event| js!("requestPermission" in &event));
+
+        let (permission, set_permission) = signal(if !is_supported.get_untracked() {
+            PermissionState::Unknown
+        } else if requires_permission {
+            PermissionState::Prompt
+        } else {
+            PermissionState::Granted
+        });
+
         if is_supported.get_untracked() {
             let cleanup = use_event_listener_with_options(
                 window(),
@@ -75,6 +142,27 @@ pub fn use_device_orientation() -> UseDeviceOrientationReturn {
                 move || cleanup()
             });
         }
+
+        let request_permission = {
+            let request_permission = move || {
+                // Has to be called synchronously so it still counts as part of the user gesture
+                let promise = requires_permission
+                    .then(request_device_orientation_permission)
+                    .flatten();
+
+                Box::pin(async move {
+                    if let Some(promise) = promise {
+                        if let Some(state) = await_device_orientation_permission(promise).await {
+                            set_permission.set(state);
+                        }
+                    }
+
+                    permission.get_untracked()
+                }) as DeviceOrientationPermissionFuture
+            };
+            let wrapped_request_permission = SendWrapper::new(request_permission);
+            move || wrapped_request_permission()
+        };
     }}
 
     #[allow(clippy::useless_conversion)]
@@ -84,15 +172,70 @@ pub fn use_device_orientation() -> UseDeviceOrientationReturn {
         alpha: alpha.into(),
         beta: beta.into(),
         gamma: gamma.into(),
+        permission: permission.into(),
+        request_permission,
     }
 }
 
+/// The `DeviceOrientationEvent` constructor which has the static method `requestPermission` on iOS.
+#[cfg(not(feature = "ssr"))]
+fn device_orientation_event() -> Option<wasm_bindgen::JsValue> {
+    js_sys::Reflect::get(&leptos::prelude::window(), &"DeviceOrientationEvent".into())
+        .ok()
+        .filter(|event| !event.is_undefined())
+}
+
+/// Calls `DeviceOrientationEvent.requestPermission()` and returns the pending promise.
+/// Returns `None` if the method doesn't exist.
+#[cfg(not(feature = "ssr"))]
+fn request_device_orientation_permission() -> Option<js_sys::Promise> {
+    use crate::js;
+    use wasm_bindgen::JsCast;
+
+    let event = device_orientation_event()?;
+
+    let request_permission = js!(event["requestPermission"])
+        .ok()?
+        .dyn_into::<js_sys::Function>()
+        .ok()?;
+
+    request_permission
+        .call0(&event)
+        .ok()?
+        .dyn_into::<js_sys::Promise>()
+        .ok()
+}
+
+/// Awaits the promise returned by [`request_device_orientation_permission`] and returns the
+/// resulting permission. Returns `None` if the request failed, e.g. because it wasn't called
+/// from a user gesture.
+#[cfg(not(feature = "ssr"))]
+async fn await_device_orientation_permission(promise: js_sys::Promise) -> Option<PermissionState> {
+    let state = crate::js_fut!(promise).await.ok()?;
+
+    web_sys::PermissionState::from_js_value(&state).map(PermissionState::from)
+}
+
+/// Future returned by [`UseDeviceOrientationReturn::request_permission`].
+pub type DeviceOrientationPermissionFuture = Pin<Box<dyn Future<Output = PermissionState>>>;
+
 /// Return type of [`use_device_orientation`].
 #[derive(Clone)]
-pub struct UseDeviceOrientationReturn {
+pub struct UseDeviceOrientationReturn<RequestPermissionFn>
+where
+    RequestPermissionFn: Fn() -> DeviceOrientationPermissionFuture + Clone + Send + Sync,
+{
     pub is_supported: Signal<bool>,
     pub absolute: Signal<bool>,
     pub alpha: Signal<Option<f64>>,
     pub beta: Signal<Option<f64>>,
     pub gamma: Signal<Option<f64>>,
+
+    /// Whether the orientation events are allowed to be fired. Only iOS requires the user to
+    /// grant the permission. See [`use_device_orientation`] for details.
+    pub permission: Signal<PermissionState>,
+
+    /// Requests the permission on iOS. Has to be called from a user gesture like a click.
+    /// Resolves to the new permission.
+    pub request_permission: RequestPermissionFn,
 }
@@ -0,0 +1,241 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{js, sendwrap_fn, use_supported_immediately};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{MediaDeviceInfo, MediaDeviceKind};
+
+/// Reactive [`mediaDevices.enumerateDevices`](https://developer.mozilla.org/en-US/docs/Web/API/MediaDevices/enumerateDevices)
+/// listing the available media input and output devices.
+///
+/// The list is updated whenever a device is connected or disconnected. This is the companion to
+/// [`fn@crate::use_user_media`] for building device pickers.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_devices_list, UseDevicesListReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseDevicesListReturn {
+///     video_inputs,
+///     ensure_permissions,
+///     ..
+/// } = use_devices_list();
+///
+/// view! {
+///     <button on:click=move |_| ensure_permissions()>"Show camera names"</button>
+///     <select>
+///         {move || {
+///             video_inputs
+///                 .get()
+///                 .into_iter()
+///                 .map(|device| view! { <option value=device.device_id()>{device.label()}</option> })
+///                 .collect_view()
+///         }}
+///     </select>
+/// }
+/// # }
+/// ```
+///
+/// ### Permissions
+///
+/// Browsers only reveal the labels of the devices after the user granted permission to access
+/// them. Call `ensure_permissions` to request a short-lived media stream that is immediately
+/// stopped again. Afterwards the list is refreshed and contains the labels.
+/// You can also request the permissions right away with the option `request_permissions`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_devices_list_with_options, UseDevicesListOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let devices = use_devices_list_with_options(
+///     UseDevicesListOptions::default()
+///         .request_permissions(true)
+///         .video(false),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `ensure_permissions` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_devices_list`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server all device lists are empty and `is_supported` is `false`.
+/// Calling `ensure_permissions` does nothing.
+pub fn use_devices_list() -> UseDevicesListReturn<impl Fn() + Clone + Send + Sync> {
+    use_devices_list_with_options(UseDevicesListOptions::default())
+}
+
+/// Version of [`use_devices_list`] that takes a `UseDevicesListOptions`. See [`use_devices_list`] for how to use.
+pub fn use_devices_list_with_options(
+    options: UseDevicesListOptions,
+) -> UseDevicesListReturn<impl Fn() + Clone + Send + Sync> {
+    let UseDevicesListOptions {
+        request_permissions,
+        audio,
+        video,
+    } = options;
+
+    let is_supported = use_supported_immediately(|| js!("mediaDevices" in &window().navigator()));
+
+    let (devices, set_devices) = signal_local(Vec::<MediaDeviceInfo>::new());
+    let (permission_granted, set_permission_granted) = signal(false);
+
+    let update = move || {
+        #[cfg(not(feature = "ssr"))]
+        if is_supported.get_untracked() {
+            leptos::task::spawn_local(async move {
+                if let Ok(list) = enumerate_devices().await {
+                    set_devices.set(list);
+                }
+            });
+        }
+    };
+
+    let ensure_permissions = sendwrap_fn!(move || {
+        #[cfg(not(feature = "ssr"))]
+        if is_supported.get_untracked() && !permission_granted.get_untracked() {
+            leptos::task::spawn_local(async move {
+                if request_media(audio, video).await.is_ok() {
+                    set_permission_granted.set(true);
+                }
+                update();
+            });
+        }
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        use crate::use_event_listener;
+        use leptos::ev::Custom;
+
+        if let Ok(media_devices) = window().navigator().media_devices() {
+            let _ = use_event_listener(
+                media_devices,
+                Custom::<web_sys::Event>::new("devicechange"),
+                move |_| update(),
+            );
+        }
+
+        update();
+
+        if request_permissions {
+            ensure_permissions();
+        }
+    }
+
+    let devices_of_kind = move |kind: MediaDeviceKind| {
+        Signal::derive_local(move || {
+            devices.with(|devices| {
+                devices
+                    .iter()
+                    .filter(|device| device.kind() == kind)
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+        })
+    };
+
+    UseDevicesListReturn {
+        is_supported,
+        devices: devices.into(),
+        video_inputs: devices_of_kind(MediaDeviceKind::Videoinput),
+        audio_inputs: devices_of_kind(MediaDeviceKind::Audioinput),
+        audio_outputs: devices_of_kind(MediaDeviceKind::Audiooutput),
+        permission_granted: permission_granted.into(),
+        ensure_permissions,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn enumerate_devices() -> Result<Vec<MediaDeviceInfo>, wasm_bindgen::JsValue> {
+    let promise = window().navigator().media_devices()?.enumerate_devices()?;
+    let list = crate::js_fut!(promise).await?;
+
+    Ok(js_sys::Array::from(&list)
+        .iter()
+        .map(|device| device.unchecked_into::<MediaDeviceInfo>())
+        .collect())
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn request_media(audio: bool, video: bool) -> Result<(), wasm_bindgen::JsValue> {
+    let constraints = web_sys::MediaStreamConstraints::new();
+    constraints.set_audio(&audio.into());
+    constraints.set_video(&video.into());
+
+    let promise = window()
+        .navigator()
+        .media_devices()?
+        .get_user_media_with_constraints(&constraints)?;
+    let stream = crate::js_fut!(promise).await?;
+    let stream = stream.unchecked_into::<web_sys::MediaStream>();
+
+    for track in stream.get_tracks() {
+        track.unchecked_ref::<web_sys::MediaStreamTrack>().stop();
+    }
+
+    Ok(())
+}
+
+/// Options for [`use_devices_list_with_options`].
+#[derive(DefaultBuilder, Clone, Copy)]
+pub struct UseDevicesListOptions {
+    /// Request the permissions immediately. Defaults to `false`.
+    request_permissions: bool,
+
+    /// Request access to audio devices when the permissions are requested. Defaults to `true`.
+    audio: bool,
+
+    /// Request access to video devices when the permissions are requested. Defaults to `true`.
+    video: bool,
+}
+
+impl Default for UseDevicesListOptions {
+    fn default() -> Self {
+        Self {
+            request_permissions: false,
+            audio: true,
+            video: true,
+        }
+    }
+}
+
+/// Return type of [`use_devices_list`].
+#[derive(Clone)]
+pub struct UseDevicesListReturn<EnsureFn>
+where
+    EnsureFn: Fn() + Clone + Send + Sync,
+{
+    /// Whether `navigator.mediaDevices` is supported by the browser.
+    pub is_supported: Signal<bool>,
+
+    /// All available media devices.
+    pub devices: Signal<Vec<MediaDeviceInfo>, LocalStorage>,
+
+    /// All available video input devices like cameras.
+    pub video_inputs: Signal<Vec<MediaDeviceInfo>, LocalStorage>,
+
+    /// All available audio input devices like microphones.
+    pub audio_inputs: Signal<Vec<MediaDeviceInfo>, LocalStorage>,
+
+    /// All available audio output devices like speakers or headphones.
+    pub audio_outputs: Signal<Vec<MediaDeviceInfo>, LocalStorage>,
+
+    /// Whether the user granted access to the devices through `ensure_permissions`.
+    pub permission_granted: Signal<bool>,
+
+    /// Requests permission to access the devices so that their labels become available.
+    pub ensure_permissions: EnsureFn,
+}
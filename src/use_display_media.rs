@@ -41,6 +41,26 @@ use wasm_bindgen::{JsCast, JsValue};
 /// # }
 /// ```
 ///
+/// To record a browser tab including its audio you can provide a few hints to the browser.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_display_media_with_options, DisplaySurface, UseDisplayMediaOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let display_media = use_display_media_with_options(
+///     UseDisplayMediaOptions::default()
+///         .audio(true)
+///         .preferred_display_surface(DisplaySurface::Browser)
+///         .self_browser_surface(false)
+///         .surface_switching(true),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
 /// The returned closures `start` and `stop` are sendwrapped functions. They can
@@ -59,7 +79,13 @@ pub fn use_display_media(
 pub fn use_display_media_with_options(
     options: UseDisplayMediaOptions,
 ) -> UseDisplayMediaReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
-    let UseDisplayMediaOptions { enabled, audio } = options;
+    let UseDisplayMediaOptions {
+        enabled,
+        audio,
+        preferred_display_surface,
+        self_browser_surface,
+        surface_switching,
+    } = options;
 
     let (enabled, set_enabled) = enabled.into_signal();
 
@@ -71,11 +97,20 @@ pub fn use_display_media_with_options(
                 return;
             }
 
-            let stream = create_media(audio).await;
+            let stream = create_media(
+                audio,
+                preferred_display_surface,
+                self_browser_surface,
+                surface_switching,
+            )
+            .await;
 
             set_stream.update(|s| *s = Some(stream));
         } else {
             let _ = audio;
+            let _ = preferred_display_surface;
+            let _ = self_browser_surface;
+            let _ = surface_switching;
         }}
     };
 
@@ -131,9 +166,14 @@ pub fn use_display_media_with_options(
 }
 
 #[cfg(not(feature = "ssr"))]
-async fn create_media(audio: bool) -> Result<web_sys::MediaStream, JsValue> {
-    use crate::js_fut;
+async fn create_media(
+    audio: bool,
+    preferred_display_surface: Option<DisplaySurface>,
+    self_browser_surface: Option<bool>,
+    surface_switching: Option<bool>,
+) -> Result<web_sys::MediaStream, JsValue> {
     use crate::use_window::use_window;
+    use crate::{js, js_fut};
 
     let media = use_window()
         .navigator()
@@ -145,12 +185,35 @@ async fn create_media(audio: bool) -> Result<web_sys::MediaStream, JsValue> {
         constraints.set_audio(&JsValue::from(true));
     }
 
+    if let Some(display_surface) = preferred_display_surface {
+        let video = js_sys::Object::new();
+        js!(video["displaySurface"] = display_surface.as_str());
+        constraints.set_video(&video);
+    }
+
+    if let Some(self_browser_surface) = self_browser_surface {
+        js!(constraints["selfBrowserSurface"] = include_or_exclude(self_browser_surface));
+    }
+
+    if let Some(surface_switching) = surface_switching {
+        js!(constraints["surfaceSwitching"] = include_or_exclude(surface_switching));
+    }
+
     let promise = media.get_display_media_with_constraints(&constraints)?;
     let res = js_fut!(promise).await?;
 
     Ok::<_, JsValue>(web_sys::MediaStream::unchecked_from_js(res))
 }
 
+#[cfg(not(feature = "ssr"))]
+fn include_or_exclude(include: bool) -> &'static str {
+    if include {
+        "include"
+    } else {
+        "exclude"
+    }
+}
+
 // NOTE: there's no video value because it has to be `true`. Otherwise the stream would always resolve to an Error.
 /// Options for [`use_display_media`].
 #[derive(DefaultBuilder, Clone, Copy, Debug)]
@@ -162,6 +225,21 @@ pub struct UseDisplayMediaOptions {
     /// will contain an audio track, if audio is supported and available for the display surface chosen by the user.
     /// The default value is `false`.
     audio: bool,
+
+    /// Hint which kind of display surface should be offered first to the user in the selection dialog.
+    /// The user can still choose a different one. Defaults to `None` which leaves the choice to the browser.
+    #[builder(into)]
+    preferred_display_surface: Option<DisplaySurface>,
+
+    /// Hint whether the current tab should be offered to the user as a display surface.
+    /// Defaults to `None` which leaves the choice to the browser.
+    #[builder(into)]
+    self_browser_surface: Option<bool>,
+
+    /// Hint whether the browser should allow the user to dynamically switch the shared tab while sharing.
+    /// Defaults to `None` which leaves the choice to the browser.
+    #[builder(into)]
+    surface_switching: Option<bool>,
 }
 
 impl Default for UseDisplayMediaOptions {
@@ -169,6 +247,31 @@ impl Default for UseDisplayMediaOptions {
         Self {
             enabled: false.into(),
             audio: false,
+            preferred_display_surface: None,
+            self_browser_surface: None,
+            surface_switching: None,
+        }
+    }
+}
+
+/// Kind of display surface that can be captured by [`use_display_media`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DisplaySurface {
+    /// The whole screen
+    Monitor,
+    /// A single application window
+    Window,
+    /// A browser tab
+    Browser,
+}
+
+impl DisplaySurface {
+    /// The value of the `displaySurface` constraint.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DisplaySurface::Monitor => "monitor",
+            DisplaySurface::Window => "window",
+            DisplaySurface::Browser => "browser",
         }
     }
 }
@@ -33,6 +33,30 @@ use web_sys::{
 /// # view! { }
 /// # }
 /// ```
+///
+/// To use anything that doesn't have a convenience method, use `with`. The closure is only
+/// called in the browser. On the server `with` returns `None`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_document;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let has_focus = use_document().with(|document| document.has_focus().unwrap_or_default());
+///
+/// if use_document().is_ssr() {
+///     // we're on the server
+/// }
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server this is always `None`, so all methods return `None` or a sensible default instead
+/// of panicking like `leptos::prelude::document()` would.
 pub fn use_document() -> UseDocument {
     cfg_if! { if #[cfg(feature = "ssr")] {
         UseDocument(None)
@@ -53,6 +77,19 @@ impl Deref for UseDocument {
 }
 
 impl UseDocument {
+    /// Calls `f` with the document in the browser and returns its result. Returns `None` on the server
+    /// without calling `f`.
+    #[inline(always)]
+    pub fn with<R>(&self, f: impl FnOnce(&Document) -> R) -> Option<R> {
+        self.0.as_ref().map(f)
+    }
+
+    /// Returns `true` on the server where there is no document.
+    #[inline(always)]
+    pub fn is_ssr(&self) -> bool {
+        self.0.is_none()
+    }
+
     impl_ssr_safe_method!(
         /// Returns `Some(Document)` in the Browser. `None` otherwise.
         body(&self) -> Option<HtmlElement>;
@@ -45,6 +45,39 @@ use web_sys::PointerEvent;
 /// }
 /// # }
 /// ```
+///
+/// ### Grid and drop
+///
+/// With the option `grid` the position snaps to a grid while dragging. If you only want to
+/// commit the new position when the dragging ends, use `on_drop`. It is called once with
+/// the final position if the element has been moved.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_draggable_with_options, UseDraggableOptions, UseDraggableReturn};
+/// # use leptos_use::core::Position;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let UseDraggableReturn { style, .. } = use_draggable_with_options(
+///     el,
+///     UseDraggableOptions::default()
+///         .grid((20.0, 20.0))
+///         .on_drop(|position: Position| {
+///             leptos::logging::log!("Dropped at {}, {}", position.x, position.y);
+///         }),
+/// );
+///
+/// view! {
+///     <div node_ref=el style=move || format!("position: fixed; {}", style.get())>
+///         "Drag me!"
+///     </div>
+/// }
+/// # }
+/// ```
 pub fn use_draggable<El, M>(target: El) -> UseDraggableReturn
 where
     El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
@@ -73,6 +106,8 @@ where
         on_start,
         on_move,
         on_end,
+        grid,
+        on_drop,
         ..
     } = options;
 
@@ -86,6 +121,9 @@ where
 
     let (position, set_position) = initial_value.into_signal();
     let (start_position, set_start_position) = signal(None::<Position>);
+    // Position of the element when the dragging started
+    let drag_start_position = StoredValue::new(None::<Position>);
+    let current_position = position;
 
     let filter_event = move |event: &PointerEvent| {
         let ty = event.pointer_type();
@@ -138,6 +176,7 @@ where
                 drop(zone);
 
                 set_start_position.set(Some(position));
+                drag_start_position.set_value(Some(current_position.get_untracked()));
                 handle_event(event);
             }
         }
@@ -151,10 +190,13 @@ where
                 return;
             }
             if let Some(start_position) = start_position.get_untracked() {
-                let position = Position {
+                let mut position = Position {
                     x: event.client_x() as f64 - start_position.x,
                     y: event.client_y() as f64 - start_position.y,
                 };
+                if let Some((grid_x, grid_y)) = grid {
+                    position = snap_to_grid(position, grid_x, grid_y);
+                }
                 set_position.set(position);
 
                 #[cfg(debug_assertions)]
@@ -185,11 +227,22 @@ where
         #[cfg(debug_assertions)]
         let zone = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
 
+        let final_position = position.get_untracked();
+
         on_end(UseDraggableCallbackArgs {
-            position: position.get_untracked(),
+            position: final_position,
             event: event.clone(),
         });
 
+        let has_moved = drag_start_position
+            .get_value()
+            .map(|start| start.x != final_position.x || start.y != final_position.y)
+            .unwrap_or_default();
+
+        if has_moved {
+            on_drop(final_position);
+        }
+
         #[cfg(debug_assertions)]
         drop(zone);
 
@@ -273,6 +326,14 @@ where
     /// Callback when dragging end.
     on_end: Arc<dyn Fn(UseDraggableCallbackArgs) + Send + Sync>,
 
+    /// Snap the position to a grid of the given `(width, height)` while dragging. Defaults to `None`.
+    #[builder(into)]
+    grid: Option<(f64, f64)>,
+
+    /// Callback that is called once when the dragging ends with the final position.
+    /// In contrast to `on_end` it is only called if the element has actually been moved.
+    on_drop: Arc<dyn Fn(Position) + Send + Sync>,
+
     #[builder(skip)]
     _marker1: PhantomData<DragM>,
     #[builder(skip)]
@@ -297,12 +358,29 @@ where
             on_start: Arc::new(|_| true),
             on_move: Arc::new(|_| {}),
             on_end: Arc::new(|_| {}),
+            grid: None,
+            on_drop: Arc::new(|_| {}),
             _marker1: PhantomData,
             _marker2: PhantomData,
         }
     }
 }
 
+fn snap_to_grid(position: Position, grid_x: f64, grid_y: f64) -> Position {
+    let snap = |value: f64, grid: f64| {
+        if grid > 0.0 {
+            (value / grid).round() * grid
+        } else {
+            value
+        }
+    };
+
+    Position {
+        x: snap(position.x, grid_x),
+        y: snap(position.y, grid_y),
+    }
+}
+
 /// Argument for the `on_...` handler functions of [`UseDraggableOptions`].
 pub struct UseDraggableCallbackArgs {
     /// Position of the `target` element
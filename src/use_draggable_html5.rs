@@ -0,0 +1,184 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::IntoElementMaybeSignal;
+use codee::Encoder;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Make an element draggable with the native [HTML Drag and Drop API](https://developer.mozilla.org/en-US/docs/Web/API/HTML_Drag_and_Drop_API).
+///
+/// In contrast to [`fn@crate::use_draggable`], which moves an element with pointer events,
+/// this uses the browser's drag and drop. This means you get the native drag image and can
+/// drag data across windows and even to other applications.
+///
+/// When the dragging starts, `payload` is encoded with the given codec and put into the
+/// [`DataTransfer`](https://developer.mozilla.org/en-US/docs/Web/API/DataTransfer) of the drag event.
+/// Use [`fn@crate::use_droppable`] with the same codec to receive it.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_draggable_html5, UseDraggableHtml5Return};
+/// # use codee::string::JsonSerdeCodec;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, Clone)]
+/// struct Card {
+///     id: u32,
+///     title: String,
+/// }
+///
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let card = Card { id: 1, title: "Write docs".to_string() };
+///
+/// let UseDraggableHtml5Return { is_dragging } =
+///     use_draggable_html5::<Card, JsonSerdeCodec, _, _>(el, card);
+///
+/// view! {
+///     <div node_ref=el class:dragging=is_dragging>"Drag me"</div>
+/// }
+/// # }
+/// ```
+///
+/// By default the payload is stored with the format `"text/plain"` so it can be dropped into
+/// other applications as well. You can change that with the option `format`.
+///
+/// > Please check [the codec chapter](https://leptos-use.rs/codecs.html) to see what codecs are
+/// > available and what feature flags they require.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this amounts to a no-op and `is_dragging` is always `false`.
+pub fn use_draggable_html5<T, C, El, M>(
+    target: El,
+    payload: impl Into<Signal<T>>,
+) -> UseDraggableHtml5Return
+where
+    T: Send + Sync + 'static,
+    C: Encoder<T, Encoded = String>,
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_draggable_html5_with_options::<T, C, El, M>(
+        target,
+        payload,
+        UseDraggableHtml5Options::default(),
+    )
+}
+
+/// Version of [`use_draggable_html5`] that takes a `UseDraggableHtml5Options`. See [`use_draggable_html5`] for how to use.
+pub fn use_draggable_html5_with_options<T, C, El, M>(
+    target: El,
+    payload: impl Into<Signal<T>>,
+    options: UseDraggableHtml5Options,
+) -> UseDraggableHtml5Return
+where
+    T: Send + Sync + 'static,
+    C: Encoder<T, Encoded = String>,
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UseDraggableHtml5Options {
+        format,
+        effect_allowed,
+        on_start,
+        on_end,
+    } = options;
+
+    let payload = payload.into();
+
+    let (is_dragging, set_dragging) = signal(false);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use leptos::ev::{dragend, dragstart};
+
+        let target = target.into_element_maybe_signal();
+
+        Effect::new(move || {
+            if let Some(el) = target.get() {
+                let _ = el.set_attribute("draggable", "true");
+            }
+        });
+
+        let _ = use_event_listener(target, dragstart, move |event| {
+            if let Some(data_transfer) = event.data_transfer() {
+                match payload.with_untracked(|payload| C::encode(payload)) {
+                    Ok(encoded) => {
+                        let _ = data_transfer.set_data(&format, &encoded);
+                    }
+                    Err(_) => {
+                        leptos::logging::error!("drag payload serialization error");
+                    }
+                }
+
+                data_transfer.set_effect_allowed(&effect_allowed);
+            }
+
+            set_dragging.set(true);
+
+            #[cfg(debug_assertions)]
+            let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+            on_start(event);
+        });
+
+        let _ = use_event_listener(target, dragend, move |event| {
+            set_dragging.set(false);
+
+            #[cfg(debug_assertions)]
+            let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+            on_end(event);
+        });
+    }
+
+    UseDraggableHtml5Return {
+        is_dragging: is_dragging.into(),
+    }
+}
+
+/// Options for [`use_draggable_html5_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseDraggableHtml5Options {
+    /// The format (MIME type) the encoded payload is stored with in the `DataTransfer`.
+    /// Defaults to `"text/plain"`.
+    #[builder(into)]
+    format: String,
+
+    /// Which operations are allowed for the dragged data. One of `"none"`, `"copy"`, `"copyLink"`,
+    /// `"copyMove"`, `"link"`, `"linkMove"`, `"move"`, `"all"`. Defaults to `"all"`.
+    /// See [`effectAllowed`](https://developer.mozilla.org/en-US/docs/Web/API/DataTransfer/effectAllowed).
+    #[builder(into)]
+    effect_allowed: String,
+
+    /// Event handler for the [`dragstart`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/dragstart_event) event.
+    /// Called after the payload has been set.
+    on_start: Arc<dyn Fn(web_sys::DragEvent) + Send + Sync>,
+
+    /// Event handler for the [`dragend`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/dragend_event) event.
+    on_end: Arc<dyn Fn(web_sys::DragEvent) + Send + Sync>,
+}
+
+impl Default for UseDraggableHtml5Options {
+    fn default() -> Self {
+        Self {
+            format: "text/plain".to_string(),
+            effect_allowed: "all".to_string(),
+            on_start: Arc::new(|_| {}),
+            on_end: Arc::new(|_| {}),
+        }
+    }
+}
+
+/// Return type of [`use_draggable_html5`].
+#[derive(Clone, Copy)]
+pub struct UseDraggableHtml5Return {
+    /// Whether the element is being dragged
+    pub is_dragging: Signal<bool>,
+}
@@ -0,0 +1,222 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::IntoElementMaybeSignal;
+use codee::Decoder;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Create a drop target for the native [HTML Drag and Drop API](https://developer.mozilla.org/en-US/docs/Web/API/HTML_Drag_and_Drop_API).
+///
+/// This is the counterpart of [`fn@crate::use_draggable_html5`]. The dropped data is decoded with
+/// the given codec. Dropped files are provided as well, so this also works for files dragged
+/// in from the operating system. If you only need files, have a look at [`fn@crate::use_drop_zone`].
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_droppable_with_options, UseDroppableEvent, UseDroppableOptions, UseDroppableReturn};
+/// # use codee::string::JsonSerdeCodec;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, Clone)]
+/// struct Card {
+///     id: u32,
+///     title: String,
+/// }
+///
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let column = NodeRef::<Div>::new();
+///
+/// let UseDroppableReturn { is_over, .. } = use_droppable_with_options::<Card, JsonSerdeCodec, _, _>(
+///     column,
+///     UseDroppableOptions::default().on_drop(|event: UseDroppableEvent<Card>| {
+///         if let Some(card) = event.data {
+///             leptos::logging::log!("Card {} dropped", card.id);
+///         }
+///     }),
+/// );
+///
+/// view! {
+///     <div node_ref=column class:highlight=is_over>"Drop cards here"</div>
+/// }
+/// # }
+/// ```
+///
+/// The data is read with the format `"text/plain"` by default which is also the default of
+/// [`fn@crate::use_draggable_html5`]. You can change that with the option `format`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this amounts to a no-op. `is_over` is always `false` and `data` always `None`.
+pub fn use_droppable<T, C, El, M>(target: El) -> UseDroppableReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+    C: Decoder<T, Encoded = str>,
+    El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+{
+    use_droppable_with_options::<T, C, El, M>(target, UseDroppableOptions::default())
+}
+
+/// Version of [`use_droppable`] that takes a `UseDroppableOptions`. See [`use_droppable`] for how to use.
+pub fn use_droppable_with_options<T, C, El, M>(
+    target: El,
+    options: UseDroppableOptions<T>,
+) -> UseDroppableReturn<T>
+where
+    T: Clone + Send + Sync + 'static,
+    C: Decoder<T, Encoded = str>,
+    El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+{
+    let UseDroppableOptions {
+        format,
+        drop_effect,
+        on_drop,
+    } = options;
+
+    let (is_over, set_over) = signal(false);
+    let (data, set_data) = signal(None::<T>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use leptos::ev::{dragenter, dragleave, dragover};
+
+        let target = target.into_element_maybe_signal();
+
+        let counter = StoredValue::new(0_usize);
+
+        let _ = use_event_listener(target, dragenter, move |event| {
+            event.prevent_default();
+            counter.update_value(|counter| *counter += 1);
+            set_over.set(true);
+        });
+
+        let _ = use_event_listener(target, dragover, move |event| {
+            // necessary to allow dropping
+            event.prevent_default();
+
+            if let Some(data_transfer) = event.data_transfer() {
+                data_transfer.set_drop_effect(&drop_effect);
+            }
+        });
+
+        let _ = use_event_listener(target, dragleave, move |event| {
+            event.prevent_default();
+            counter.update_value(|counter| *counter = counter.saturating_sub(1));
+            if counter.get_value() == 0 {
+                set_over.set(false);
+            }
+        });
+
+        let _ = use_event_listener(target, leptos::ev::drop, move |event| {
+            event.prevent_default();
+            counter.set_value(0);
+            set_over.set(false);
+
+            let data_transfer = event.data_transfer();
+
+            let decoded = data_transfer
+                .as_ref()
+                .and_then(|data_transfer| data_transfer.get_data(&format).ok())
+                .filter(|data| !data.is_empty())
+                .and_then(|data| {
+                    C::decode(&data)
+                        .map_err(|_| leptos::logging::error!("drop data deserialization error"))
+                        .ok()
+                });
+
+            let files = data_transfer
+                .and_then(|data_transfer| data_transfer.files())
+                .map(|files| js_sys::Array::from(&files).to_vec())
+                .unwrap_or_default()
+                .into_iter()
+                .map(web_sys::File::from)
+                .collect();
+
+            #[cfg(debug_assertions)]
+            let zone = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+            on_drop(UseDroppableEvent {
+                data: decoded.clone(),
+                files,
+                event,
+            });
+
+            #[cfg(debug_assertions)]
+            drop(zone);
+
+            set_data.set(decoded);
+        });
+    }
+
+    UseDroppableReturn {
+        is_over: is_over.into(),
+        data: data.into(),
+    }
+}
+
+/// Options for [`use_droppable_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseDroppableOptions<T> {
+    /// The format (MIME type) the data is read from the `DataTransfer`. Defaults to `"text/plain"`.
+    #[builder(into)]
+    format: String,
+
+    /// The operation that is shown to the user while dragging over the target. One of `"none"`,
+    /// `"copy"`, `"link"` or `"move"`. Defaults to `"move"`.
+    /// See [`dropEffect`](https://developer.mozilla.org/en-US/docs/Web/API/DataTransfer/dropEffect).
+    #[builder(into)]
+    drop_effect: String,
+
+    /// Event handler for the [`drop`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/drop_event) event.
+    #[builder(skip)]
+    on_drop: Arc<dyn Fn(UseDroppableEvent<T>) + Send + Sync>,
+}
+
+impl<T> Default for UseDroppableOptions<T> {
+    fn default() -> Self {
+        Self {
+            format: "text/plain".to_string(),
+            drop_effect: "move".to_string(),
+            on_drop: Arc::new(|_| {}),
+        }
+    }
+}
+
+impl<T> UseDroppableOptions<T> {
+    /// Event handler for the [`drop`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/drop_event) event.
+    pub fn on_drop<F>(self, on_drop: F) -> Self
+    where
+        F: Fn(UseDroppableEvent<T>) + Send + Sync + 'static,
+    {
+        Self {
+            on_drop: Arc::new(on_drop),
+            ..self
+        }
+    }
+}
+
+/// Event passed to the `on_drop` handler of [`UseDroppableOptions`].
+pub struct UseDroppableEvent<T> {
+    /// The decoded data. `None` if there is no data with the given format or it couldn't be decoded.
+    pub data: Option<T>,
+    /// The dropped files
+    pub files: Vec<web_sys::File>,
+    /// The original drag event
+    pub event: web_sys::DragEvent,
+}
+
+/// Return type of [`use_droppable`].
+pub struct UseDroppableReturn<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Whether something is dragged over the target
+    pub is_over: Signal<bool>,
+    /// The data of the last drop
+    pub data: Signal<Option<T>>,
+}
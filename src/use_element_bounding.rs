@@ -27,6 +27,39 @@ use leptos::reactive::wrappers::read::Signal;
 /// # }
 /// ```
 ///
+/// ### Transformed elements
+///
+/// The bounding rect includes CSS transforms of the element and its ancestors. If the element is
+/// inside a scaled container, like a zoomable canvas, you can additionally get the untransformed
+/// layout size with the option `offset_size`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_element_bounding_with_options, UseElementBoundingOptions, UseElementBoundingReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+/// let UseElementBoundingReturn {
+///     width, offset_width, ..
+/// } = use_element_bounding_with_options(el, UseElementBoundingOptions::default().offset_size(true));
+///
+/// let zoom = move || width.get() / offset_width.get();
+/// #
+/// # view! { <div node_ref=el></div> }
+/// # }
+/// ```
+///
+/// ### Batched measurements
+///
+/// The bounding rect isn't read right away when the element resizes, the window scrolls or `update`
+/// is called. Instead the measurement is scheduled for the next animation frame. Multiple triggers
+/// in the same frame result in a single measurement and all elements measured by
+/// `use_element_bounding` read their layout together before any signal is updated. This way
+/// many measured elements cause only one layout pass per frame, even if effects that depend on
+/// the returned signals write styles.
+///
 /// ## SendWrapped Return
 ///
 /// The returned closure `update` is a sendwrapped function. It can
@@ -60,6 +93,8 @@ where
     let (bottom, set_bottom) = signal(0.0);
     let (x, set_x) = signal(0.0);
     let (y, set_y) = signal(0.0);
+    let (offset_width, set_offset_width) = signal(0.0);
+    let (offset_height, set_offset_height) = signal(0.0);
 
     let update;
 
@@ -76,6 +111,8 @@ where
         let _ = set_bottom;
         let _ = set_x;
         let _ = set_y;
+        let _ = set_offset_width;
+        let _ = set_offset_height;
 
         update = move || ();
     }
@@ -87,39 +124,79 @@ where
             UseEventListenerOptions,
         };
         use leptos::ev::{resize, scroll};
+        use std::cell::Cell;
+        use std::rc::Rc;
+        use wasm_bindgen::JsCast;
 
         let UseElementBoundingOptions {
             reset,
             window_resize,
             window_scroll,
             immediate,
+            offset_size,
         } = options;
 
         let target = target.into_element_maybe_signal();
 
+        // Whether a measurement is already scheduled for the next frame
+        let scheduled = Rc::new(Cell::new(false));
+
+        let measurement: Measurement = Rc::new({
+            let scheduled = Rc::clone(&scheduled);
+
+            move || {
+                scheduled.set(false);
+
+                // `None` if the component has been disposed in the meantime
+                let el = target.try_get_untracked()?;
+
+                if let Some(el) = el {
+                    let rect = el.get_bounding_client_rect();
+
+                    let offset = if offset_size {
+                        el.dyn_ref::<web_sys::HtmlElement>()
+                            .map(|el| (el.offset_width() as f64, el.offset_height() as f64))
+                    } else {
+                        None
+                    };
+
+                    Some(Box::new(move || {
+                        set_height.set(rect.height());
+                        set_width.set(rect.width());
+                        set_left.set(rect.x());
+                        set_right.set(rect.x() + rect.width());
+                        set_top.set(rect.y());
+                        set_bottom.set(rect.y() + rect.height());
+                        set_x.set(rect.x());
+                        set_y.set(rect.y());
+
+                        if let Some((offset_width, offset_height)) = offset {
+                            set_offset_width.set(offset_width);
+                            set_offset_height.set(offset_height);
+                        }
+                    }) as Box<dyn FnOnce()>)
+                } else if reset {
+                    Some(Box::new(move || {
+                        set_height.set(0.0);
+                        set_width.set(0.0);
+                        set_left.set(0.0);
+                        set_right.set(0.0);
+                        set_top.set(0.0);
+                        set_bottom.set(0.0);
+                        set_x.set(0.0);
+                        set_y.set(0.0);
+                        set_offset_width.set(0.0);
+                        set_offset_height.set(0.0);
+                    }) as Box<dyn FnOnce()>)
+                } else {
+                    None
+                }
+            }
+        });
+
         update = sendwrap_fn!(move || {
-            let el = target.get_untracked();
-
-            if let Some(el) = el {
-                let rect = el.get_bounding_client_rect();
-
-                set_height.set(rect.height());
-                set_width.set(rect.width());
-                set_left.set(rect.x());
-                set_right.set(rect.x() + rect.width());
-                set_top.set(rect.y());
-                set_bottom.set(rect.y() + rect.height());
-                set_x.set(rect.x());
-                set_y.set(rect.y());
-            } else if reset {
-                set_height.set(0.0);
-                set_width.set(0.0);
-                set_left.set(0.0);
-                set_right.set(0.0);
-                set_top.set(0.0);
-                set_bottom.set(0.0);
-                set_x.set(0.0);
-                set_y.set(0.0);
+            if !scheduled.replace(true) {
+                schedule_measurement(Rc::clone(&measurement));
             }
         });
 
@@ -182,10 +259,49 @@ where
         bottom: bottom.into(),
         x: x.into(),
         y: y.into(),
+        offset_width: offset_width.into(),
+        offset_height: offset_height.into(),
         update,
     }
 }
 
+/// Reads the layout of an element and returns the function that writes the result into the signals.
+#[cfg(not(feature = "ssr"))]
+type Measurement = std::rc::Rc<dyn Fn() -> Option<Box<dyn FnOnce()>>>;
+
+#[cfg(not(feature = "ssr"))]
+thread_local! {
+    static PENDING_MEASUREMENTS: std::cell::RefCell<Vec<Measurement>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+/// Schedules a measurement for the next animation frame together with all other pending ones.
+#[cfg(not(feature = "ssr"))]
+fn schedule_measurement(measurement: Measurement) {
+    let is_first = PENDING_MEASUREMENTS.with_borrow_mut(|pending| {
+        pending.push(measurement);
+        pending.len() == 1
+    });
+
+    if is_first {
+        request_animation_frame(flush_measurements);
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn flush_measurements() {
+    let pending = PENDING_MEASUREMENTS.take();
+
+    // Read all layouts before writing anything so the browser only has to compute the layout once
+    let writes = pending
+        .iter()
+        .filter_map(|measurement| measurement())
+        .collect::<Vec<_>>();
+
+    for write in writes {
+        write();
+    }
+}
+
 /// Options for [`use_element_bounding_with_options`].
 #[derive(DefaultBuilder)]
 pub struct UseElementBoundingOptions {
@@ -208,6 +324,13 @@ pub struct UseElementBoundingOptions {
     ///
     /// Default: `true`
     pub immediate: bool,
+
+    /// Also report the layout size of the element as `offset_width` and `offset_height`.
+    /// In contrast to the bounding rect this size is not affected by CSS transforms like `scale()`.
+    /// Only HTML elements have a layout size.
+    ///
+    /// Default: `false`
+    pub offset_size: bool,
 }
 
 impl Default for UseElementBoundingOptions {
@@ -217,6 +340,7 @@ impl Default for UseElementBoundingOptions {
             window_resize: true,
             window_scroll: true,
             immediate: true,
+            offset_size: false,
         }
     }
 }
@@ -242,6 +366,14 @@ where
     pub x: Signal<f64>,
     /// Reactive version of [`BoudingClientRect.y`](https://developer.mozilla.org/en-US/docs/Web/API/DOMRectReadOnly/y)
     pub y: Signal<f64>,
+    /// Reactive version of [`HTMLElement.offsetWidth`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/offsetWidth).
+    /// Only updated if the option `offset_size` is `true`.
+    pub offset_width: Signal<f64>,
+    /// Reactive version of [`HTMLElement.offsetHeight`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/offsetHeight).
+    /// Only updated if the option `offset_size` is `true`.
+    pub offset_height: Signal<f64>,
     /// Function to re-evaluate `get_bounding_client_rect()` and update the signals.
+    /// The measurement happens in the next animation frame so the signals still hold the old
+    /// values right after calling it.
     pub update: F,
 }
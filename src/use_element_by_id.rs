@@ -0,0 +1,81 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{use_mutation_observer_with_options, UseMutationObserverOptions};
+use leptos::prelude::*;
+
+/// Reactive `document.getElementById`.
+///
+/// The element is looked up again whenever the `id` changes and, with the help of a
+/// [`MutationObserver`](https://developer.mozilla.org/en-US/docs/Web/API/MutationObserver),
+/// whenever elements are added to or removed from the document. This way you can wait for an
+/// element that is mounted later, for example the target of a portal.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_element_by_id;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let target = use_element_by_id("modal-target");
+///
+/// view! {
+///     <Show when=move || target.with(Option::is_some)>
+///         "The modal target is mounted"
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// The `id` can also be a signal.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_element_by_id;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (id, set_id) = signal("first".to_string());
+///
+/// let element = use_element_by_id(id);
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server this returns a `Signal` that always contains the value `None`.
+pub fn use_element_by_id(
+    id: impl Into<Signal<String>>,
+) -> Signal<Option<web_sys::Element>, LocalStorage> {
+    let id = id.into();
+
+    let (element, set_element) = signal_local(None::<web_sys::Element>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let update = move || {
+            let el = id.with_untracked(|id| document().get_element_by_id(id));
+
+            if element.with_untracked(|element| element != &el) {
+                set_element.set(el);
+            }
+        };
+
+        Effect::watch(move || id.track(), move |_, _, _| update(), true);
+
+        let _ = use_mutation_observer_with_options::<_, Option<web_sys::Element>, _>(
+            document().document_element(),
+            move |_, _| update(),
+            UseMutationObserverOptions::default()
+                .child_list(true)
+                .subtree(true)
+                .attributes(true)
+                .attribute_filter(vec!["id".to_string()]),
+        );
+    }
+
+    element.into()
+}
@@ -2,6 +2,8 @@ use crate::core::IntoElementMaybeSignal;
 use crate::{use_event_listener_with_options, UseEventListenerOptions};
 use default_struct_builder::DefaultBuilder;
 use leptos::ev::{mouseenter, mouseleave};
+#[cfg(not(feature = "ssr"))]
+use leptos::ev::{touchcancel, touchend, touchmove, touchstart};
 use leptos::leptos_dom::helpers::TimeoutHandle;
 use leptos::prelude::*;
 use leptos::reactive::wrappers::read::Signal;
@@ -30,6 +32,35 @@ use leptos::reactive::wrappers::read::Signal;
 /// # }
 /// ```
 ///
+/// ### Long press
+///
+/// Touch devices have no hover. To make hover-only UI like tooltips reachable there, set the
+/// option `long_press` to the time in ms a finger has to rest on the element. After that the
+/// element counts as hovered until the finger is lifted. If the finger moves more than
+/// `long_press_slop` pixels before, the long press is canceled.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Button;
+/// # use leptos_use::{use_element_hover_with_options, UseElementHoverOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Button>::new();
+/// let is_hovered = use_element_hover_with_options(
+///     el,
+///     UseElementHoverOptions::default().long_press(500_u64),
+/// );
+///
+/// view! {
+///     <button node_ref=el>"Info"</button>
+///     <Show when=move || is_hovered.get()>
+///         <div class="tooltip">"Shown on hover or long press"</div>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// On the server this returns a `Signal` that always contains the value `false`.
@@ -41,7 +72,6 @@ where
 }
 
 /// Version of [`use_element_hover`] that takes a `UseElementHoverOptions`. See [`use_element_hover`] for how to use.
-
 #[cfg_attr(feature = "ssr", allow(unused_variables, unused_mut))]
 pub fn use_element_hover_with_options<El, M>(
     el: El,
@@ -53,17 +83,17 @@ where
     let UseElementHoverOptions {
         delay_enter,
         delay_leave,
+        long_press,
+        long_press_slop,
     } = options;
 
     let (is_hovered, set_hovered) = signal(false);
 
     let timer = StoredValue::new(None::<TimeoutHandle>);
 
-    let toggle = move |entering: bool| {
+    let toggle = move |entering: bool, delay: u64| {
         #[cfg(not(feature = "ssr"))]
         {
-            let delay = if entering { delay_enter } else { delay_leave };
-
             timer.update_value(|timer| {
                 if let Some(handle) = timer.take() {
                     handle.clear();
@@ -88,21 +118,126 @@ where
 
     let el = el.into_element_maybe_signal();
 
-    let _ =
-        use_event_listener_with_options(el, mouseenter, move |_| toggle(true), listener_options);
+    let _ = use_event_listener_with_options(
+        el,
+        mouseenter,
+        move |_| toggle(true, delay_enter),
+        listener_options,
+    );
+
+    let _ = use_event_listener_with_options(
+        el,
+        mouseleave,
+        move |_| toggle(false, delay_leave),
+        listener_options,
+    );
+
+    #[cfg(not(feature = "ssr"))]
+    if let Some(long_press) = long_press {
+        let press_start = StoredValue::new(None::<(f64, f64)>);
+        let press_timer = StoredValue::new(None::<TimeoutHandle>);
+
+        let cancel_press = move || {
+            press_timer.update_value(|timer| {
+                if let Some(handle) = timer.take() {
+                    handle.clear();
+                }
+            });
+            press_start.set_value(None);
+        };
+
+        let touch_position = |event: &web_sys::TouchEvent| {
+            event
+                .touches()
+                .get(0)
+                .map(|touch| (touch.client_x() as f64, touch.client_y() as f64))
+        };
+
+        let _ = use_event_listener_with_options(
+            el,
+            touchstart,
+            move |event| {
+                cancel_press();
+
+                let Some(position) = touch_position(&event) else {
+                    return;
+                };
+                press_start.set_value(Some(position));
+
+                press_timer.set_value(
+                    set_timeout_with_handle(
+                        move || {
+                            press_timer.set_value(None);
+                            toggle(true, 0);
+                        },
+                        std::time::Duration::from_millis(long_press),
+                    )
+                    .ok(),
+                );
+            },
+            listener_options,
+        );
+
+        let _ = use_event_listener_with_options(
+            el,
+            touchmove,
+            move |event| {
+                let Some((start_x, start_y)) = press_start.get_value() else {
+                    return;
+                };
 
-    let _ =
-        use_event_listener_with_options(el, mouseleave, move |_| toggle(false), listener_options);
+                if let Some((x, y)) = touch_position(&event) {
+                    if (x - start_x).hypot(y - start_y) > long_press_slop {
+                        cancel_press();
+                    }
+                }
+            },
+            listener_options,
+        );
+
+        let release = move || {
+            cancel_press();
+
+            if is_hovered.get_untracked() {
+                toggle(false, delay_leave);
+            }
+        };
+
+        let _ = use_event_listener_with_options(el, touchend, move |_| release(), listener_options);
+
+        let _ =
+            use_event_listener_with_options(el, touchcancel, move |_| release(), listener_options);
+    }
 
     is_hovered.into()
 }
 
 /// Options for [`use_element_hover_with_options`].
-#[derive(DefaultBuilder, Default)]
+#[derive(DefaultBuilder)]
 pub struct UseElementHoverOptions {
     /// The time in ms the mouse has to be hovered over the element before the signal is changed to `true`. Defaults to `0`.
     delay_enter: u64,
 
     /// The time in ms after the mouse has left the element before the signal is changed to `false`. Defaults to `0`.
     delay_leave: u64,
+
+    /// If set, a touch that rests on the element for this many ms counts as hover until the finger
+    /// is lifted. `delay_leave` is applied after lifting the finger. Defaults to `None`.
+    #[builder(into)]
+    long_press: Option<u64>,
+
+    /// How many pixels the finger may move before a pending long press is canceled.
+    /// Only used if `long_press` is set. Defaults to `10.0`.
+    long_press_slop: f64,
+}
+
+impl Default for UseElementHoverOptions {
+    fn default() -> Self {
+        Self {
+            delay_enter: 0,
+            delay_leave: 0,
+            long_press: None,
+            long_press_slop: 10.0,
+        }
+    }
 }
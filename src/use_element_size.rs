@@ -1,12 +1,16 @@
 use crate::core::IntoElementMaybeSignal;
 use crate::core::Size;
+use crate::{js, use_supported_immediately};
 use cfg_if::cfg_if;
 use default_struct_builder::DefaultBuilder;
 use leptos::prelude::*;
 use leptos::reactive::wrappers::read::Signal;
 
 cfg_if! { if #[cfg(not(feature = "ssr"))] {
-    use crate::{use_resize_observer_with_options, UseResizeObserverOptions};
+    use crate::{
+        use_event_listener, use_interval_fn, use_resize_observer_with_options,
+        UseResizeObserverOptions,
+    };
     use crate::{watch_with_options, WatchOptions};
     use wasm_bindgen::JsCast;
 }}
@@ -30,7 +34,7 @@ cfg_if! { if #[cfg(not(feature = "ssr"))] {
 /// # fn Demo() -> impl IntoView {
 /// let el = NodeRef::<Div>::new();
 ///
-/// let UseElementSizeReturn { width, height } = use_element_size(el);
+/// let UseElementSizeReturn { width, height, .. } = use_element_size(el);
 ///
 /// view! {
 ///     <div node_ref=el>
@@ -41,9 +45,43 @@ cfg_if! { if #[cfg(not(feature = "ssr"))] {
 /// # }
 /// ```
 ///
+/// ## Fallback
+///
+/// In environments without `ResizeObserver` (e.g. some embedded webviews) the size is measured
+/// with [`getBoundingClientRect`](https://developer.mozilla.org/en-US/docs/Web/API/Element/getBoundingClientRect)
+/// whenever the window is resized or the target changes. Changes of the element size that
+/// aren't caused by a window resize aren't detected in this case unless you set the option
+/// `fallback_poll_interval` to additionally measure the element periodically. The returned
+/// `is_supported` is `false` when this degraded fallback is used. For the content box the
+/// padding is subtracted from [`clientWidth`](https://developer.mozilla.org/en-US/docs/Web/API/Element/clientWidth)
+/// and [`clientHeight`](https://developer.mozilla.org/en-US/docs/Web/API/Element/clientHeight)
+/// and for the border box [`offsetWidth`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/offsetWidth)
+/// and [`offsetHeight`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/offsetHeight)
+/// are used.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_element_size_with_options, UseElementSizeOptions, UseElementSizeReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// // without `ResizeObserver` measure the element every 500ms
+/// let UseElementSizeReturn { width, height, .. } = use_element_size_with_options(
+///     el,
+///     UseElementSizeOptions::default().fallback_poll_interval(500_u64),
+/// );
+/// #
+/// # view! { <div node_ref=el></div> }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
-/// On the server the returned signals always contain the value of the `initial_size` option.
+/// On the server the returned signals always contain the value of the `initial_size` option
+/// and `is_supported` is `false`.
 ///
 /// ## See also
 ///
@@ -64,11 +102,17 @@ pub fn use_element_size_with_options<El, M>(
 where
     El: IntoElementMaybeSignal<web_sys::Element, M>,
 {
-    let UseElementSizeOptions { box_, initial_size } = options;
+    let UseElementSizeOptions {
+        box_,
+        initial_size,
+        fallback_poll_interval,
+    } = options;
 
     let (width, set_width) = signal(initial_size.width);
     let (height, set_height) = signal(initial_size.height);
 
+    let is_supported = use_supported_immediately(|| js!("ResizeObserver" in &window()));
+
     #[cfg(not(feature = "ssr"))]
     {
         let box_ = box_.unwrap_or(web_sys::ResizeObserverBoxOptions::ContentBox);
@@ -160,11 +204,87 @@ where
             },
             WatchOptions::default().immediate(false),
         );
+
+        if !is_supported.get_untracked() {
+            let measure = move || {
+                if let Some(target) = target.get_untracked() {
+                    let (new_width, new_height) = measure_box(&target, box_);
+
+                    // avoid notifying subscribers on every poll
+                    if width.get_untracked() != new_width {
+                        set_width.set(new_width);
+                    }
+                    if height.get_untracked() != new_height {
+                        set_height.set(new_height);
+                    }
+                }
+            };
+
+            let _ = use_event_listener(window(), leptos::ev::resize, move |_| measure());
+
+            if let Some(fallback_poll_interval) = fallback_poll_interval {
+                let _ = use_interval_fn(measure, fallback_poll_interval);
+            }
+
+            Effect::watch(
+                move || target.get(),
+                move |_, _, _| {
+                    // wait for the element to be laid out
+                    request_animation_frame(measure);
+                },
+                true,
+            );
+        }
     }
 
     UseElementSizeReturn {
         width: width.into(),
         height: height.into(),
+        is_supported,
+    }
+}
+
+/// Measures the `box_` of `target` without `ResizeObserver`.
+#[cfg(not(feature = "ssr"))]
+fn measure_box(target: &web_sys::Element, box_: web_sys::ResizeObserverBoxOptions) -> (f64, f64) {
+    let content_box = || {
+        let padding = |styles: &web_sys::CssStyleDeclaration, property: &str| {
+            styles
+                .get_property_value(property)
+                .ok()
+                .and_then(|v| v.trim_end_matches("px").parse::<f64>().ok())
+                .unwrap_or_default()
+        };
+
+        let (padding_x, padding_y) = match window().get_computed_style(target) {
+            Ok(Some(styles)) => (
+                padding(&styles, "padding-left") + padding(&styles, "padding-right"),
+                padding(&styles, "padding-top") + padding(&styles, "padding-bottom"),
+            ),
+            _ => (0.0, 0.0),
+        };
+
+        (
+            (target.client_width() as f64 - padding_x).max(0.0),
+            (target.client_height() as f64 - padding_y).max(0.0),
+        )
+    };
+
+    match box_ {
+        web_sys::ResizeObserverBoxOptions::BorderBox => {
+            if let Some(target) = target.dyn_ref::<web_sys::HtmlElement>() {
+                (target.offset_width() as f64, target.offset_height() as f64)
+            } else {
+                let rect = target.get_bounding_client_rect();
+                (rect.width(), rect.height())
+            }
+        }
+        web_sys::ResizeObserverBoxOptions::DevicePixelContentBox => {
+            let (width, height) = content_box();
+            let ratio = window().device_pixel_ratio();
+            (width * ratio, height * ratio)
+        }
+        _ => content_box(),
     }
 }
 
@@ -178,6 +298,12 @@ pub struct UseElementSizeOptions {
     /// The box that is used to determine the dimensions of the target. Defaults to `ContentBox`.
     #[builder(into)]
     pub box_: Option<web_sys::ResizeObserverBoxOptions>,
+
+    /// Interval in milliseconds in which the element is measured if `ResizeObserver` isn't
+    /// supported. Without it the size is only updated when the window is resized or the target
+    /// changes. Has no effect if `ResizeObserver` is supported. Defaults to `None`.
+    #[builder(into)]
+    fallback_poll_interval: Option<u64>,
 }
 
 /// The return value of [`use_element_size`].
@@ -186,4 +312,7 @@ pub struct UseElementSizeReturn {
     pub width: Signal<f64>,
     /// The height of the element.
     pub height: Signal<f64>,
+    /// Whether `ResizeObserver` is supported. If `false` the size is only updated when the
+    /// window is resized, the target changes or the `fallback_poll_interval` has passed.
+    pub is_supported: Signal<bool>,
 }
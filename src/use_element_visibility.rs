@@ -1,4 +1,5 @@
 use crate::core::IntoElementMaybeSignal;
+use crate::use_document_visibility;
 use cfg_if::cfg_if;
 use default_struct_builder::DefaultBuilder;
 use leptos::prelude::*;
@@ -35,12 +36,38 @@ use leptos::reactive::wrappers::read::Signal;
 /// # }
 /// ```
 ///
+/// ### Tab visibility
+///
+/// An element that is inside the viewport isn't really visible if the browser tab is in the
+/// background. With the option `require_document_visible` the returned signal is only `true`
+/// if the element is in the viewport and the document is visible. This is useful to pause
+/// expensive animations or videos.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_element_visibility_with_options, UseElementVisibilityOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let is_visible = use_element_visibility_with_options(
+///     el,
+///     UseElementVisibilityOptions::default().require_document_visible(true),
+/// );
+/// #
+/// # view! { <div node_ref=el></div> }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// On the server this returns a `Signal` that always contains the value `false`.
 ///
 /// ## See also
 ///
+/// * [`fn@crate::use_element_visibility_details`]
 /// * [`fn@crate::use_intersection_observer`]
 pub fn use_element_visibility<El, M>(target: El) -> Signal<bool>
 where
@@ -81,10 +108,194 @@ where
         );
     }}
 
-    is_visible.into()
+    if options.require_document_visible {
+        let document_visibility = use_document_visibility();
+
+        Signal::derive(move || {
+            is_visible.get() && document_visibility.get() == web_sys::VisibilityState::Visible
+        })
+    } else {
+        is_visible.into()
+    }
+}
+
+/// Tracks the visibility of an element together with how much of it is visible and from which side
+/// it entered the viewport.
+///
+/// This is useful for scroll-reveal animations that should play in the direction from which the
+/// element scrolled into view.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_element_visibility_details, EntrySide, UseElementVisibilityDetailsReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let UseElementVisibilityDetailsReturn {
+///     is_visible,
+///     ratio,
+///     entry_side,
+/// } = use_element_visibility_details(el);
+///
+/// view! {
+///     <div
+///         node_ref=el
+///         class:slide-up=move || entry_side.get() == Some(EntrySide::Bottom)
+///         class:slide-down=move || entry_side.get() == Some(EntrySide::Top)
+///         style:opacity=move || ratio.get().to_string()
+///     >
+///         "..."
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// With the option `require_document_visible` the element is treated as invisible (`ratio` is `0.0`)
+/// while the browser tab is in the background, just like with [`use_element_visibility`].
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_visible` is always `false`, `ratio` is always `0.0` and `entry_side` is always `None`.
+pub fn use_element_visibility_details<El, M>(target: El) -> UseElementVisibilityDetailsReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_element_visibility_details_with_options::<El, M, web_sys::Element, _>(
+        target,
+        UseElementVisibilityOptions::default(),
+    )
+}
+
+/// Version of [`use_element_visibility_details`] that takes a `UseElementVisibilityOptions`. See [`use_element_visibility_details`] for how to use.
+#[cfg_attr(feature = "ssr", allow(unused_variables))]
+pub fn use_element_visibility_details_with_options<El, M, ContainerEl, ContainerM>(
+    target: El,
+    options: UseElementVisibilityOptions<ContainerEl, ContainerM>,
+) -> UseElementVisibilityDetailsReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+    ContainerEl: IntoElementMaybeSignal<web_sys::Element, ContainerM>,
+{
+    let (is_visible, set_visible) = signal(false);
+    let (ratio, set_ratio) = signal(0.0);
+    let (entry_side, set_entry_side) = signal(None::<EntrySide>);
+
+    cfg_if! { if #[cfg(not(feature = "ssr"))] {
+        // position (top, left) of the element relative to the root at the last observation
+        let mut prev_position = None::<(f64, f64)>;
+
+        use_intersection_observer_with_options(
+            target.into_element_maybe_signal(),
+            move |entries, _| {
+                // See `use_element_visibility_with_options` why we skip zero size rects.
+                if let Some(entry) = entries.into_iter().rev().find(|entry| {
+                    let rect = entry.bounding_client_rect();
+                    rect.width() > 0.0 || rect.height() > 0.0
+                }) {
+                    let rect = entry.bounding_client_rect();
+                    let (root_top, root_left, root_bottom, root_right) = entry
+                        .root_bounds()
+                        .map(|root| (root.top(), root.left(), root.bottom(), root.right()))
+                        .unwrap_or_else(|| {
+                            let width = window()
+                                .inner_width()
+                                .ok()
+                                .and_then(|w| w.as_f64())
+                                .unwrap_or_default();
+                            let height = window()
+                                .inner_height()
+                                .ok()
+                                .and_then(|h| h.as_f64())
+                                .unwrap_or_default();
+
+                            (0.0, 0.0, height, width)
+                        });
+
+                    let position = (rect.top() - root_top, rect.left() - root_left);
+                    let is_intersecting = entry.is_intersecting();
+
+                    if is_intersecting && !is_visible.get_untracked() {
+                        let side = if rect.top() < root_top {
+                            Some(EntrySide::Top)
+                        } else if rect.bottom() > root_bottom {
+                            Some(EntrySide::Bottom)
+                        } else if rect.left() < root_left {
+                            Some(EntrySide::Left)
+                        } else if rect.right() > root_right {
+                            Some(EntrySide::Right)
+                        } else {
+                            // Entered in one go (e.g. fast scrolling),
+                            // so we use the movement since the last observation.
+                            prev_position.and_then(|(prev_top, prev_left)| {
+                                let delta_top = position.0 - prev_top;
+                                let delta_left = position.1 - prev_left;
+
+                                if delta_top.abs() >= delta_left.abs() {
+                                    if delta_top < 0.0 {
+                                        Some(EntrySide::Bottom)
+                                    } else if delta_top > 0.0 {
+                                        Some(EntrySide::Top)
+                                    } else {
+                                        None
+                                    }
+                                } else if delta_left < 0.0 {
+                                    Some(EntrySide::Right)
+                                } else {
+                                    Some(EntrySide::Left)
+                                }
+                            })
+                        };
+
+                        set_entry_side.set(side);
+                    }
+
+                    prev_position = Some(position);
+
+                    set_visible.set(is_intersecting);
+                    set_ratio.set(if is_intersecting {
+                        entry.intersection_ratio()
+                    } else {
+                        0.0
+                    });
+                }
+            },
+            UseIntersectionObserverOptions::default()
+                .root(options.viewport)
+                .thresholds((0..=100).map(|i| i as f64 / 100.0).collect::<Vec<_>>()),
+        );
+    }}
+
+    if options.require_document_visible {
+        let document_visibility = use_document_visibility();
+        let is_document_visible =
+            move || document_visibility.get() == web_sys::VisibilityState::Visible;
+
+        UseElementVisibilityDetailsReturn {
+            is_visible: Signal::derive(move || is_visible.get() && is_document_visible()),
+            ratio: Signal::derive(move || {
+                if is_document_visible() {
+                    ratio.get()
+                } else {
+                    0.0
+                }
+            }),
+            entry_side: entry_side.into(),
+        }
+    } else {
+        UseElementVisibilityDetailsReturn {
+            is_visible: is_visible.into(),
+            ratio: ratio.into(),
+            entry_side: entry_side.into(),
+        }
+    }
 }
 
-/// Options for [`use_element_visibility_with_options`].
+/// Options for [`use_element_visibility_with_options`] and [`use_element_visibility_details_with_options`].
 #[derive(DefaultBuilder)]
 pub struct UseElementVisibilityOptions<El, M>
 where
@@ -99,6 +310,12 @@ where
     #[cfg_attr(feature = "ssr", allow(dead_code))]
     viewport: Option<El>,
 
+    /// If `true`, the element is only considered visible if the document is visible as well,
+    /// i.e. the browser tab isn't in the background. For [`use_element_visibility_details_with_options`]
+    /// the `ratio` is `0.0` as well while the document is hidden.
+    /// Defaults to `false`.
+    require_document_visible: bool,
+
     #[builder(skip)]
     _marker: PhantomData<M>,
 }
@@ -110,7 +327,36 @@ where
     fn default() -> Self {
         Self {
             viewport: None,
+            require_document_visible: false,
             _marker: PhantomData,
         }
     }
 }
+
+/// Return type of [`use_element_visibility_details`].
+pub struct UseElementVisibilityDetailsReturn {
+    /// Whether the element is currently (at least partially) visible.
+    pub is_visible: Signal<bool>,
+
+    /// How much of the element is visible. Between `0.0` (invisible) and `1.0` (fully visible).
+    /// This is updated in steps of `0.01`.
+    pub ratio: Signal<f64>,
+
+    /// The edge of the viewport through which the element became visible the last time.
+    /// `None` before the element has been visible or if it was visible right away.
+    pub entry_side: Signal<Option<EntrySide>>,
+}
+
+/// Side of the viewport through which an element entered it.
+/// See [`fn@crate::use_element_visibility_details`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EntrySide {
+    /// Scrolled into view from the top. This happens when scrolling up.
+    Top,
+    /// Scrolled into view from the bottom. This happens when scrolling down.
+    Bottom,
+    /// Scrolled into view from the left.
+    Left,
+    /// Scrolled into view from the right.
+    Right,
+}
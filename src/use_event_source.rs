@@ -69,6 +69,43 @@ use thiserror::Error;
 /// # }
 /// ```
 ///
+/// ### Typed Named Events
+///
+/// If named events carry different kinds of data, you can decode each of them with its own codec
+/// by calling `named_event` on the return value. It returns typed signals that are updated
+/// whenever the event is received, also after reconnecting.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_event_source, UseEventSourceNamedEventReturn};
+/// # use codee::string::{FromToStringCodec, JsonSerdeCodec};
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Serialize, Deserialize, Clone, PartialEq)]
+/// pub struct Metrics {
+///     pub cpu: f64,
+///     pub memory: f64,
+/// }
+///
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let event_source = use_event_source::<String, FromToStringCodec>("https://event-source-url");
+///
+/// // `event: metrics` is JSON
+/// let UseEventSourceNamedEventReturn { data: metrics, .. } =
+///     event_source.named_event::<Metrics, JsonSerdeCodec>("metrics");
+///
+/// // `event: log` is plain text
+/// let UseEventSourceNamedEventReturn { data: log_line, .. } =
+///     event_source.named_event::<String, FromToStringCodec>("log");
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// These events don't have to be listed in the option `named_events` and they don't update
+/// `data`, `event` or `error` of the return value.
+///
 /// ### Immediate
 ///
 /// Auto-connect (enabled by default).
@@ -398,6 +435,77 @@ where
     pub event_source: Signal<Option<web_sys::EventSource>, LocalStorage>,
 }
 
+impl<T, Err, OpenFn, CloseFn> UseEventSourceReturn<T, Err, OpenFn, CloseFn>
+where
+    Err: Send + Sync + 'static,
+    T: Clone + Send + Sync + 'static,
+    OpenFn: Fn() + Clone + Send + Sync + 'static,
+    CloseFn: Fn() + Clone + Send + Sync + 'static,
+{
+    /// Listens for the named event `event_name` and decodes its data with the codec `C`.
+    /// See [`use_event_source`] for how to use.
+    pub fn named_event<U, C>(&self, event_name: &str) -> UseEventSourceNamedEventReturn<U, C::Error>
+    where
+        U: Send + Sync + 'static,
+        C: Decoder<U, Encoded = str>,
+        C::Error: 'static,
+    {
+        let (data, set_data) = signal(None::<U>);
+        let (error, set_error) = signal_local(None::<C::Error>);
+
+        let _ = use_event_listener(
+            self.event_source,
+            leptos::ev::Custom::<leptos::ev::Event>::new(event_name.to_string()),
+            move |e| {
+                if let Some(data_string) = js!(e["data"]).ok().and_then(|d| d.as_string()) {
+                    match C::decode(&data_string) {
+                        Ok(value) => {
+                            set_data.set(Some(value));
+                            set_error.set(None);
+                        }
+                        Err(err) => set_error.set(Some(err)),
+                    }
+                }
+            },
+        );
+
+        UseEventSourceNamedEventReturn {
+            data: data.into(),
+            error: error.into(),
+        }
+    }
+}
+
+/// Return type of [`UseEventSourceReturn::named_event`].
+pub struct UseEventSourceNamedEventReturn<T, Err>
+where
+    T: Send + Sync + 'static,
+    Err: 'static,
+{
+    /// Latest decoded data of this event
+    pub data: Signal<Option<T>>,
+
+    /// The error of decoding the latest data of this event. Reset when data is decoded successfully.
+    pub error: Signal<Option<Err>, LocalStorage>,
+}
+
+impl<T, Err> Clone for UseEventSourceNamedEventReturn<T, Err>
+where
+    T: Send + Sync + 'static,
+    Err: 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, Err> Copy for UseEventSourceNamedEventReturn<T, Err>
+where
+    T: Send + Sync + 'static,
+    Err: 'static,
+{
+}
+
 #[derive(Error, Debug)]
 pub enum UseEventSourceError<Err> {
     #[error("Error event: {0:?}")]
@@ -0,0 +1,227 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::sendwrap_fn;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Open the native file picker without rendering an `<input type="file">` yourself.
+///
+/// A hidden file input is created and clicked programmatically. The selected files are exposed
+/// reactively. Together with [`fn@crate::use_drop_zone`] you get a complete upload experience
+/// where files can be dropped or selected by clicking.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_file_dialog_with_options, UseFileDialogOptions, UseFileDialogReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseFileDialogReturn { files, open, reset } = use_file_dialog_with_options(
+///     UseFileDialogOptions::default()
+///         .accept("image/*")
+///         .multiple(true),
+/// );
+///
+/// view! {
+///     <button on:click=move |_| open()>"Choose images"</button>
+///     <button on:click=move |_| reset()>"Reset"</button>
+///     <p>{move || files.with(|files| files.len())} " files selected"</p>
+/// }
+/// # }
+/// ```
+///
+/// Browsers only open the file picker in response to a user interaction. So `open` has to be
+/// called from an event handler like a click handler.
+///
+/// To react to a selection directly, use the option `on_change`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_file_dialog_with_options, UseFileDialogOptions, UseFileDialogReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseFileDialogReturn { open, .. } = use_file_dialog_with_options(
+///     UseFileDialogOptions::default().on_change(|files: &[web_sys::File]| {
+///         for file in files {
+///             leptos::logging::log!("Selected {}", file.name());
+///         }
+///     }),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `open` and `reset` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_file_dialog`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `files` is always empty and `open` and `reset` are no-ops.
+pub fn use_file_dialog() -> UseFileDialogReturn<
+    impl Fn() + Clone + Send + Sync + 'static,
+    impl Fn() + Clone + Send + Sync + 'static,
+> {
+    use_file_dialog_with_options(UseFileDialogOptions::default())
+}
+
+/// Version of [`use_file_dialog`] that takes a `UseFileDialogOptions`. See [`use_file_dialog`] for how to use.
+pub fn use_file_dialog_with_options(
+    options: UseFileDialogOptions,
+) -> UseFileDialogReturn<
+    impl Fn() + Clone + Send + Sync + 'static,
+    impl Fn() + Clone + Send + Sync + 'static,
+> {
+    let UseFileDialogOptions {
+        accept,
+        multiple,
+        capture,
+        on_change,
+    } = options;
+
+    let (files, set_files) = signal_local(Vec::<web_sys::File>::new());
+
+    let input = StoredValue::new_local(None::<web_sys::HtmlInputElement>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+        use wasm_bindgen::JsCast;
+
+        if let Some(el) = document()
+            .create_element("input")
+            .ok()
+            .map(|el| el.unchecked_into::<web_sys::HtmlInputElement>())
+        {
+            el.set_type("file");
+            el.set_accept(&accept);
+            el.set_multiple(multiple);
+            if let Some(capture) = &capture {
+                let _ = el.set_attribute("capture", capture);
+            }
+
+            let _ = use_event_listener(el.clone(), leptos::ev::change, move |event| {
+                let Some(el) = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<web_sys::HtmlInputElement>().ok())
+                else {
+                    return;
+                };
+
+                let selected = el
+                    .files()
+                    .map(|files| {
+                        js_sys::Array::from(&files)
+                            .to_vec()
+                            .into_iter()
+                            .map(web_sys::File::from)
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+
+                #[cfg(debug_assertions)]
+                let zone = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+                on_change(&selected);
+
+                #[cfg(debug_assertions)]
+                drop(zone);
+
+                set_files.set(selected);
+            });
+
+            input.set_value(Some(el));
+        }
+    }
+
+    let open = sendwrap_fn!(move || {
+        if let Some(input) = input.get_value() {
+            input.click();
+        }
+    });
+
+    let reset = sendwrap_fn!(move || {
+        if let Some(input) = input.get_value() {
+            input.set_value("");
+        }
+        set_files.set(vec![]);
+    });
+
+    UseFileDialogReturn {
+        files: files.into(),
+        open,
+        reset,
+    }
+}
+
+/// Options for [`use_file_dialog_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseFileDialogOptions {
+    /// File types that can be selected like `"image/*"` or `".pdf,.docx"`.
+    /// See [`accept`](https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/accept).
+    /// Defaults to `"*"`.
+    #[builder(into)]
+    accept: String,
+
+    /// Whether multiple files can be selected. Defaults to `true`.
+    multiple: bool,
+
+    /// Which camera to use to capture a new image or video instead of selecting a file on mobile
+    /// devices. Either `"user"` or `"environment"`.
+    /// See [`capture`](https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/capture).
+    /// Defaults to `None`.
+    #[builder(into)]
+    capture: Option<String>,
+
+    /// Called with the selected files every time the selection changes.
+    #[builder(skip)]
+    on_change: ArcFnFiles,
+}
+
+type ArcFnFiles = Arc<dyn Fn(&[web_sys::File]) + Send + Sync>;
+
+impl Default for UseFileDialogOptions {
+    fn default() -> Self {
+        Self {
+            accept: "*".to_string(),
+            multiple: true,
+            capture: None,
+            on_change: Arc::new(|_| {}),
+        }
+    }
+}
+
+impl UseFileDialogOptions {
+    /// Called with the selected files every time the selection changes.
+    pub fn on_change<F>(self, on_change: F) -> Self
+    where
+        F: Fn(&[web_sys::File]) + Send + Sync + 'static,
+    {
+        Self {
+            on_change: Arc::new(on_change),
+            ..self
+        }
+    }
+}
+
+/// Return type of [`use_file_dialog`].
+pub struct UseFileDialogReturn<OpenFn, ResetFn>
+where
+    OpenFn: Fn() + Clone + Send + Sync + 'static,
+    ResetFn: Fn() + Clone + Send + Sync + 'static,
+{
+    /// The currently selected files
+    pub files: Signal<Vec<web_sys::File>, LocalStorage>,
+
+    /// Opens the file picker. Has to be called from a user interaction like a click handler.
+    pub open: OpenFn,
+
+    /// Clears the selection
+    pub reset: ResetFn,
+}
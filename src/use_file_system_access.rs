@@ -0,0 +1,421 @@
+use crate::{js, use_supported_immediately};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Open, create and save files on the user's disk with the
+/// [File System Access API](https://developer.mozilla.org/en-US/docs/Web/API/File_System_API).
+///
+/// This API is only available in Chromium based browsers. Check `is_supported` before offering
+/// these features.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::task::spawn_local;
+/// # use leptos_use::{use_file_system_access, FileSystemAccessData, UseFileSystemAccessReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let fs = use_file_system_access();
+///
+/// let UseFileSystemAccessReturn {
+///     is_supported,
+///     data,
+///     file_name,
+///     ..
+/// } = fs;
+///
+/// let open = move |_| {
+///     spawn_local(async move {
+///         if let Err(err) = fs.open().await {
+///             leptos::logging::error!("{err}");
+///         }
+///     });
+/// };
+///
+/// let save = move |_| {
+///     spawn_local(async move {
+///         let _ = fs.save().await;
+///     });
+/// };
+///
+/// let text = move || match data.get() {
+///     Some(FileSystemAccessData::Text(text)) => text,
+///     _ => String::new(),
+/// };
+///
+/// view! {
+///     <Show when=move || is_supported.get() fallback=|| "Not supported by your browser">
+///         <button on:click=open>"Open"</button>
+///         <button on:click=save>"Save"</button>
+///         <p>{file_name}</p>
+///         <textarea
+///             prop:value=text
+///             on:input=move |ev| data.set(Some(FileSystemAccessData::Text(event_target_value(&ev))))
+///         />
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// `open` shows a file picker and reads the selected file into `data`. `save` writes `data` back
+/// to the same file. `save_as` and `create` show a save file picker first.
+/// If the user cancels a picker, nothing changes and the operation returns `Ok(())`.
+///
+/// ### Options
+///
+/// With the option `data_type` you can choose whether the file is read as text (the default),
+/// as an `ArrayBuffer` or as a `Blob`. The file types that can be selected are set with `types`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{
+/// #     use_file_system_access_with_options, FileSystemAccessDataType, FileSystemAccessFileType,
+/// #     UseFileSystemAccessOptions,
+/// # };
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let fs = use_file_system_access_with_options(
+///     UseFileSystemAccessOptions::default()
+///         .data_type(FileSystemAccessDataType::ArrayBuffer)
+///         .types(vec![FileSystemAccessFileType {
+///             description: "Images".to_string(),
+///             accept: vec![("image/*".to_string(), vec![".png".to_string(), ".jpg".to_string()])],
+///         }])
+///         .suggested_name("image.png"),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is `false` and all operations fail with
+/// [`UseFileSystemAccessError::NotSupported`].
+pub fn use_file_system_access() -> UseFileSystemAccessReturn {
+    use_file_system_access_with_options(UseFileSystemAccessOptions::default())
+}
+
+/// Version of [`use_file_system_access`] that takes a `UseFileSystemAccessOptions`. See [`use_file_system_access`] for how to use.
+pub fn use_file_system_access_with_options(
+    options: UseFileSystemAccessOptions,
+) -> UseFileSystemAccessReturn {
+    let is_supported = use_supported_immediately(|| {
+        let window = window();
+        js!("showOpenFilePicker" in &window) && js!("showSaveFilePicker" in &window)
+    });
+
+    let data = RwSignal::new_local(None::<FileSystemAccessData>);
+    let (file, set_file) = signal_local(None::<web_sys::File>);
+
+    UseFileSystemAccessReturn {
+        is_supported,
+        data,
+        file: file.into(),
+        file_name: Signal::derive(move || {
+            file.with(|file| file.as_ref().map(|file| file.name()).unwrap_or_default())
+        }),
+        file_mime: Signal::derive(move || {
+            file.with(|file| file.as_ref().map(|file| file.type_()).unwrap_or_default())
+        }),
+        file_size: Signal::derive(move || {
+            file.with(|file| file.as_ref().map(|file| file.size()).unwrap_or_default())
+        }),
+        file_last_modified: Signal::derive(move || {
+            file.with(|file| {
+                file.as_ref()
+                    .map(|file| file.last_modified())
+                    .unwrap_or_default()
+            })
+        }),
+        set_file,
+        handle: StoredValue::new_local(None),
+        options: StoredValue::new(options),
+    }
+}
+
+/// Options for [`use_file_system_access_with_options`].
+#[derive(DefaultBuilder, Clone, Default)]
+pub struct UseFileSystemAccessOptions {
+    /// The file types that can be selected in the pickers. Defaults to none which means all files.
+    types: Vec<FileSystemAccessFileType>,
+
+    /// If `true` the pickers don't offer an option to select all files in addition to `types`.
+    /// Defaults to `false`.
+    exclude_accept_all_option: bool,
+
+    /// Suggested file name of the save file picker. Defaults to `None`.
+    #[builder(into)]
+    suggested_name: Option<String>,
+
+    /// How the file is read into `data`. Defaults to [`FileSystemAccessDataType::Text`].
+    data_type: FileSystemAccessDataType,
+}
+
+/// File type that can be selected in the pickers of [`use_file_system_access`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FileSystemAccessFileType {
+    /// Description of the file type that is shown in the picker
+    pub description: String,
+    /// MIME types with their file extensions like `("image/*", vec![".png", ".jpg"])`
+    pub accept: Vec<(String, Vec<String>)>,
+}
+
+/// How the file is read by [`use_file_system_access`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FileSystemAccessDataType {
+    /// Read as text into [`FileSystemAccessData::Text`]
+    #[default]
+    Text,
+    /// Read into [`FileSystemAccessData::ArrayBuffer`]
+    ArrayBuffer,
+    /// Keep as [`FileSystemAccessData::Blob`]
+    Blob,
+}
+
+/// Content of the file of [`use_file_system_access`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum FileSystemAccessData {
+    Text(String),
+    ArrayBuffer(js_sys::ArrayBuffer),
+    Blob(web_sys::Blob),
+}
+
+/// Return type of [`use_file_system_access`].
+#[derive(Clone, Copy)]
+pub struct UseFileSystemAccessReturn {
+    /// Whether the browser supports the File System Access API
+    pub is_supported: Signal<bool>,
+
+    /// Content of the current file. Set it to change what is written by `save`.
+    pub data: RwSignal<Option<FileSystemAccessData>, LocalStorage>,
+
+    /// The current file
+    pub file: Signal<Option<web_sys::File>, LocalStorage>,
+
+    /// Name of the current file
+    pub file_name: Signal<String>,
+
+    /// MIME type of the current file
+    pub file_mime: Signal<String>,
+
+    /// Size of the current file in bytes
+    pub file_size: Signal<f64>,
+
+    /// Last modification of the current file in milliseconds since the Unix epoch
+    pub file_last_modified: Signal<f64>,
+
+    set_file: WriteSignal<Option<web_sys::File>, LocalStorage>,
+    handle: StoredValue<Option<JsValue>, LocalStorage>,
+    options: StoredValue<UseFileSystemAccessOptions>,
+}
+
+impl UseFileSystemAccessReturn {
+    /// Shows a file picker and reads the selected file into `data`.
+    pub async fn open(&self) -> Result<(), UseFileSystemAccessError> {
+        let Some(handle) = self.pick("showOpenFilePicker").await? else {
+            return Ok(());
+        };
+
+        let handle = js_sys::Array::from(&handle).get(0);
+        self.handle.set_value(Some(handle));
+
+        self.update_file().await?;
+        self.read_data().await
+    }
+
+    /// Shows a save file picker and creates a new empty file.
+    pub async fn create(&self) -> Result<(), UseFileSystemAccessError> {
+        let Some(handle) = self.pick("showSaveFilePicker").await? else {
+            return Ok(());
+        };
+
+        self.handle.set_value(Some(handle));
+        self.data.set(None);
+
+        self.update_file().await
+    }
+
+    /// Writes `data` to the current file. If there is no current file, it behaves like `save_as`.
+    pub async fn save(&self) -> Result<(), UseFileSystemAccessError> {
+        if self.handle.get_value().is_none() {
+            return self.save_as().await;
+        }
+
+        self.write_data().await?;
+        self.update_file().await
+    }
+
+    /// Shows a save file picker and writes `data` to the selected file.
+    pub async fn save_as(&self) -> Result<(), UseFileSystemAccessError> {
+        let Some(handle) = self.pick("showSaveFilePicker").await? else {
+            return Ok(());
+        };
+
+        self.handle.set_value(Some(handle));
+
+        self.write_data().await?;
+        self.update_file().await
+    }
+
+    /// Reads the current file into `data` again.
+    pub async fn update_data(&self) -> Result<(), UseFileSystemAccessError> {
+        self.update_file().await?;
+        self.read_data().await
+    }
+
+    /// Calls the given picker. Returns `None` if the user cancelled it.
+    async fn pick(&self, picker: &str) -> Result<Option<JsValue>, UseFileSystemAccessError> {
+        if !self.is_supported.get_untracked() {
+            return Err(UseFileSystemAccessError::NotSupported);
+        }
+
+        let picker_options = self
+            .options
+            .with_value(|options| build_picker_options(options, picker == "showSaveFilePicker"));
+
+        match call_method(&window(), picker, &[picker_options]).await {
+            Ok(handle) => Ok(Some(handle)),
+            Err(err) if is_abort_error(&err) => Ok(None),
+            Err(err) => Err(UseFileSystemAccessError::Js(err)),
+        }
+    }
+
+    async fn update_file(&self) -> Result<(), UseFileSystemAccessError> {
+        let Some(handle) = self.handle.get_value() else {
+            return Ok(());
+        };
+
+        let file = call_method(&handle, "getFile", &[])
+            .await
+            .map_err(UseFileSystemAccessError::Js)?;
+
+        self.set_file.try_set(Some(file.unchecked_into()));
+
+        Ok(())
+    }
+
+    async fn read_data(&self) -> Result<(), UseFileSystemAccessError> {
+        let Some(file) = self.file.get_untracked() else {
+            return Ok(());
+        };
+
+        let data_type = self.options.with_value(|options| options.data_type);
+
+        let data = match data_type {
+            FileSystemAccessDataType::Text => FileSystemAccessData::Text(
+                wasm_bindgen_futures::JsFuture::from(file.text())
+                    .await
+                    .map_err(UseFileSystemAccessError::Js)?
+                    .as_string()
+                    .unwrap_or_default(),
+            ),
+            FileSystemAccessDataType::ArrayBuffer => FileSystemAccessData::ArrayBuffer(
+                wasm_bindgen_futures::JsFuture::from(file.array_buffer())
+                    .await
+                    .map_err(UseFileSystemAccessError::Js)?
+                    .unchecked_into(),
+            ),
+            FileSystemAccessDataType::Blob => FileSystemAccessData::Blob(file.into()),
+        };
+
+        self.data.try_set(Some(data));
+
+        Ok(())
+    }
+
+    async fn write_data(&self) -> Result<(), UseFileSystemAccessError> {
+        let Some(handle) = self.handle.get_value() else {
+            return Ok(());
+        };
+
+        let data = match self.data.get_untracked() {
+            Some(FileSystemAccessData::Text(text)) => JsValue::from(text),
+            Some(FileSystemAccessData::ArrayBuffer(buffer)) => buffer.into(),
+            Some(FileSystemAccessData::Blob(blob)) => blob.into(),
+            None => JsValue::from(""),
+        };
+
+        let writable = call_method(&handle, "createWritable", &[])
+            .await
+            .map_err(UseFileSystemAccessError::Js)?;
+
+        if let Err(err) = call_method(&writable, "write", &[data]).await {
+            // Discard the partial write and release the lock on the file.
+            let _ = call_method(&writable, "abort", &[]).await;
+            return Err(UseFileSystemAccessError::Js(err));
+        }
+
+        call_method(&writable, "close", &[])
+            .await
+            .map_err(UseFileSystemAccessError::Js)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum UseFileSystemAccessError {
+    #[error("the File System Access API is not supported")]
+    NotSupported,
+    #[error("file system access failed: {0:?}")]
+    Js(JsValue),
+}
+
+fn build_picker_options(options: &UseFileSystemAccessOptions, save: bool) -> JsValue {
+    let picker_options = js_sys::Object::new();
+
+    let types = options
+        .types
+        .iter()
+        .map(|file_type| {
+            let accept = js_sys::Object::new();
+            for (mime, extensions) in &file_type.accept {
+                let extensions = extensions
+                    .iter()
+                    .map(|extension| JsValue::from(extension.as_str()))
+                    .collect::<js_sys::Array>();
+                let _ = js_sys::Reflect::set(&accept, &mime.into(), &extensions);
+            }
+
+            let file_type_object = js_sys::Object::new();
+            let _ = js_sys::Reflect::set(
+                &file_type_object,
+                &"description".into(),
+                &file_type.description.as_str().into(),
+            );
+            let _ = js_sys::Reflect::set(&file_type_object, &"accept".into(), &accept);
+
+            JsValue::from(file_type_object)
+        })
+        .collect::<js_sys::Array>();
+
+    js!(picker_options["types"] = types);
+    js!(picker_options["excludeAcceptAllOption"] = options.exclude_accept_all_option);
+
+    if save {
+        if let Some(suggested_name) = &options.suggested_name {
+            js!(picker_options["suggestedName"] = suggested_name.as_str());
+        }
+    }
+
+    picker_options.into()
+}
+
+/// Calls the method `name` of `target` and awaits the returned promise.
+async fn call_method(target: &JsValue, name: &str, args: &[JsValue]) -> Result<JsValue, JsValue> {
+    let method = js_sys::Reflect::get(target, &name.into())?.dyn_into::<js_sys::Function>()?;
+
+    let result = method.apply(target, &args.iter().collect::<js_sys::Array>())?;
+
+    wasm_bindgen_futures::JsFuture::from(js_sys::Promise::resolve(&result)).await
+}
+
+fn is_abort_error(err: &JsValue) -> bool {
+    js!(err["name"]).ok().and_then(|name| name.as_string()) == Some("AbortError".to_string())
+}
@@ -0,0 +1,110 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::IntoElementMaybeSignal;
+use crate::use_event_listener;
+use leptos::ev::{blur, focus};
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Reactive utility to track or set the focus state of a DOM element.
+///
+/// State changes to reflect whether the target element is the focused element. Setting the
+/// state from outside will trigger `focus` and `blur` events for the `true` and `false` values respectively.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Input;
+/// # use leptos_use::{use_focus, UseFocusReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let input = NodeRef::<Input>::new();
+///
+/// let UseFocusReturn { focused, set_focused } = use_focus(input);
+///
+/// view! {
+///     <input node_ref=input />
+///     <p>{move || if focused.get() { "Focused" } else { "Not focused" }}</p>
+///     <button on:click=move |_| set_focused.set(true)>"Focus the input"</button>
+/// }
+/// # }
+/// ```
+///
+/// Focus changes that happen programmatically, for example by calling `element.focus()`, are
+/// reflected in `focused` as well.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `focused` is always `false` and setting it has no effect.
+pub fn use_focus<El, M>(target: El) -> UseFocusReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let target = target.into_element_maybe_signal();
+
+    let focused = RwSignal::new(false);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let is_active =
+            move |el: &web_sys::Element| document().active_element().as_ref() == Some(el);
+
+        Effect::watch(
+            move || target.get(),
+            move |el, _, _| {
+                focused.set(el.as_ref().is_some_and(is_active));
+            },
+            true,
+        );
+
+        Effect::watch(
+            move || focused.get(),
+            move |focused, _, _| {
+                let Some(el) = target.get_untracked() else {
+                    return;
+                };
+
+                if *focused == is_active(&el) {
+                    return;
+                }
+
+                if let Some(el) = el.dyn_ref::<web_sys::HtmlElement>() {
+                    if *focused {
+                        let _ = el.focus();
+                    } else {
+                        let _ = el.blur();
+                    }
+                } else if let Some(el) = el.dyn_ref::<web_sys::SvgElement>() {
+                    if *focused {
+                        let _ = el.focus();
+                    } else {
+                        let _ = el.blur();
+                    }
+                }
+            },
+            false,
+        );
+
+        let _ = use_event_listener(target, focus, move |_| focused.set(true));
+        let _ = use_event_listener(target, blur, move |_| focused.set(false));
+    }
+
+    let (focused, set_focused) = focused.split();
+
+    UseFocusReturn {
+        focused: focused.into(),
+        set_focused,
+    }
+}
+
+/// Return type of [`use_focus`].
+#[derive(Clone, Copy)]
+pub struct UseFocusReturn {
+    /// Whether the target element is focused.
+    pub focused: Signal<bool>,
+
+    /// Focuses the target element when set to `true` and blurs it when set to `false`.
+    pub set_focused: WriteSignal<bool>,
+}
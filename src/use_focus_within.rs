@@ -0,0 +1,81 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::IntoElementMaybeSignal;
+use crate::use_event_listener;
+use leptos::ev::{focusin, focusout};
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Reactive utility to track if an element or one of its descendants has focus.
+///
+/// It is meant to match the behavior of the `:focus-within` CSS pseudo-class.
+/// A common use case would be on a form element to see if any of its inputs currently has focus.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Form;
+/// # use leptos_use::use_focus_within;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let form = NodeRef::<Form>::new();
+///
+/// let focused = use_focus_within(form);
+///
+/// view! {
+///     <form node_ref=form>
+///         <input type="text" placeholder="First Name" />
+///         <input type="text" placeholder="Last Name" />
+///     </form>
+///     <p>{move || if focused.get() { "The form has focus" } else { "The form doesn't have focus" }}</p>
+/// }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server this returns a `Signal` that is always `false`.
+pub fn use_focus_within<El, M>(target: El) -> Signal<bool>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let target = target.into_element_maybe_signal();
+
+    let (focused, set_focused) = signal(false);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        Effect::watch(
+            move || target.get(),
+            move |el, _, _| {
+                set_focused.set(el.as_ref().is_some_and(|el| {
+                    el.contains(
+                        document()
+                            .active_element()
+                            .as_ref()
+                            .map(|active| active.unchecked_ref()),
+                    )
+                }));
+            },
+            true,
+        );
+
+        // `focusin` and `focusout` bubble, so they are also dispatched for descendants
+        let _ = use_event_listener(target, focusin, move |_| set_focused.set(true));
+        let _ = use_event_listener(target, focusout, move |evt| {
+            let related_target = evt
+                .related_target()
+                .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+
+            set_focused.set(target.with_untracked(|el| {
+                el.as_ref().is_some_and(|el| {
+                    related_target.is_some() && el.contains(related_target.as_ref())
+                })
+            }));
+        });
+    }
+
+    focused.into()
+}
@@ -0,0 +1,35 @@
+use crate::use_media_query;
+use leptos::reactive::wrappers::read::Signal;
+
+/// Reactive [forced-colors](https://developer.mozilla.org/en-US/docs/Web/CSS/@media/forced-colors) media query.
+///
+/// Forced colors are active for example in the high contrast mode of Windows. In this case the
+/// browser replaces your colors with a limited palette chosen by the user, so custom backgrounds
+/// should be disabled.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_forced_colors;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let is_forced_colors = use_forced_colors();
+///
+/// view! { <div class:custom-background=move || !is_forced_colors.get()>"..."</div> }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server this returns a `Signal` that always contains the value `false`.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_media_query`]
+/// * [`fn@crate::use_preferred_contrast`]
+/// * [`fn@crate::use_prefers_reduced_transparency`]
+pub fn use_forced_colors() -> Signal<bool> {
+    use_media_query("(forced-colors: active)")
+}
@@ -0,0 +1,96 @@
+use crate::{use_raf_fn_with_options, UseRafFnOptions};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Reactive frames per second.
+///
+/// The frame rate is measured with `request_animation_frame` and averaged over the last `every`
+/// frames. The returned signal is updated only once per `every` frames.
+///
+/// Measuring is paused while the tab is hidden because browsers throttle animation frames of
+/// background tabs, which would otherwise pollute the average.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_fps;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let fps = use_fps();
+///
+/// view! { <div>"FPS: " {move || format!("{:.0}", fps.get())}</div> }
+/// # }
+/// ```
+///
+/// To get a smoother value, average over more frames.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_fps_with_options, UseFpsOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let fps = use_fps_with_options(UseFpsOptions::default().every(60));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server this returns a `Signal` that always contains `0.0`.
+pub fn use_fps() -> Signal<f64> {
+    use_fps_with_options(UseFpsOptions::default())
+}
+
+/// Version of [`use_fps`] that takes a `UseFpsOptions`. See [`use_fps`] for how to use.
+pub fn use_fps_with_options(options: UseFpsOptions) -> Signal<f64> {
+    let UseFpsOptions { every } = options;
+
+    let every = every.max(1);
+
+    let (fps, set_fps) = signal(0.0);
+
+    let frames = Rc::new(Cell::new(0_u32));
+    let elapsed = Rc::new(Cell::new(0.0_f64));
+
+    let _ = use_raf_fn_with_options(
+        move |args| {
+            // The first frame after starting or resuming has no delta.
+            if args.delta <= 0.0 {
+                return;
+            }
+
+            frames.set(frames.get() + 1);
+            elapsed.set(elapsed.get() + args.delta);
+
+            if frames.get() >= every {
+                set_fps.set(1000.0 * frames.get() as f64 / elapsed.get());
+
+                frames.set(0);
+                elapsed.set(0.0);
+            }
+        },
+        UseRafFnOptions::default().pause_when_hidden(true),
+    );
+
+    fps.into()
+}
+
+/// Options for [`use_fps_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseFpsOptions {
+    /// Number of frames the frame rate is averaged over. The returned signal is updated every
+    /// `every` frames. Defaults to `10`.
+    every: u32,
+}
+
+impl Default for UseFpsOptions {
+    fn default() -> Self {
+        Self { every: 10 }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::{use_permission, PermissionState};
 use default_struct_builder::DefaultBuilder;
 use leptos::prelude::*;
 use leptos::reactive::wrappers::read::Signal;
@@ -25,12 +26,78 @@ use leptos::reactive::wrappers::read::Signal;
 ///     error,
 ///     resume,
 ///     pause,
+///     ..
 /// } = use_geolocation();
 /// #
 /// # view! { }
 /// # }
 /// ```
 ///
+/// ### Permission
+///
+/// The state of the `geolocation` permission is returned as `permission`. It's always queried
+/// (independent of `resume_on_grant`) so you can e.g. show a hint when access is denied. Querying
+/// it doesn't prompt the user.
+///
+/// If the user denies the permission at first and grants it later (e.g. in the site settings),
+/// the watch stays stopped by default. With the option `resume_on_grant` it is restarted
+/// automatically when the permission is granted. This only applies if `immediate` is `true`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_geolocation_with_options, PermissionState, UseGeolocationOptions, UseGeolocationReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseGeolocationReturn {
+///     coords,
+///     permission,
+///     ..
+/// } = use_geolocation_with_options(UseGeolocationOptions::default().resume_on_grant(true));
+///
+/// view! {
+///     <Show when=move || permission.get() == PermissionState::Denied>
+///         "Please allow access to your location in the site settings"
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ### Smoothing
+///
+/// GPS positions jitter, especially when the device doesn't move. To keep e.g. a map marker from
+/// jumping around, set the option `smoothing`. It enables a simple Kalman filter that weights every
+/// new position by its reported accuracy. The value is the expected speed of the device in meters
+/// per second. The lower it is, the smoother (and slower to follow) the result becomes.
+///
+/// The filtered position is returned as `smoothed_coords` while `coords` always contains the
+/// raw position reported by the browser.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_geolocation_with_options, UseGeolocationOptions, UseGeolocationReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseGeolocationReturn {
+///     smoothed_coords,
+///     ..
+/// } = use_geolocation_with_options(
+///     UseGeolocationOptions::default()
+///         .enable_high_accuracy(true)
+///         .smoothing(3.0), // walking speed
+/// );
+///
+/// view! {
+///     <Show when=move || smoothed_coords.get().is_some()>
+///         {move || {
+///             let coords = smoothed_coords.get().unwrap();
+///             format!("{}, {} (±{}m)", coords.latitude, coords.longitude, coords.accuracy)
+///         }}
+///     </Show>
+/// }
+/// # }
+/// ```
 ///
 /// ## SendWrapped Return
 ///
@@ -39,7 +106,11 @@ use leptos::reactive::wrappers::read::Signal;
 ///
 /// ## Server-Side Rendering
 ///
+/// It's safe to call this function at the top level of a component that is rendered on the server.
 /// On the server all signals returns will always contain `None` and the functions do nothing.
+/// `permission` is always `PermissionState::Unknown` on the server.
+/// In the browser the position is only watched after the component has been mounted or hydrated,
+/// so the signals start with `None` there as well and receive values afterwards.
 pub fn use_geolocation(
 ) -> UseGeolocationReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
     use_geolocation_with_options(UseGeolocationOptions::default())
@@ -52,6 +123,10 @@ pub fn use_geolocation_with_options(
     let (located_at, set_located_at) = signal(None::<f64>);
     let (error, set_error) = signal_local(None::<web_sys::PositionError>);
     let (coords, set_coords) = signal_local(None::<web_sys::Coordinates>);
+    let (smoothed_coords, set_smoothed_coords) = signal(None::<SmoothedCoordinates>);
+
+    // Always queried because it's part of the return value, not only for `resume_on_grant`
+    let permission = use_permission("geolocation");
 
     let resume;
     let pause;
@@ -65,6 +140,7 @@ pub fn use_geolocation_with_options(
         let _ = set_located_at;
         let _ = set_error;
         let _ = set_coords;
+        let _ = set_smoothed_coords;
     }
 
     #[cfg(not(feature = "ssr"))]
@@ -73,9 +149,25 @@ pub fn use_geolocation_with_options(
         use std::sync::{Arc, Mutex};
         use wasm_bindgen::prelude::*;
 
+        let filter = Arc::new(Mutex::new(options.smoothing.map(PositionKalmanFilter::new)));
+
         let update_position = move |position: web_sys::Position| {
+            let coords = position.coords();
+
+            let raw = SmoothedCoordinates {
+                latitude: coords.latitude(),
+                longitude: coords.longitude(),
+                accuracy: coords.accuracy(),
+            };
+
+            let smoothed = match filter.lock().unwrap().as_mut() {
+                Some(filter) => filter.process(raw, position.timestamp()),
+                None => raw,
+            };
+
             set_located_at.set(Some(position.timestamp()));
-            set_coords.set(Some(position.coords()));
+            set_coords.set(Some(coords));
+            set_smoothed_coords.set(Some(smoothed));
             set_error.set(None);
         };
 
@@ -90,11 +182,15 @@ pub fn use_geolocation_with_options(
             let position_options = options.as_position_options();
 
             sendwrap_fn!(move || {
+                if watch_handle.lock().unwrap().is_some() {
+                    return;
+                }
+
                 let navigator = use_window().navigator();
                 if let Some(navigator) = navigator {
                     if let Ok(geolocation) = navigator.geolocation() {
                         let update_position = Closure::wrap(
-                            Box::new(update_position) as Box<dyn Fn(web_sys::Position)>
+                            Box::new(update_position.clone()) as Box<dyn Fn(web_sys::Position)>
                         );
                         let on_error = Closure::wrap(
                             Box::new(on_error) as Box<dyn Fn(web_sys::PositionError)>
@@ -116,7 +212,9 @@ pub fn use_geolocation_with_options(
         };
 
         if options.immediate {
-            resume();
+            // Start watching only after the component has been mounted (or hydrated).
+            let resume = resume.clone();
+            Effect::new(move |_| resume());
         }
 
         pause = {
@@ -125,7 +223,7 @@ pub fn use_geolocation_with_options(
             sendwrap_fn!(move || {
                 let navigator = use_window().navigator();
                 if let Some(navigator) = navigator {
-                    if let Some(handle) = *watch_handle.lock().unwrap() {
+                    if let Some(handle) = watch_handle.lock().unwrap().take() {
                         if let Ok(geolocation) = navigator.geolocation() {
                             geolocation.clear_watch(handle);
                         }
@@ -134,6 +232,28 @@ pub fn use_geolocation_with_options(
             })
         };
 
+        if options.immediate && options.resume_on_grant {
+            let pause = pause.clone();
+            let resume = resume.clone();
+
+            Effect::watch(
+                move || permission.get(),
+                move |permission, prev_permission, _| {
+                    if *permission == PermissionState::Granted
+                        && matches!(
+                            prev_permission,
+                            Some(PermissionState::Denied | PermissionState::Prompt)
+                        )
+                    {
+                        // the previous watch has been stopped by the browser with an error
+                        pause();
+                        resume();
+                    }
+                },
+                false,
+            );
+        }
+
         on_cleanup({
             let pause = pause.clone();
 
@@ -145,10 +265,12 @@ pub fn use_geolocation_with_options(
 
     UseGeolocationReturn {
         coords: coords.into(),
+        smoothed_coords: smoothed_coords.into(),
         located_at: located_at.into(),
         error: error.into(),
         resume,
         pause,
+        permission,
     }
 }
 
@@ -160,6 +282,11 @@ pub struct UseGeolocationOptions {
     /// If `false` you have to call `resume` manually to start it. Defaults to `true`.
     immediate: bool,
 
+    /// If `true` and `immediate` is `true`, the geolocation watch is restarted automatically when
+    /// the `geolocation` permission is granted after it had been denied or not yet decided.
+    /// Defaults to `false`.
+    resume_on_grant: bool,
+
     /// A boolean value that indicates the application would like to receive the best
     /// possible results. If `true` and if the device is able to provide a more accurate
     /// position, it will do so. Note that this can result in slower response times or
@@ -177,6 +304,13 @@ pub struct UseGeolocationOptions {
     /// the device is allowed to take in order to return a position.
     /// The default value is 27000.
     timeout: u32,
+
+    /// If set, `smoothed_coords` are filtered with a simple Kalman filter that weights positions
+    /// by their accuracy. The value is the expected speed of the device in meters per second.
+    /// Lower values result in smoother but slower reacting coordinates.
+    /// Defaults to `None` which means that `smoothed_coords` are the same as `coords`.
+    #[builder(into)]
+    smoothing: Option<f64>,
 }
 
 impl Default for UseGeolocationOptions {
@@ -186,6 +320,8 @@ impl Default for UseGeolocationOptions {
             maximum_age: 30000,
             timeout: 27000,
             immediate: true,
+            resume_on_grant: false,
+            smoothing: None,
         }
     }
 }
@@ -219,6 +355,10 @@ where
     /// See [`GeolocationCoordinates`](https://developer.mozilla.org/en-US/docs/Web/API/GeolocationCoordinates)..
     pub coords: Signal<Option<web_sys::Coordinates>, LocalStorage>,
 
+    /// The current position filtered according to the option `smoothing`. If `smoothing` is
+    /// not set, this is the same position as `coords`.
+    pub smoothed_coords: Signal<Option<SmoothedCoordinates>>,
+
     /// The timestamp of the current coordinates.
     pub located_at: Signal<Option<f64>>,
 
@@ -230,4 +370,71 @@ where
 
     /// Pause the geolocation watch.
     pub pause: PauseFn,
+
+    /// The state of the `geolocation` permission. Querying it doesn't prompt the user.
+    pub permission: Signal<PermissionState>,
+}
+
+/// Position returned as `smoothed_coords` by [`use_geolocation`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SmoothedCoordinates {
+    /// Latitude in decimal degrees.
+    pub latitude: f64,
+
+    /// Longitude in decimal degrees.
+    pub longitude: f64,
+
+    /// Estimated accuracy of the position in meters.
+    pub accuracy: f64,
+}
+
+/// Minimal Kalman filter for positions where the measurement noise is the reported accuracy.
+#[cfg(not(feature = "ssr"))]
+struct PositionKalmanFilter {
+    /// Expected speed in meters per second.
+    speed: f64,
+    /// Estimated position and its variance in square meters. `None` before the first position.
+    state: Option<(SmoothedCoordinates, f64)>,
+    timestamp: f64,
+}
+
+#[cfg(not(feature = "ssr"))]
+impl PositionKalmanFilter {
+    fn new(speed: f64) -> Self {
+        Self {
+            speed,
+            state: None,
+            timestamp: 0.0,
+        }
+    }
+
+    fn process(&mut self, measurement: SmoothedCoordinates, timestamp: f64) -> SmoothedCoordinates {
+        let measurement_variance = measurement.accuracy.max(1.0).powi(2);
+
+        let Some((estimate, mut variance)) = self.state else {
+            self.state = Some((measurement, measurement_variance));
+            self.timestamp = timestamp;
+            return measurement;
+        };
+
+        // the longer ago the last position, the more uncertain the estimate
+        let elapsed_ms = timestamp - self.timestamp;
+        if elapsed_ms > 0.0 {
+            variance += elapsed_ms * self.speed * self.speed / 1000.0;
+            self.timestamp = timestamp;
+        }
+
+        let gain = variance / (variance + measurement_variance);
+        variance *= 1.0 - gain;
+
+        let estimate = SmoothedCoordinates {
+            latitude: estimate.latitude + gain * (measurement.latitude - estimate.latitude),
+            longitude: estimate.longitude + gain * (measurement.longitude - estimate.longitude),
+            accuracy: variance.sqrt(),
+        };
+
+        self.state = Some((estimate, variance));
+
+        estimate
+    }
 }
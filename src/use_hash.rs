@@ -0,0 +1,148 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{use_event_listener, use_window};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::{hashchange, popstate};
+use leptos::prelude::*;
+use wasm_bindgen::JsValue;
+
+/// Reactive [`location.hash`](https://developer.mozilla.org/en-US/docs/Web/API/Location/hash).
+///
+/// The returned hash never contains the leading `#`. Setting the hash updates the url with the
+/// History API so no navigation (and no scrolling to an anchor) is triggered. Afterwards a
+/// `hashchange` event is dispatched on the window so other listeners like routers are notified
+/// just as if the hash had been changed by the browser.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_hash;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (hash, set_hash) = use_hash();
+///
+/// view! {
+///     <button on:click=move |_| set_hash.set("settings".to_string())>"Settings"</button>
+///     <button on:click=move |_| set_hash.set("profile".to_string())>"Profile"</button>
+///
+///     <Show when=move || hash.get() == "settings">
+///         "Settings Tab"
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// By default a new history entry is created for every change so the back button returns to
+/// the previous hash. Set the option `replace` to `true` to replace the current entry instead.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_hash_with_options, UseHashOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (hash, set_hash) = use_hash_with_options(UseHashOptions::default().replace(true));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server the hash is always an empty string and setting it has no effect.
+/// Browsers don't send the hash to the server.
+pub fn use_hash() -> (Signal<String>, WriteSignal<String>) {
+    use_hash_with_options(UseHashOptions::default())
+}
+
+/// Version of [`use_hash`] that takes a `UseHashOptions`. See [`use_hash`] for how to use.
+pub fn use_hash_with_options(options: UseHashOptions) -> (Signal<String>, WriteSignal<String>) {
+    let UseHashOptions { replace } = options;
+
+    let (hash, set_hash) = signal(String::new());
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let read = || {
+            let hash = window().location().hash().unwrap_or_default();
+            normalize_hash(&hash).to_string()
+        };
+
+        let update_from_url = move || {
+            let url_hash = read();
+
+            if hash.with_untracked(|hash| hash != &url_hash) {
+                set_hash.set(url_hash);
+            }
+        };
+
+        update_from_url();
+
+        Effect::watch(
+            move || hash.get(),
+            move |hash: &String, _, _| {
+                let hash = normalize_hash(hash);
+
+                if hash == read() {
+                    return;
+                }
+
+                let location = window().location();
+                let url = format!(
+                    "{}{}{}",
+                    location.pathname().unwrap_or_default(),
+                    location.search().unwrap_or_default(),
+                    if hash.is_empty() {
+                        String::new()
+                    } else {
+                        format!("#{hash}")
+                    },
+                );
+
+                let old_url = location.href().unwrap_or_default();
+
+                if let Ok(history) = window().history() {
+                    let result = if replace {
+                        history.replace_state_with_url(&JsValue::NULL, "", Some(&url))
+                    } else {
+                        history.push_state_with_url(&JsValue::NULL, "", Some(&url))
+                    };
+
+                    // The History API doesn't fire `hashchange` by itself
+                    if result.is_ok() {
+                        let init = web_sys::HashChangeEventInit::new();
+                        init.set_old_url(&old_url);
+                        init.set_new_url(&location.href().unwrap_or_default());
+
+                        if let Ok(event) =
+                            web_sys::HashChangeEvent::new_with_event_init_dict("hashchange", &init)
+                        {
+                            let _ = window().dispatch_event(&event);
+                        }
+                    }
+                }
+            },
+            false,
+        );
+
+        let _ = use_event_listener(use_window(), hashchange, move |_| update_from_url());
+        let _ = use_event_listener(use_window(), popstate, move |_| update_from_url());
+    }
+
+    (hash.into(), set_hash)
+}
+
+#[cfg(not(feature = "ssr"))]
+fn normalize_hash(hash: &str) -> &str {
+    hash.strip_prefix('#').unwrap_or(hash)
+}
+
+/// Options for [`use_hash_with_options`].
+#[derive(DefaultBuilder, Default, Clone, Copy)]
+pub struct UseHashOptions {
+    /// If `true` changes replace the current history entry instead of creating a new one.
+    /// Defaults to `false`.
+    replace: bool,
+}
@@ -41,7 +41,7 @@ use leptos::reactive::wrappers::read::Signal;
 /// # #[component]
 /// # fn Demo() -> impl IntoView {
 /// let UseIdleReturn {
-///     idle, last_active, reset
+///     idle, last_active, reset, ..
 /// } = use_idle(5 * 60 * 1000); // 5 minutes
 ///
 /// reset(); // restarts the idle timer. Does not change the `last_active` value.
@@ -50,6 +50,26 @@ use leptos::reactive::wrappers::read::Signal;
 /// # }
 /// ```
 ///
+/// Warning before a session times out:
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_idle, UseIdleReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseIdleReturn {
+///     seconds_until_idle, ..
+/// } = use_idle(5 * 60 * 1000); // 5 minutes
+///
+/// view! {
+///     <Show when=move || seconds_until_idle.get() <= 30.0>
+///         "You'll be logged out in " {move || seconds_until_idle.get().ceil()} "s"
+///     </Show>
+/// }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
 /// The returned closure `reset` is a sendwrapped function. It can
@@ -63,6 +83,7 @@ use leptos::reactive::wrappers::read::Signal;
 /// UseIdleReturn{
 ///     idle: Signal(initial_state),
 ///     last_active: Signal(now),
+///     seconds_until_idle: Signal(if initial_state { 0.0 } else { timeout / 1000.0 }),
 ///     reset: || {}
 /// }
 /// ```
@@ -84,6 +105,8 @@ pub fn use_idle_with_options(
 
     let (idle, set_idle) = signal(initial_state);
     let (last_active, set_last_active) = signal(now());
+    let (timer_started, set_timer_started) = signal(now());
+    let (current_time, set_current_time) = signal(now());
 
     let reset;
 
@@ -96,6 +119,8 @@ pub fn use_idle_with_options(
         let _ = filter;
         let _ = set_last_active;
         let _ = set_idle;
+        let _ = set_timer_started;
+        let _ = set_current_time;
     }
 
     #[cfg(not(feature = "ssr"))]
@@ -118,6 +143,8 @@ pub fn use_idle_with_options(
 
             sendwrap_fn!(move || {
                 set_idle.set(false);
+                set_timer_started.set(now());
+                set_current_time.set(now());
                 if let Some(timer) = timer.replace(
                     set_timeout_with_handle(
                         move || set_idle.set(true),
@@ -163,12 +190,33 @@ pub fn use_idle_with_options(
             });
         }
 
+        if let Ok(interval) = set_interval_with_handle(
+            move || {
+                if !idle.get_untracked() {
+                    set_current_time.set(now());
+                }
+            },
+            Duration::from_secs(1),
+        ) {
+            on_cleanup(move || interval.clear());
+        }
+
         reset.clone()();
     }
 
+    let seconds_until_idle = Signal::derive(move || {
+        if idle.get() {
+            return 0.0;
+        }
+
+        let elapsed = current_time.get() - timer_started.get();
+        ((timeout as f64 - elapsed) / 1000.0).max(0.0)
+    });
+
     UseIdleReturn {
         idle: idle.into(),
         last_active: last_active.into(),
+        seconds_until_idle,
         reset,
     }
 }
@@ -229,6 +277,10 @@ where
     /// Timestamp of last user activity.
     pub last_active: Signal<f64>,
 
+    /// Seconds left until the user is considered idle. Updated every second while the user is
+    /// active and `0.0` once idle.
+    pub seconds_until_idle: Signal<f64>,
+
     /// Reset function. Sets the idle state to `false`.
     pub reset: F,
 }
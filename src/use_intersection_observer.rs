@@ -49,9 +49,94 @@ cfg_if! { if #[cfg(not(feature = "ssr"))] {
 /// # }
 /// ```
 ///
+/// ### Multiple targets
+///
+/// One observer can watch many elements which is a lot more efficient than one observer per
+/// element. You can either pass multiple targets like a `Vec<NodeRef>` or a `Signal<Vec<web_sys::Element>>`
+/// or add and remove elements with the returned `observe` and `unobserve` functions.
+/// Use [`web_sys::IntersectionObserverEntry::target`] in the callback to find out to which
+/// element an entry belongs.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_intersection_observer, UseIntersectionObserverReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (visible_ids, set_visible_ids) = signal(Vec::<String>::new());
+///
+/// let UseIntersectionObserverReturn {
+///     observe, unobserve, ..
+/// } = use_intersection_observer(
+///     Vec::<web_sys::Element>::new(),
+///     move |entries, _| {
+///         set_visible_ids.update(|ids| {
+///             for entry in entries {
+///                 let id = entry.target().id();
+///                 ids.retain(|i| i != &id);
+///                 if entry.is_intersecting() {
+///                     ids.push(id);
+///                 }
+///             }
+///         });
+///     },
+/// );
+///
+/// let item = NodeRef::<Div>::new();
+///
+/// Effect::new(move || {
+///     if let Some(item) = item.get() {
+///         observe(&item);
+///     }
+/// });
+///
+/// view! {
+///     <div id="item-1" node_ref=item>"Item 1"</div>
+/// }
+/// # }
+/// ```
+///
+/// ### Reactive root margin
+///
+/// The option `root_margin` can be a signal. An `IntersectionObserver` can't change its root
+/// margin after creation, so every change disconnects the current observer and creates a new one
+/// that observes the same targets. The callback is called again with the initial entries.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Img;
+/// # use leptos_use::{use_intersection_observer_with_options, UseIntersectionObserverOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let target = NodeRef::<Img>::new();
+/// let (is_slow_connection, set_slow_connection) = signal(false);
+///
+/// // preload images earlier on fast connections
+/// let preload_margin = Signal::derive(move || {
+///     if is_slow_connection.get() {
+///         "100px".to_string()
+///     } else {
+///         "800px".to_string()
+///     }
+/// });
+///
+/// use_intersection_observer_with_options(
+///     target,
+///     move |entries, _| {
+///         // load the image
+///     },
+///     UseIntersectionObserverOptions::default().root_margin(preload_margin),
+/// );
+/// #
+/// # view! { <img node_ref=target /> }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
-/// The returned closures `pause`, `resume` and `stop` are sendwrapped functions. They can
+/// The returned closures `pause`, `resume`, `stop`, `observe` and `unobserve` are sendwrapped functions. They can
 /// only be called from the same thread that called `use_intersection_observer`.
 ///
 /// ## Server-Side Rendering
@@ -61,6 +146,7 @@ cfg_if! { if #[cfg(not(feature = "ssr"))] {
 /// ## See also
 ///
 /// * [`fn@crate::use_element_visibility`]
+#[allow(clippy::type_complexity)]
 pub fn use_intersection_observer<Els, M, F, RootM>(
     target: Els,
     callback: F,
@@ -68,6 +154,8 @@ pub fn use_intersection_observer<Els, M, F, RootM>(
     impl Fn() + Clone + Send + Sync,
     impl Fn() + Clone + Send + Sync,
     impl Fn() + Clone + Send + Sync,
+    impl Fn(&web_sys::Element) + Clone + Send + Sync,
+    impl Fn(&web_sys::Element) + Clone + Send + Sync,
 >
 where
     Els: IntoElementsMaybeSignal<web_sys::Element, M>,
@@ -83,6 +171,7 @@ where
 
 /// Version of [`use_intersection_observer`] that takes a [`UseIntersectionObserverOptions`]. See [`use_intersection_observer`] for how to use.
 #[cfg_attr(feature = "ssr", allow(unused_variables, unused_mut))]
+#[allow(clippy::type_complexity)]
 pub fn use_intersection_observer_with_options<Els, M, RootEl, RootM, F>(
     target: Els,
     mut callback: F,
@@ -91,6 +180,8 @@ pub fn use_intersection_observer_with_options<Els, M, RootEl, RootM, F>(
     impl Fn() + Clone + Send + Sync,
     impl Fn() + Clone + Send + Sync,
     impl Fn() + Clone + Send + Sync,
+    impl Fn(&web_sys::Element) + Clone + Send + Sync,
+    impl Fn(&web_sys::Element) + Clone + Send + Sync,
 >
 where
     Els: IntoElementsMaybeSignal<web_sys::Element, M>,
@@ -110,12 +201,16 @@ where
     let pause;
     let cleanup;
     let stop;
+    let observe;
+    let unobserve;
 
     #[cfg(feature = "ssr")]
     {
         pause = || {};
         cleanup = || {};
         stop = || {};
+        observe = |_: &web_sys::Element| {};
+        unobserve = |_: &web_sys::Element| {};
     }
 
     #[cfg(not(feature = "ssr"))]
@@ -142,6 +237,9 @@ where
         let observer: Arc<Mutex<Option<SendWrapper<web_sys::IntersectionObserver>>>> =
             Arc::new(Mutex::new(None));
 
+        // Targets added with `observe`. They are kept when the observer is re-created.
+        let observed: Arc<Mutex<Vec<SendWrapper<web_sys::Element>>>> = Arc::new(Mutex::new(vec![]));
+
         cleanup = {
             let observer = Arc::clone(&observer);
 
@@ -157,17 +255,20 @@ where
 
         let stop_watch = {
             let cleanup = cleanup.clone();
+            let observer = Arc::clone(&observer);
+            let observed = Arc::clone(&observed);
 
             watch_with_options(
                 move || {
                     (
                         targets.get(),
                         root.as_ref().map(|root| root.get()),
+                        root_margin.get(),
                         is_active.get(),
                     )
                 },
                 move |values, _, _| {
-                    let (targets, root, is_active) = values;
+                    let (targets, root, root_margin, is_active) = values;
 
                     cleanup();
 
@@ -176,7 +277,7 @@ where
                     }
 
                     let options = web_sys::IntersectionObserverInit::new();
-                    options.set_root_margin(&root_margin);
+                    options.set_root_margin(root_margin);
                     options.set_threshold(
                         &thresholds
                             .iter()
@@ -201,6 +302,10 @@ where
                         obs.observe(&target);
                     }
 
+                    for target in observed.lock().unwrap().iter() {
+                        obs.observe(target);
+                    }
+
                     *observer.lock().unwrap() = Some(SendWrapper::new(obs));
                 },
                 WatchOptions::default().immediate(immediate),
@@ -226,6 +331,31 @@ where
                 set_active.set(false);
             })
         };
+
+        observe = {
+            let observer = Arc::clone(&observer);
+            let observed = Arc::clone(&observed);
+
+            sendwrap_fn!(move |target: &web_sys::Element| {
+                let mut observed = observed.lock().unwrap();
+                if observed.iter().any(|el| **el == *target) {
+                    return;
+                }
+                observed.push(SendWrapper::new(target.clone()));
+
+                if let Some(observer) = observer.lock().unwrap().as_ref() {
+                    observer.observe(target);
+                }
+            })
+        };
+
+        unobserve = sendwrap_fn!(move |target: &web_sys::Element| {
+            observed.lock().unwrap().retain(|el| **el != *target);
+
+            if let Some(observer) = observer.lock().unwrap().as_ref() {
+                observer.unobserve(target);
+            }
+        });
     }
 
     UseIntersectionObserverReturn {
@@ -236,6 +366,8 @@ where
             set_active.set(true);
         }),
         stop,
+        observe,
+        unobserve,
     }
 }
 
@@ -263,8 +395,11 @@ where
     /// [`margin`](https://developer.mozilla.org/en-US/docs/Web/CSS/margin) property; see
     /// [The intersection root and root margin](https://developer.mozilla.org/en-US/docs/Web/API/Intersection_Observer_API#the_intersection_root_and_root_margin)
     /// for more information on how the margin works and the syntax. The default is `"0px"`.
+    ///
+    /// The `IntersectionObserver` can't change its root margin so whenever this signal changes
+    /// the observer is re-created.
     #[builder(into)]
-    root_margin: String,
+    root_margin: Signal<String>,
 
     // TODO : validate that each number is between 0 and 1 ?
     /// A `Vec` of numbers between 0.0 and 1.0, specifying a ratio of intersection area to total
@@ -295,11 +430,13 @@ where
 }
 
 /// The return value of [`use_intersection_observer`].
-pub struct UseIntersectionObserverReturn<StopFn, PauseFn, ResumeFn>
+pub struct UseIntersectionObserverReturn<StopFn, PauseFn, ResumeFn, ObserveFn, UnobserveFn>
 where
     StopFn: Fn() + Clone + Send + Sync,
     PauseFn: Fn() + Clone + Send + Sync,
     ResumeFn: Fn() + Clone + Send + Sync,
+    ObserveFn: Fn(&web_sys::Element) + Clone + Send + Sync,
+    UnobserveFn: Fn(&web_sys::Element) + Clone + Send + Sync,
 {
     /// Pauses the `IntersectionObserver` observations. Will cause `is_active = false`.
     pub pause: PauseFn,
@@ -309,4 +446,9 @@ where
     pub stop: StopFn,
     /// A signal which is `true` when the `IntersectionObserver` is active, and `false` when paused or stopped.
     pub is_active: Signal<bool>,
+    /// Adds an element to the observed targets. It stays observed when the observer is paused
+    /// and resumed until `unobserve` is called.
+    pub observe: ObserveFn,
+    /// Removes an element that has been added with `observe` from the observed targets.
+    pub unobserve: UnobserveFn,
 }
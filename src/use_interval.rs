@@ -1,4 +1,4 @@
-use crate::utils::Pausable;
+use crate::utils::{Pausable, UseVisibilityPause};
 use crate::{sendwrap_fn, use_interval_fn_with_options, UseIntervalFnOptions};
 use default_struct_builder::DefaultBuilder;
 use leptos::prelude::*;
@@ -30,14 +30,52 @@ use std::rc::Rc;
 /// # }
 /// ```
 ///
+/// ### Pause and resume
+///
+/// The counter only increases while the interval is active. This makes it easy to build for
+/// example a pausable countdown.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_interval, UseIntervalReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseIntervalReturn {
+///     counter,
+///     reset,
+///     is_active,
+///     pause,
+///     resume
+/// } = use_interval(1000);
+///
+/// let remaining = move || 60_u64.saturating_sub(counter.get());
+///
+/// view! {
+///     <p>{remaining} " seconds left"</p>
+///     <Show
+///         when=move || is_active.get()
+///         fallback=move || view! { <button on:click={let resume = resume.clone(); move |_| resume()}>"Resume"</button> }
+///     >
+///         <button on:click={let pause = pause.clone(); move |_| pause()}>"Pause"</button>
+///     </Show>
+///     <button on:click=move |_| reset()>"Restart"</button>
+/// }
+/// # }
+/// ```
+///
+/// To pause the interval automatically while the tab is in the background, use the option
+/// `pause_when_hidden`. See [`UseVisibilityPause`] for details.
+///
 /// ## SendWrapped Return
 ///
 /// The returned closures `pause`, `resume` and `reset` are sendwrapped functions. They can
-/// only be called from the same thread that called `use_intersection_observer`.
+/// only be called from the same thread that called `use_interval`.
 ///
 /// ## Server-Side Rendering
 ///
-/// On the server this function will simply be ignored.
+/// On the server the interval never runs. `counter` stays at `0` and `is_active` is always `false`.
+/// The returned `pause` and `resume` are no-ops while `reset` still resets the counter.
 pub fn use_interval<N>(
     interval: N,
 ) -> UseIntervalReturn<
@@ -66,6 +104,7 @@ where
     let UseIntervalOptions {
         immediate,
         callback,
+        visibility_pause,
     } = options;
 
     let (counter, set_counter) = signal(0u64);
@@ -88,6 +127,7 @@ where
         UseIntervalFnOptions {
             immediate,
             immediate_callback: false,
+            visibility_pause,
         },
     );
 
@@ -108,6 +148,10 @@ pub struct UseIntervalOptions {
 
     /// Callback on every interval.
     callback: Rc<dyn Fn(u64)>,
+
+    /// How the counter behaves while the document is hidden. See [`UseVisibilityPause`].
+    /// Defaults to not pausing.
+    visibility_pause: UseVisibilityPause,
 }
 
 impl Default for UseIntervalOptions {
@@ -115,6 +159,22 @@ impl Default for UseIntervalOptions {
         Self {
             immediate: true,
             callback: Rc::new(|_: u64| {}),
+            visibility_pause: UseVisibilityPause::default(),
+        }
+    }
+}
+
+impl UseIntervalOptions {
+    /// Pause the counter while the document is hidden (e.g. the tab is in the background) and
+    /// start the interval over when it becomes visible again.
+    /// Shortcut for `visibility_pause(UseVisibilityPause::default().pause_when_hidden(true))`.
+    pub fn pause_when_hidden(self, pause_when_hidden: bool) -> Self {
+        Self {
+            visibility_pause: UseVisibilityPause {
+                pause_when_hidden,
+                ..self.visibility_pause
+            },
+            ..self
         }
     }
 }
@@ -1,7 +1,7 @@
 #![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
 
 use crate::sendwrap_fn;
-use crate::utils::Pausable;
+use crate::utils::{Pausable, UseVisibilityPause};
 use default_struct_builder::DefaultBuilder;
 use leptos::leptos_dom::helpers::IntervalHandle;
 use leptos::prelude::*;
@@ -35,6 +35,29 @@ use std::time::Duration;
 /// # }
 /// ```
 ///
+/// ### Pause when hidden
+///
+/// To stop the timer while the document is hidden (e.g. the tab is in the background), set
+/// `pause_when_hidden` to `true`. When the document becomes visible again the timer starts
+/// over. See [`UseVisibilityPause`] for calling the callback right away if it has been missed.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_interval_fn_with_options, UseIntervalFnOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// use_interval_fn_with_options(
+///     || {
+///         // do something
+///     },
+///     1000,
+///     UseIntervalFnOptions::default().pause_when_hidden(true),
+/// );
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
 /// The returned closures `pause` and `resume` are sendwrapped functions. They can
@@ -42,7 +65,8 @@ use std::time::Duration;
 ///
 /// ## Server-Side Rendering
 ///
-/// On the server this function will simply be ignored.
+/// On the server the callback is never called, not even with `immediate_callback` enabled.
+/// The returned `pause` and `resume` are no-ops and `is_active` is always `false`.
 pub fn use_interval_fn<CbFn, N>(
     callback: CbFn,
     interval: N,
@@ -67,6 +91,7 @@ where
     let UseIntervalFnOptions {
         immediate,
         immediate_callback,
+        visibility_pause,
     } = options;
 
     let timer: Arc<SendWrapper<Cell<Option<IntervalHandle>>>> =
@@ -95,6 +120,8 @@ where
 
     let interval = interval.into();
 
+    let resume_callback = callback.clone();
+
     let resume = sendwrap_fn!(move || {
         #[cfg(not(feature = "ssr"))]
         {
@@ -106,7 +133,7 @@ where
             set_active.set(true);
 
             let callback = {
-                let callback = callback.clone();
+                let callback = resume_callback.clone();
 
                 move || {
                     #[cfg(debug_assertions)]
@@ -148,6 +175,21 @@ where
         on_cleanup(move || effect.stop());
     }
 
+    crate::utils::use_visibility_pause(
+        visibility_pause,
+        is_active.into(),
+        pause.clone(),
+        resume.clone(),
+        move |hidden_ms| {
+            if hidden_ms >= interval.get_untracked() as f64 {
+                #[cfg(debug_assertions)]
+                let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+                callback();
+            }
+        },
+    );
+
     on_cleanup({
         let pause = SendWrapper::new(pause.clone());
         #[allow(clippy::redundant_closure)]
@@ -169,6 +211,10 @@ pub struct UseIntervalFnOptions {
 
     /// Execute the callback immediate after calling this function. Defaults to `false`
     pub immediate_callback: bool,
+
+    /// How the timer behaves while the document is hidden. See [`UseVisibilityPause`].
+    /// Defaults to not pausing.
+    pub visibility_pause: UseVisibilityPause,
 }
 
 impl Default for UseIntervalFnOptions {
@@ -176,6 +222,22 @@ impl Default for UseIntervalFnOptions {
         Self {
             immediate: true,
             immediate_callback: false,
+            visibility_pause: UseVisibilityPause::default(),
+        }
+    }
+}
+
+impl UseIntervalFnOptions {
+    /// Pause the timer while the document is hidden (e.g. the tab is in the background) and
+    /// start it over when it becomes visible again.
+    /// Shortcut for `visibility_pause(UseVisibilityPause::default().pause_when_hidden(true))`.
+    pub fn pause_when_hidden(self, pause_when_hidden: bool) -> Self {
+        Self {
+            visibility_pause: UseVisibilityPause {
+                pause_when_hidden,
+                ..self.visibility_pause
+            },
+            ..self
         }
     }
 }
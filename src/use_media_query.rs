@@ -2,11 +2,14 @@
 
 use crate::use_event_listener;
 use cfg_if::cfg_if;
+use default_struct_builder::DefaultBuilder;
 use leptos::ev::change;
 use leptos::prelude::*;
 use leptos::reactive::wrappers::read::Signal;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::fmt::Display;
 use std::rc::Rc;
+use std::sync::Arc;
 
 /// Reactive [Media Query](https://developer.mozilla.org/en-US/docs/Web/CSS/Media_Queries/Testing_media_queries).
 ///
@@ -31,9 +34,59 @@ use std::rc::Rc;
 /// # }
 /// ```
 ///
+/// ### Typed queries
+///
+/// Instead of writing the query string by hand you can build it with [`MediaQuery`]. This way
+/// a typo can't lead to a query that never matches.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_media_query, MediaQuery, MediaQueryOrientation, MediaQueryPointer};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// #
+/// // (min-width: 1024px) and (orientation: landscape)
+/// let is_large_landscape = use_media_query(
+///     MediaQuery::default()
+///         .min_width(1024_u32)
+///         .orientation(MediaQueryOrientation::Landscape),
+/// );
+///
+/// // (pointer: coarse)
+/// let is_touch = use_media_query(MediaQuery::default().pointer(MediaQueryPointer::Coarse));
+/// #
+/// #    view! { }
+/// # }
+/// ```
+///
+/// ### Change callback
+///
+/// To run a side effect whenever the query starts or stops matching, provide `on_change`.
+/// It's not called for the initial value.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::logging::log;
+/// # use leptos_use::{use_media_query_with_options, UseMediaQueryOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// #
+/// let is_mobile = use_media_query_with_options(
+///     "(max-width: 640px)",
+///     UseMediaQueryOptions::default().on_change(|is_mobile: bool| {
+///         log!("Switched to {} layout", if is_mobile { "mobile" } else { "desktop" });
+///     }),
+/// );
+/// #
+/// #    view! { }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
-/// On the server this functions returns a Signal that is always `false`.
+/// On the server this functions returns a Signal that is always `false` and `on_change` is never called.
 ///
 /// ## See also
 ///
@@ -41,6 +94,16 @@ use std::rc::Rc;
 /// * [`fn@crate::use_preferred_contrast`]
 /// * [`fn@crate::use_prefers_reduced_motion`]
 pub fn use_media_query(query: impl Into<Signal<String>>) -> Signal<bool> {
+    use_media_query_with_options(query, UseMediaQueryOptions::default())
+}
+
+/// Version of [`use_media_query`] that takes a `UseMediaQueryOptions`. See [`use_media_query`] for how to use.
+pub fn use_media_query_with_options(
+    query: impl Into<Signal<String>>,
+    options: UseMediaQueryOptions,
+) -> Signal<bool> {
+    let UseMediaQueryOptions { on_change } = options;
+
     let query = query.into();
 
     let (matches, set_matches) = signal(false);
@@ -51,6 +114,22 @@ pub fn use_media_query(query: impl Into<Signal<String>>) -> Signal<bool> {
 
         let listener = Rc::new(RefCell::new(Rc::new(|_| {}) as Rc<dyn Fn(web_sys::Event)>));
 
+        // `on_change` isn't called for the initial value
+        let initialized = Rc::new(Cell::new(false));
+
+        let set = move |value: bool| {
+            let changed = value != matches.get_untracked();
+            let was_initialized = initialized.replace(true);
+            set_matches.set(value);
+
+            if changed && was_initialized {
+                #[cfg(debug_assertions)]
+                let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+                on_change(value);
+            }
+        };
+
         let cleanup = {
             let remove_listener = Rc::clone(&remove_listener);
 
@@ -64,6 +143,7 @@ pub fn use_media_query(query: impl Into<Signal<String>>) -> Signal<bool> {
         let update = {
             let cleanup = cleanup.clone();
             let listener = Rc::clone(&listener);
+            let set = set.clone();
 
             Rc::new(move || {
                 cleanup();
@@ -72,7 +152,7 @@ pub fn use_media_query(query: impl Into<Signal<String>>) -> Signal<bool> {
                 *media_query = window().match_media(&query.get()).unwrap_or(None);
 
                 if let Some(media_query) = media_query.as_ref() {
-                    set_matches.set(media_query.matches());
+                    set(media_query.matches());
 
                     let listener = Rc::clone(&*listener.borrow());
 
@@ -82,7 +162,7 @@ pub fn use_media_query(query: impl Into<Signal<String>>) -> Signal<bool> {
                         move |e| listener(e),
                     ))));
                 } else {
-                    set_matches.set(false);
+                    set(false);
                 }
             })
         };
@@ -104,4 +184,142 @@ pub fn use_media_query(query: impl Into<Signal<String>>) -> Signal<bool> {
     matches.into()
 }
 
+/// Options for [`use_media_query_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseMediaQueryOptions {
+    /// Called with the new value whenever the query starts or stops matching.
+    /// Not called for the initial value.
+    on_change: Arc<dyn Fn(bool) + Send + Sync>,
+}
+
+impl Default for UseMediaQueryOptions {
+    fn default() -> Self {
+        Self {
+            on_change: Arc::new(|_| {}),
+        }
+    }
+}
+
 type RemoveListener = Rc<RefCell<Option<Box<dyn Fn()>>>>;
+
+/// Builder for media query strings that can be passed to [`use_media_query`].
+///
+/// All the set features have to match. Widths and heights are in pixels.
+/// The query string can be obtained with `to_string()`.
+#[derive(DefaultBuilder, Default, Clone, Debug, PartialEq, Eq)]
+pub struct MediaQuery {
+    /// `(min-width: <value>px)`
+    #[builder(into)]
+    min_width: Option<u32>,
+
+    /// `(max-width: <value>px)`
+    #[builder(into)]
+    max_width: Option<u32>,
+
+    /// `(min-height: <value>px)`
+    #[builder(into)]
+    min_height: Option<u32>,
+
+    /// `(max-height: <value>px)`
+    #[builder(into)]
+    max_height: Option<u32>,
+
+    /// `(orientation: <value>)`
+    #[builder(into)]
+    orientation: Option<MediaQueryOrientation>,
+
+    /// `(hover: <value>)`
+    #[builder(into)]
+    hover: Option<MediaQueryHover>,
+
+    /// `(pointer: <value>)`
+    #[builder(into)]
+    pointer: Option<MediaQueryPointer>,
+}
+
+impl Display for MediaQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let features = [
+            self.min_width.map(|v| format!("(min-width: {v}px)")),
+            self.max_width.map(|v| format!("(max-width: {v}px)")),
+            self.min_height.map(|v| format!("(min-height: {v}px)")),
+            self.max_height.map(|v| format!("(max-height: {v}px)")),
+            self.orientation.map(|v| format!("(orientation: {v})")),
+            self.hover.map(|v| format!("(hover: {v})")),
+            self.pointer.map(|v| format!("(pointer: {v})")),
+        ];
+
+        let query = features
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" and ");
+
+        if query.is_empty() {
+            write!(f, "all")
+        } else {
+            write!(f, "{query}")
+        }
+    }
+}
+
+impl From<MediaQuery> for Signal<String> {
+    fn from(query: MediaQuery) -> Self {
+        Signal::stored(query.to_string())
+    }
+}
+
+/// Value of the `orientation` media feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MediaQueryOrientation {
+    Portrait,
+    Landscape,
+}
+
+impl Display for MediaQueryOrientation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Portrait => write!(f, "portrait"),
+            Self::Landscape => write!(f, "landscape"),
+        }
+    }
+}
+
+/// Value of the `hover` media feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MediaQueryHover {
+    /// The primary input mechanism can't hover
+    None,
+    /// The primary input mechanism can hover over elements
+    Hover,
+}
+
+impl Display for MediaQueryHover {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Hover => write!(f, "hover"),
+        }
+    }
+}
+
+/// Value of the `pointer` media feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MediaQueryPointer {
+    /// There is no pointing device
+    None,
+    /// The primary pointing device has limited accuracy, like a finger
+    Coarse,
+    /// The primary pointing device is accurate, like a mouse
+    Fine,
+}
+
+impl Display for MediaQueryPointer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Coarse => write!(f, "coarse"),
+            Self::Fine => write!(f, "fine"),
+        }
+    }
+}
@@ -0,0 +1,144 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::utils::Pausable;
+use crate::{
+    use_document_visibility, use_interval_fn_with_options, use_supported_immediately,
+    UseIntervalFnOptions,
+};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+
+/// Reactive memory usage of the JavaScript heap.
+///
+/// This uses the non-standard [`performance.memory`](https://developer.mozilla.org/en-US/docs/Web/API/Performance/memory)
+/// which is only available in Chromium based browsers. Check `is_supported` before showing the values.
+///
+/// The values are polled in an interval. Polling is paused while the tab is hidden.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_memory, UseMemoryReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseMemoryReturn {
+///     is_supported,
+///     used,
+///     total,
+///     limit,
+/// } = use_memory();
+///
+/// let to_mb = |bytes: u64| format!("{:.1} MB", bytes as f64 / 1024.0 / 1024.0);
+///
+/// view! {
+///     <Show when=move || is_supported.get() fallback=|| "Memory info isn't supported">
+///         <div>"Used: " {move || to_mb(used.get())}</div>
+///         <div>"Allocated: " {move || to_mb(total.get())}</div>
+///         <div>"Limit: " {move || to_mb(limit.get())}</div>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is `false` and all values are always `0`.
+pub fn use_memory() -> UseMemoryReturn {
+    use_memory_with_options(UseMemoryOptions::default())
+}
+
+/// Version of [`use_memory`] that takes a `UseMemoryOptions`. See [`use_memory`] for how to use.
+pub fn use_memory_with_options(options: UseMemoryOptions) -> UseMemoryReturn {
+    let UseMemoryOptions { interval } = options;
+
+    let (used, set_used) = signal(0_u64);
+    let (total, set_total) = signal(0_u64);
+    let (limit, set_limit) = signal(0_u64);
+
+    let is_supported = use_supported_immediately(|| {
+        window()
+            .performance()
+            .is_some_and(|performance| crate::js!("memory" in &performance))
+    });
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let update = move || {
+            let Some(memory) = window()
+                .performance()
+                .and_then(|performance| crate::js!(performance["memory"]).ok())
+                .filter(|memory| memory.is_object())
+            else {
+                return;
+            };
+
+            let read = |key: &str| {
+                js_sys::Reflect::get(&memory, &key.into())
+                    .ok()
+                    .and_then(|value| value.as_f64())
+                    .unwrap_or_default() as u64
+            };
+
+            set_used.set(read("usedJSHeapSize"));
+            set_total.set(read("totalJSHeapSize"));
+            set_limit.set(read("jsHeapSizeLimit"));
+        };
+
+        if is_supported.get_untracked() {
+            update();
+
+            let Pausable { pause, resume, .. } =
+                use_interval_fn_with_options(update, interval, UseIntervalFnOptions::default());
+
+            let visibility = use_document_visibility();
+
+            Effect::watch(
+                move || visibility.get(),
+                move |visibility, _, _| {
+                    if *visibility == web_sys::VisibilityState::Visible {
+                        update();
+                        resume();
+                    } else {
+                        pause();
+                    }
+                },
+                true,
+            );
+        }
+    }
+
+    UseMemoryReturn {
+        is_supported,
+        used: used.into(),
+        total: total.into(),
+        limit: limit.into(),
+    }
+}
+
+/// Options for [`use_memory_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseMemoryOptions {
+    /// Interval in milliseconds in which the memory usage is polled. Defaults to `1000`.
+    interval: u64,
+}
+
+impl Default for UseMemoryOptions {
+    fn default() -> Self {
+        Self { interval: 1000 }
+    }
+}
+
+/// Return type of [`use_memory`].
+#[derive(Clone, Copy)]
+pub struct UseMemoryReturn {
+    /// Whether the browser supports `performance.memory`
+    pub is_supported: Signal<bool>,
+    /// Used size of the JavaScript heap in bytes (`usedJSHeapSize`)
+    pub used: Signal<u64>,
+    /// Allocated size of the JavaScript heap in bytes (`totalJSHeapSize`)
+    pub total: Signal<u64>,
+    /// Maximum size of the JavaScript heap in bytes (`jsHeapSizeLimit`)
+    pub limit: Signal<u64>,
+}
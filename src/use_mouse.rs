@@ -3,7 +3,7 @@
 use crate::core::{IntoElementMaybeSignal, Position};
 use crate::{use_event_listener_with_options, use_window, UseEventListenerOptions, UseWindow};
 use default_struct_builder::DefaultBuilder;
-use leptos::ev::{dragover, mousemove, touchend, touchmove, touchstart};
+use leptos::ev::{dragover, mousedown, mousemove, mouseup, touchend, touchmove, touchstart};
 use leptos::prelude::*;
 use std::convert::Infallible;
 use std::marker::PhantomData;
@@ -48,6 +48,26 @@ use wasm_bindgen::{JsCast, JsValue};
 /// # }
 /// ```
 ///
+/// ## Pressed Buttons
+///
+/// To know if the mouse is dragged, for example to pan a canvas, you can use `pressed` together with
+/// the coordinates. `buttons` contains the bitmask of all pressed buttons.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_mouse, UseMouseReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseMouseReturn {
+///     x, y, pressed, buttons, ..
+/// } = use_mouse();
+///
+/// let is_panning = move || pressed.get() && buttons.get() & 1 == 1;
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## Custom Extractor
 ///
 /// It's also possible to provide a custom extractor to get the position from the events.
@@ -100,11 +120,22 @@ where
     let (x, set_x) = signal(options.initial_value.x);
     let (y, set_y) = signal(options.initial_value.y);
     let (source_type, set_source_type) = signal(UseMouseSourceType::Unset);
+    let (pressed, set_pressed) = signal(false);
+    let (buttons, set_buttons) = signal(0_u16);
 
     let mouse_handler = {
         let coord_type = options.coord_type.clone();
 
         move |event: web_sys::MouseEvent| {
+            // Also catches buttons released while the pointer was outside of the window.
+            let current_buttons = event.buttons();
+            if buttons.get_untracked() != current_buttons {
+                set_buttons.set(current_buttons);
+            }
+            if current_buttons == 0 && pressed.get_untracked() {
+                set_pressed.set(false);
+            }
+
             let result = coord_type.extract_mouse_coords(&event);
 
             if let Some((x, y)) = result {
@@ -167,6 +198,26 @@ where
         let _ =
             use_event_listener_with_options(target, dragover, drag_handler, event_listener_options);
 
+        let _ = use_event_listener_with_options(
+            target,
+            mousedown,
+            move |event| {
+                set_buttons.set(event.buttons());
+                set_pressed.set(true);
+            },
+            event_listener_options,
+        );
+        // Listen on the window so that releasing the button outside of the target is detected.
+        let _ = use_event_listener_with_options(
+            use_window(),
+            mouseup,
+            move |event| {
+                set_buttons.set(event.buttons());
+                set_pressed.set(false);
+            },
+            event_listener_options,
+        );
+
         if options.touch && !matches!(options.coord_type, UseMouseCoordType::Movement) {
             let _ = use_event_listener_with_options(
                 target,
@@ -197,6 +248,8 @@ where
         set_x,
         set_y,
         source_type: source_type.into(),
+        pressed: pressed.into(),
+        buttons: buttons.into(),
     }
 }
 
@@ -252,6 +305,7 @@ pub enum UseMouseCoordType<E: UseMouseEventExtractor + Clone> {
     Custom(E),
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for UseMouseCoordType<Infallible> {
     fn default() -> Self {
         Self::Page
@@ -318,6 +372,12 @@ pub struct UseMouseReturn {
     pub set_y: WriteSignal<f64>,
     /// Identifies the source of the reported coordinates
     pub source_type: Signal<UseMouseSourceType>,
+    /// Whether a mouse button is currently pressed. Becomes `true` on `mousedown` on the target and
+    /// `false` on `mouseup` anywhere in the window.
+    pub pressed: Signal<bool>,
+    /// The [bitmask of the currently pressed mouse buttons](https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/buttons).
+    /// `1` is the primary, `2` the secondary and `4` the auxiliary (middle) button.
+    pub buttons: Signal<u16>,
 }
 
 /// Identifies the source of the reported coordinates
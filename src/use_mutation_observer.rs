@@ -5,7 +5,7 @@ use leptos::reactive::wrappers::read::Signal;
 use wasm_bindgen::prelude::*;
 
 cfg_if! { if #[cfg(not(feature = "ssr"))] {
-    use crate::{sendwrap_fn, use_supported};
+    use crate::{sendwrap_fn, use_supported_immediately};
     use leptos::prelude::*;
     use std::cell::RefCell;
     use std::rc::Rc;
@@ -47,18 +47,59 @@ cfg_if! { if #[cfg(not(feature = "ssr"))] {
 /// # }
 /// ```
 ///
+/// ### Pause and resume
+///
+/// To ignore DOM mutations that you perform yourself you can `pause` the observer and `resume` it
+/// afterwards. Set the option `immediate` to `false` to start paused.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_mutation_observer_with_options, UseMutationObserverOptions, UseMutationObserverReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let el = NodeRef::<Div>::new();
+///
+/// let UseMutationObserverReturn { pause, resume, is_active, .. } = use_mutation_observer_with_options(
+///     el,
+///     move |mutations, _| {
+///         // only called for mutations made by others
+///     },
+///     UseMutationObserverOptions::default().child_list(true),
+/// );
+///
+/// let append = move |_| {
+///     pause();
+///     if let Some(el) = el.get() {
+///         let _ = el.append_with_str_1("Added by me");
+///     }
+///     resume();
+/// };
+///
+/// view! {
+///     <div node_ref=el></div>
+///     <button on:click=append>"Append"</button>
+/// }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
-/// The returned closure `stop` is a sendwrapped function. It can
-/// only be called from the same thread that called `use_mouse_in_element`.
+/// The returned closures `pause`, `resume` and `stop` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_mutation_observer`.
 ///
 /// ## Server-Side Rendering
 ///
-/// On the server this amounts to a no-op.
+/// On the server this amounts to a no-op and `is_active` is always `false`.
 pub fn use_mutation_observer<El, M, F>(
     target: El,
     callback: F,
-) -> UseMutationObserverReturn<impl Fn() + Clone + Send + Sync>
+) -> UseMutationObserverReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
 where
     El: IntoElementsMaybeSignal<web_sys::Element, M>,
     F: FnMut(Vec<web_sys::MutationRecord>, web_sys::MutationObserver) + 'static,
@@ -72,15 +113,26 @@ pub fn use_mutation_observer_with_options<El, M, F>(
     target: El,
     mut callback: F,
     options: UseMutationObserverOptions,
-) -> UseMutationObserverReturn<impl Fn() + Clone + Send + Sync>
+) -> UseMutationObserverReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+>
 where
     El: IntoElementsMaybeSignal<web_sys::Element, M>,
     F: FnMut(Vec<web_sys::MutationRecord>, web_sys::MutationObserver) + 'static,
 {
+    let immediate = options.immediate;
+
     #[cfg(feature = "ssr")]
     {
+        let _ = immediate;
+
         UseMutationObserverReturn {
             is_supported: Signal::derive(|| true),
+            is_active: Signal::from(false),
+            pause: || {},
+            resume: || {},
             stop: || {},
         }
     }
@@ -109,7 +161,9 @@ where
 
         let observer: Rc<RefCell<Option<web_sys::MutationObserver>>> = Rc::new(RefCell::new(None));
 
-        let is_supported = use_supported(|| js!("MutationObserver" in &window()));
+        let is_supported = use_supported_immediately(|| js!("MutationObserver" in &window()));
+
+        let (is_active, set_active) = signal(immediate);
 
         let cleanup = {
             let observer = Rc::clone(&observer);
@@ -129,11 +183,11 @@ where
             let cleanup = cleanup.clone();
 
             let stop = Effect::watch(
-                move || targets.get(),
-                move |targets, _, _| {
+                move || (targets.get(), is_active.get()),
+                move |(targets, is_active), _, _| {
                     cleanup();
 
-                    if is_supported.get() && !targets.is_empty() {
+                    if *is_active && is_supported.get() && !targets.is_empty() {
                         let obs =
                             web_sys::MutationObserver::new(closure_js.as_ref().unchecked_ref())
                                 .expect("failed to create MutationObserver");
@@ -152,24 +206,53 @@ where
             move || stop.stop()
         };
 
-        let stop = sendwrap_fn!(move || {
-            cleanup();
-            stop_watch();
-        });
+        let stop = {
+            let cleanup = cleanup.clone();
+
+            sendwrap_fn!(move || {
+                cleanup();
+                stop_watch();
+                set_active.set(false);
+            })
+        };
 
         on_cleanup({
             let stop = SendWrapper::new(stop.clone());
+
             #[allow(clippy::redundant_closure)]
             move || stop()
         });
 
-        UseMutationObserverReturn { is_supported, stop }
+        let pause = {
+            let cleanup = cleanup.clone();
+
+            sendwrap_fn!(move || {
+                cleanup();
+                set_active.set(false);
+            })
+        };
+
+        let resume = sendwrap_fn!(move || {
+            set_active.set(true);
+        });
+
+        UseMutationObserverReturn {
+            is_supported,
+            is_active: is_active.into(),
+            pause,
+            resume,
+            stop,
+        }
     }
 }
 
 /// Options for [`use_mutation_observer_with_options`].
-#[derive(DefaultBuilder, Clone, Default)]
+#[derive(DefaultBuilder, Clone)]
 pub struct UseMutationObserverOptions {
+    /// If `true`, the `MutationObserver` is attached immediately. Otherwise it is only attached
+    /// after the returned `resume` closure is called. The default value is `true`.
+    immediate: bool,
+
     /// Set to `true` to extend monitoring to the entire subtree of nodes rooted at `target`.
     /// All of the other properties are then extended to all of the nodes in the subtree
     /// instead of applying solely to the `target` node. The default value is `false`.
@@ -210,9 +293,25 @@ pub struct UseMutationObserverOptions {
     character_data_old_value: bool,
 }
 
+impl Default for UseMutationObserverOptions {
+    fn default() -> Self {
+        Self {
+            immediate: true,
+            subtree: false,
+            child_list: false,
+            attributes: false,
+            attribute_filter: None,
+            attribute_old_value: false,
+            character_data: None,
+            character_data_old_value: false,
+        }
+    }
+}
+
 impl From<UseMutationObserverOptions> for web_sys::MutationObserverInit {
     fn from(val: UseMutationObserverOptions) -> Self {
         let UseMutationObserverOptions {
+            immediate: _,
             subtree,
             child_list,
             attributes,
@@ -243,9 +342,20 @@ impl From<UseMutationObserverOptions> for web_sys::MutationObserverInit {
 }
 
 /// The return value of [`use_mutation_observer`].
-pub struct UseMutationObserverReturn<F: Fn() + Clone + Send + Sync> {
+pub struct UseMutationObserverReturn<StopFn, PauseFn, ResumeFn>
+where
+    StopFn: Fn() + Clone + Send + Sync,
+    PauseFn: Fn() + Clone + Send + Sync,
+    ResumeFn: Fn() + Clone + Send + Sync,
+{
     /// Whether the browser supports the MutationObserver API
     pub is_supported: Signal<bool>,
+    /// A signal which is `true` when the MutationObserver is active, and `false` when paused or stopped.
+    pub is_active: Signal<bool>,
+    /// Disconnects the MutationObserver until `resume` is called. Will cause `is_active = false`.
+    pub pause: PauseFn,
+    /// Reconnects the MutationObserver. Will cause `is_active = true`.
+    pub resume: ResumeFn,
     /// A function to stop and detach the MutationObserver
-    pub stop: F,
+    pub stop: StopFn,
 }
@@ -0,0 +1,114 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use leptos::prelude::*;
+
+/// Reactive URL representing an object.
+///
+/// Creates an URL for the provided `Blob`, `File` or `MediaSource` with
+/// [`URL.createObjectURL()`](https://developer.mozilla.org/en-US/docs/Web/API/URL/createObjectURL_static).
+/// Whenever the source changes the previous URL is released with
+/// [`URL.revokeObjectURL()`](https://developer.mozilla.org/en-US/docs/Web/API/URL/revokeObjectURL_static).
+/// The URL is also released when the component is cleaned up. This way the browser can free the
+/// memory of the objects that aren't used anymore.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_drop_zone_with_options, use_object_url, UseDropZoneOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let drop_zone = NodeRef::<Div>::new();
+///
+/// let (image, set_image) = signal_local(None::<web_sys::File>);
+///
+/// use_drop_zone_with_options(
+///     drop_zone,
+///     UseDropZoneOptions::default().on_drop(move |event| {
+///         set_image.set(event.files.into_iter().next());
+///     }),
+/// );
+///
+/// let url = use_object_url::<Option<web_sys::File>, _>(image);
+///
+/// view! {
+///     <div node_ref=drop_zone>"Drop an image here"</div>
+///     <Show when=move || url.get().is_some()>
+///         <img src=move || url.get() />
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// Since `Blob`s and `File`s can't be sent between threads, the source is a local signal. The
+/// type of the source value can't be inferred from a signal, so it has to be specified.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this returns a `Signal` that always contains `None`.
+pub fn use_object_url<T, S>(source: S) -> Signal<Option<String>>
+where
+    T: ObjectUrlSource + 'static,
+    S: Into<Signal<T, LocalStorage>>,
+{
+    let source = source.into();
+
+    let (url, set_url) = signal(None::<String>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let revoke = move || {
+            if let Some(url) = url.try_get_untracked().flatten() {
+                let _ = web_sys::Url::revoke_object_url(&url);
+            }
+        };
+
+        Effect::watch(
+            move || source.track(),
+            move |_, _, _| {
+                revoke();
+                set_url.set(source.with_untracked(|source| source.create_object_url()));
+            },
+            true,
+        );
+
+        on_cleanup(revoke);
+    }
+
+    url.into()
+}
+
+/// Objects that [`use_object_url`] can create an URL for.
+pub trait ObjectUrlSource {
+    /// Creates the object URL. `None` if there is no object or the URL couldn't be created.
+    fn create_object_url(&self) -> Option<String>;
+}
+
+impl ObjectUrlSource for web_sys::Blob {
+    fn create_object_url(&self) -> Option<String> {
+        web_sys::Url::create_object_url_with_blob(self).ok()
+    }
+}
+
+impl ObjectUrlSource for web_sys::File {
+    fn create_object_url(&self) -> Option<String> {
+        web_sys::Url::create_object_url_with_blob(self).ok()
+    }
+}
+
+impl ObjectUrlSource for web_sys::MediaSource {
+    fn create_object_url(&self) -> Option<String> {
+        web_sys::Url::create_object_url_with_source(self).ok()
+    }
+}
+
+impl<T> ObjectUrlSource for Option<T>
+where
+    T: ObjectUrlSource,
+{
+    fn create_object_url(&self) -> Option<String> {
+        self.as_ref().and_then(ObjectUrlSource::create_object_url)
+    }
+}
@@ -0,0 +1,204 @@
+use crate::core::MaybeRwSignal;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Reactive offset pagination.
+///
+/// Takes care of calculating the page count and clamping the current page.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_offset_pagination_with_options, UseOffsetPaginationOptions, UseOffsetPaginationReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (total, set_total) = signal(0);
+///
+/// let UseOffsetPaginationReturn {
+///     current_page,
+///     page_count,
+///     is_first_page,
+///     is_last_page,
+///     prev,
+///     next,
+///     ..
+/// } = use_offset_pagination_with_options(
+///     UseOffsetPaginationOptions::default()
+///         .total(total)
+///         .page_size(20)
+///         .on_page_change(|page| {
+///             // fetch the data of the new page
+///         }),
+/// );
+///
+/// view! {
+///     <button disabled=is_first_page on:click=move |_| prev()>"Prev"</button>
+///     <span>{current_page} " / " {page_count}</span>
+///     <button disabled=is_last_page on:click=move |_| next()>"Next"</button>
+/// }
+/// # }
+/// ```
+///
+/// `total`, `page` and `page_size` can be signals so that the derived values update for example
+/// when the data is loaded.
+///
+/// ## Server-Side Rendering
+///
+/// This works the same on the server. The callbacks are not called on the server.
+pub fn use_offset_pagination() -> UseOffsetPaginationReturn<
+    impl Fn(usize) + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+> {
+    use_offset_pagination_with_options(UseOffsetPaginationOptions::default())
+}
+
+/// Version of [`use_offset_pagination`] that takes a `UseOffsetPaginationOptions`. See [`use_offset_pagination`] for how to use.
+pub fn use_offset_pagination_with_options(
+    options: UseOffsetPaginationOptions,
+) -> UseOffsetPaginationReturn<
+    impl Fn(usize) + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+> {
+    let UseOffsetPaginationOptions {
+        total,
+        page,
+        page_size,
+        on_page_change,
+        on_page_size_change,
+        on_page_count_change,
+    } = options;
+
+    let (page, set_page) = page.into_signal();
+    let (page_size, set_page_size) = page_size.into_signal();
+
+    let current_page_size = Signal::derive(move || page_size.get().max(1));
+
+    let page_count = Signal::derive(move || total.get().div_ceil(current_page_size.get()).max(1));
+
+    let current_page = Signal::derive(move || page.get().clamp(1, page_count.get()));
+
+    let is_first_page = Signal::derive(move || current_page.get() == 1);
+    let is_last_page = Signal::derive(move || current_page.get() == page_count.get());
+
+    let set_current_page = move |page: usize| {
+        set_page.set(page.clamp(1, page_count.get_untracked()));
+    };
+
+    let prev = move || {
+        if !is_first_page.get_untracked() {
+            set_page.set(current_page.get_untracked() - 1);
+        }
+    };
+
+    let next = move || {
+        if !is_last_page.get_untracked() {
+            set_page.set(current_page.get_untracked() + 1);
+        }
+    };
+
+    Effect::watch(
+        move || current_page.get(),
+        move |page, _, _| on_page_change(*page),
+        false,
+    );
+    Effect::watch(
+        move || current_page_size.get(),
+        move |page_size, _, _| on_page_size_change(*page_size),
+        false,
+    );
+    Effect::watch(
+        move || page_count.get(),
+        move |page_count, _, _| on_page_count_change(*page_count),
+        false,
+    );
+
+    UseOffsetPaginationReturn {
+        current_page,
+        set_current_page,
+        current_page_size,
+        set_current_page_size: set_page_size,
+        page_count,
+        is_first_page,
+        is_last_page,
+        prev,
+        next,
+    }
+}
+
+/// Options for [`use_offset_pagination_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseOffsetPaginationOptions {
+    /// Total number of items. Defaults to `usize::MAX`, i.e. an unknown number of items.
+    #[builder(into)]
+    total: Signal<usize>,
+
+    /// The current page. Pages start at `1`. Defaults to `1`.
+    #[builder(into)]
+    page: MaybeRwSignal<usize>,
+
+    /// Number of items per page. Defaults to `10`.
+    #[builder(into)]
+    page_size: MaybeRwSignal<usize>,
+
+    /// Called with the new page when the current page changes.
+    on_page_change: Arc<dyn Fn(usize) + Send + Sync>,
+
+    /// Called with the new page size when the page size changes.
+    on_page_size_change: Arc<dyn Fn(usize) + Send + Sync>,
+
+    /// Called with the new page count when the page count changes.
+    on_page_count_change: Arc<dyn Fn(usize) + Send + Sync>,
+}
+
+impl Default for UseOffsetPaginationOptions {
+    fn default() -> Self {
+        Self {
+            total: Signal::stored(usize::MAX),
+            page: 1.into(),
+            page_size: 10.into(),
+            on_page_change: Arc::new(|_| {}),
+            on_page_size_change: Arc::new(|_| {}),
+            on_page_count_change: Arc::new(|_| {}),
+        }
+    }
+}
+
+/// Return type of [`use_offset_pagination`].
+pub struct UseOffsetPaginationReturn<SetPageFn, PrevFn, NextFn>
+where
+    SetPageFn: Fn(usize) + Clone + Send + Sync,
+    PrevFn: Fn() + Clone + Send + Sync,
+    NextFn: Fn() + Clone + Send + Sync,
+{
+    /// The current page. Always between `1` and `page_count`.
+    pub current_page: Signal<usize>,
+
+    /// Sets the current page. The value is clamped to the valid page range.
+    pub set_current_page: SetPageFn,
+
+    /// The number of items per page.
+    pub current_page_size: Signal<usize>,
+
+    /// Sets the number of items per page.
+    pub set_current_page_size: WriteSignal<usize>,
+
+    /// The total number of pages. At least `1`.
+    pub page_count: Signal<usize>,
+
+    /// Whether the current page is the first one.
+    pub is_first_page: Signal<bool>,
+
+    /// Whether the current page is the last one.
+    pub is_last_page: Signal<bool>,
+
+    /// Goes to the previous page if the current page is not the first one.
+    pub prev: PrevFn,
+
+    /// Goes to the next page if the current page is not the last one.
+    pub next: NextFn,
+}
@@ -1,3 +1,5 @@
+#![cfg_attr(feature = "ssr", allow(unused_imports))]
+
 use leptos::prelude::*;
 use leptos::reactive::wrappers::read::Signal;
 use std::fmt::Display;
@@ -22,55 +24,140 @@ use std::fmt::Display;
 /// # }
 /// ```
 ///
+/// Calling `use_permission` with the same permission name in many components is cheap. All calls
+/// share the same underlying `PermissionStatus` and change listener which are removed once the
+/// last of these components is disposed.
+///
 /// ## Server-Side Rendering
 ///
 /// On the server the returned signal will always be `PermissionState::Unknown`.
 pub fn use_permission(permission_name: &str) -> Signal<PermissionState> {
-    let (state, set_state) = signal(PermissionState::Unknown);
-
     #[cfg(not(feature = "ssr"))]
     {
-        use crate::use_event_listener;
-        use std::cell::RefCell;
-        use std::rc::Rc;
+        let state = cache::subscribe(permission_name);
 
-        let permission_status = Rc::new(RefCell::new(None::<web_sys::PermissionStatus>));
+        on_cleanup({
+            let permission_name = permission_name.to_owned();
+            move || cache::unsubscribe(&permission_name)
+        });
 
-        let on_change = {
-            let permission_status = Rc::clone(&permission_status);
+        state.read_only().into()
+    }
 
-            move || {
-                if let Some(permission_status) = permission_status.borrow().as_ref() {
-                    set_state.set(PermissionState::from(permission_status.state()));
-                }
+    #[cfg(feature = "ssr")]
+    {
+        let _ = permission_name;
+
+        Signal::stored(PermissionState::Unknown)
+    }
+}
+
+/// Shares one `PermissionStatus` and its change listener between all calls of [`use_permission`]
+/// with the same permission name.
+#[cfg(not(feature = "ssr"))]
+mod cache {
+    use super::{query_permission, PermissionState};
+    use leptos::prelude::*;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::rc::{Rc, Weak};
+    use wasm_bindgen::prelude::*;
+    use wasm_bindgen::JsCast;
+
+    type StatusListener = Option<(web_sys::PermissionStatus, Closure<dyn Fn()>)>;
+
+    struct CachedPermission {
+        state: ArcRwSignal<PermissionState>,
+        subscribers: usize,
+        /// Filled once the query has resolved
+        status: Rc<RefCell<StatusListener>>,
+    }
+
+    thread_local! {
+        static CACHE: RefCell<HashMap<String, CachedPermission>> = RefCell::new(HashMap::new());
+    }
+
+    pub(super) fn subscribe(permission_name: &str) -> ArcRwSignal<PermissionState> {
+        CACHE.with_borrow_mut(|cache| {
+            if let Some(cached) = cache.get_mut(permission_name) {
+                cached.subscribers += 1;
+                return cached.state.clone();
             }
-        };
 
-        leptos::task::spawn_local({
-            let permission_name = permission_name.to_owned();
+            let state = ArcRwSignal::new(PermissionState::Unknown);
+            let status = Rc::new(RefCell::new(None));
 
-            async move {
-                if let Ok(status) = query_permission(permission_name).await {
-                    let _ = use_event_listener(status.clone(), leptos::ev::change, {
-                        let on_change = on_change.clone();
-                        move |_| on_change()
-                    });
-                    permission_status.replace(Some(status));
-                    on_change();
-                } else {
-                    set_state.set(PermissionState::Prompt);
+            leptos::task::spawn_local({
+                let permission_name = permission_name.to_owned();
+                let state = state.clone();
+                let status_ref = Rc::downgrade(&status);
+
+                async move {
+                    match query_permission(permission_name).await {
+                        Ok(status) => listen(status, state, status_ref),
+                        Err(_) => state.set(PermissionState::Prompt),
+                    }
                 }
+            });
+
+            cache.insert(
+                permission_name.to_owned(),
+                CachedPermission {
+                    state: state.clone(),
+                    subscribers: 1,
+                    status,
+                },
+            );
+
+            state
+        })
+    }
+
+    pub(super) fn unsubscribe(permission_name: &str) {
+        let removed = CACHE.with_borrow_mut(|cache| {
+            let cached = cache.get_mut(permission_name)?;
+            cached.subscribers -= 1;
+
+            if cached.subscribers == 0 {
+                cache.remove(permission_name)
+            } else {
+                None
             }
         });
-    }
 
-    #[cfg(feature = "ssr")]
-    {
-        let _ = set_state;
-        let _ = permission_name;
+        if let Some(removed) = removed {
+            if let Some((status, listener)) = removed.status.take() {
+                let _ = status.remove_event_listener_with_callback(
+                    "change",
+                    listener.as_ref().unchecked_ref(),
+                );
+            }
+        }
     }
 
-    state.into()
+    fn listen(
+        status: web_sys::PermissionStatus,
+        state: ArcRwSignal<PermissionState>,
+        status_ref: Weak<RefCell<StatusListener>>,
+    ) {
+        // Every subscriber is gone already
+        let Some(status_ref) = status_ref.upgrade() else {
+            return;
+        };
+
+        let listener = Closure::<dyn Fn()>::new({
+            let status = status.clone();
+            let state = state.clone();
+
+            move || state.set(PermissionState::from(status.state()))
+        });
+
+        let _ =
+            status.add_event_listener_with_callback("change", listener.as_ref().unchecked_ref());
+        state.set(PermissionState::from(status.state()));
+
+        status_ref.replace(Some((status, listener)));
+    }
 }
 
 /// Return type of [`use_permission`].
@@ -113,7 +200,7 @@ impl From<web_sys::PermissionState> for PermissionState {
 }
 
 #[cfg(not(feature = "ssr"))]
-async fn query_permission(
+pub(crate) async fn query_permission(
     permission: String,
 ) -> Result<web_sys::PermissionStatus, wasm_bindgen::JsValue> {
     use crate::{js, js_fut};
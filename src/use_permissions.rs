@@ -0,0 +1,53 @@
+use crate::{use_permission, PermissionState};
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::collections::HashMap;
+
+/// Reactive [Permissions API](https://developer.mozilla.org/en-US/docs/Web/API/Permissions_API) for
+/// several permissions at once.
+///
+/// Instead of calling [`fn@crate::use_permission`] for every permission this returns one reactive
+/// map from the permission name to its state. It uses [`fn@crate::use_permission`] internally so the
+/// `PermissionStatus` objects are shared with all other calls and released when the component is
+/// cleaned up.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_permission)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_permissions, PermissionState};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let permissions = use_permissions(vec!["camera", "microphone"]);
+///
+/// let can_record = move || {
+///     permissions.with(|permissions| {
+///         permissions.values().all(|state| *state == PermissionState::Granted)
+///     })
+/// };
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server every permission in the returned map will always be `PermissionState::Unknown`.
+pub fn use_permissions(permission_names: Vec<&str>) -> Signal<HashMap<String, PermissionState>> {
+    let states = permission_names
+        .into_iter()
+        .map(|name| (name.to_string(), use_permission(name)))
+        .collect::<Vec<_>>();
+
+    Signal::derive(move || {
+        states
+            .iter()
+            .map(|(name, state)| (name.clone(), state.get()))
+            .collect()
+    })
+}
@@ -0,0 +1,162 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::IntoElementMaybeSignal;
+use crate::{js, sendwrap_fn, use_supported_immediately};
+use leptos::prelude::*;
+
+/// Reactive [Pointer Lock API](https://developer.mozilla.org/en-US/docs/Web/API/Pointer_Lock_API).
+///
+/// While the pointer is locked the cursor is hidden and the mouse movement is reported as relative
+/// deltas in `movement_x` and `movement_y`. This is what first-person games or 3D editors need.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Canvas;
+/// # use leptos_use::{use_pointer_lock, UsePointerLockReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let canvas = NodeRef::<Canvas>::new();
+///
+/// let UsePointerLockReturn {
+///     is_locked,
+///     movement_x,
+///     movement_y,
+///     lock,
+///     unlock,
+///     ..
+/// } = use_pointer_lock(canvas);
+///
+/// Effect::new(move || {
+///     if is_locked.get() {
+///         // rotate the camera by `movement_x.get()` and `movement_y.get()`
+///     }
+/// });
+///
+/// view! { <canvas node_ref=canvas on:click=move |_| lock()></canvas> }
+/// # }
+/// ```
+///
+/// Browsers only allow locking the pointer in response to a user gesture like a click.
+/// The user can always unlock it by pressing <kbd>Esc</kbd>.
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `lock` and `unlock` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_pointer_lock`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the pointer is never locked, `is_supported` is `false` and the movement is always `0.0`.
+/// Calling `lock` or `unlock` does nothing.
+pub fn use_pointer_lock<El, M>(
+    target: El,
+) -> UsePointerLockReturn<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync>
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let target = target.into_element_maybe_signal();
+
+    let is_supported = use_supported_immediately(|| js!("pointerLockElement" in &document()));
+
+    let (element, set_element) = signal_local(None::<web_sys::Element>);
+    let (movement_x, set_movement_x) = signal(0.0);
+    let (movement_y, set_movement_y) = signal(0.0);
+    let (error, set_error) = signal(false);
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        use crate::{use_document, use_event_listener};
+        use leptos::ev::{mousemove, Custom};
+
+        let _ = use_event_listener(
+            use_document(),
+            Custom::<web_sys::Event>::new("pointerlockchange"),
+            move |_| {
+                let locked_element = document().pointer_lock_element();
+
+                if locked_element.is_none() {
+                    set_movement_x.set(0.0);
+                    set_movement_y.set(0.0);
+                }
+
+                set_element.set(locked_element);
+            },
+        );
+
+        let _ = use_event_listener(
+            use_document(),
+            Custom::<web_sys::Event>::new("pointerlockerror"),
+            move |_| set_error.set(true),
+        );
+
+        let _ = use_event_listener(use_document(), mousemove, move |event| {
+            if element.with_untracked(Option::is_some) {
+                set_movement_x.set(event.movement_x() as f64);
+                set_movement_y.set(event.movement_y() as f64);
+            }
+        });
+    }
+
+    let lock = sendwrap_fn!(move || {
+        #[cfg(not(feature = "ssr"))]
+        if is_supported.get_untracked() {
+            if let Some(target) = target.get_untracked() {
+                set_error.set(false);
+                target.request_pointer_lock();
+            }
+        }
+    });
+
+    let unlock = sendwrap_fn!(move || {
+        #[cfg(not(feature = "ssr"))]
+        if is_supported.get_untracked() && element.with_untracked(Option::is_some) {
+            document().exit_pointer_lock();
+        }
+    });
+
+    UsePointerLockReturn {
+        is_supported,
+        is_locked: Signal::derive(move || element.with(Option::is_some)),
+        element: element.into(),
+        movement_x: movement_x.into(),
+        movement_y: movement_y.into(),
+        error: error.into(),
+        lock,
+        unlock,
+    }
+}
+
+/// Return type of [`use_pointer_lock`].
+#[derive(Clone)]
+pub struct UsePointerLockReturn<LockFn, UnlockFn>
+where
+    LockFn: Fn() + Clone + Send + Sync,
+    UnlockFn: Fn() + Clone + Send + Sync,
+{
+    /// Whether the Pointer Lock API is supported by the browser.
+    pub is_supported: Signal<bool>,
+
+    /// Whether the pointer is currently locked.
+    pub is_locked: Signal<bool>,
+
+    /// The element the pointer is currently locked to.
+    pub element: Signal<Option<web_sys::Element>, LocalStorage>,
+
+    /// Horizontal movement in pixels since the last `mousemove` event while the pointer is locked.
+    pub movement_x: Signal<f64>,
+
+    /// Vertical movement in pixels since the last `mousemove` event while the pointer is locked.
+    pub movement_y: Signal<f64>,
+
+    /// `true` if the latest call to `lock` failed.
+    pub error: Signal<bool>,
+
+    /// Locks the pointer to the target element.
+    pub lock: LockFn,
+
+    /// Releases the pointer lock.
+    pub unlock: UnlockFn,
+}
@@ -5,6 +5,9 @@ use std::fmt::Display;
 
 /// Reactive [prefers-contrast](https://developer.mozilla.org/en-US/docs/Web/CSS/@media/prefers-contrast) media query.
 ///
+/// The returned signal distinguishes between `More`, `Less`, `Custom` and `NoPreference` and is
+/// updated live whenever the user changes their system settings.
+///
 /// ## Usage
 ///
 /// ```
@@ -20,6 +23,8 @@ use std::fmt::Display;
 /// # }
 /// ```
 ///
+/// If you only want to know if the user prefers a high contrast, use [`use_prefers_high_contrast`].
+///
 /// ## Server-Side Rendering
 ///
 /// On the server this returns a `Signal` that always contains the value `PreferredContrast::NoPreference`.
@@ -47,6 +52,35 @@ pub fn use_preferred_contrast() -> Signal<PreferredContrast> {
     })
 }
 
+/// Reactive `(prefers-contrast: more)` media query.
+///
+/// This is a shortcut to switch to a high contrast palette.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_prefers_high_contrast;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let is_high_contrast = use_prefers_high_contrast();
+///
+/// view! { <div class:high-contrast=is_high_contrast>"..."</div> }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server this returns a `Signal` that always contains the value `false`.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_preferred_contrast`]
+pub fn use_prefers_high_contrast() -> Signal<bool> {
+    use_media_query("(prefers-contrast: more)")
+}
+
 /// Return value for [`use_preferred_contrast`]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PreferredContrast {
@@ -56,6 +90,14 @@ pub enum PreferredContrast {
     #[default]
     NoPreference,
 }
+
+impl PreferredContrast {
+    /// Returns `true` if the user prefers more contrast.
+    pub fn is_high_contrast(self) -> bool {
+        self == PreferredContrast::More
+    }
+}
+
 impl Display for PreferredContrast {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -0,0 +1,90 @@
+use crate::utils::get_header;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Reactive [reduced data preference](https://developer.mozilla.org/en-US/docs/Web/CSS/@media/prefers-reduced-data).
+///
+/// Use this to serve lighter assets when the user opted into saving data.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_prefers_reduced_data;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let is_reduced_data_preferred = use_prefers_reduced_data();
+///
+/// view! {
+///     <img src=move || {
+///         if is_reduced_data_preferred.get() { "hero-small.jpg" } else { "hero.jpg" }
+///     } />
+/// }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server this will try to read the
+/// [`Save-Data` header](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Save-Data)
+/// which is sent by browsers when the user enabled a data saving mode.
+///
+/// > If you're using `axum` you have to enable the `"axum"` feature in your Cargo.toml.
+/// > In case it's `actix-web` enable the feature `"actix"`, for `spin` enable `"spin"`.
+///
+/// ### Bring your own header
+///
+/// In case you're neither using Axum, Actix nor Spin, or the default implementation is not to your
+/// liking, you can provide your own way of reading the save data header value using the option
+/// [`crate::UsePrefersReducedDataOptions::ssr_save_data_header_getter`].
+///
+/// ## See also
+///
+/// * [`fn@crate::use_media_query`]
+/// * [`fn@crate::use_prefers_reduced_motion`]
+pub fn use_prefers_reduced_data() -> Signal<bool> {
+    use_prefers_reduced_data_with_options(UsePrefersReducedDataOptions::default())
+}
+
+/// Version of [`fn@crate::use_prefers_reduced_data`] that takes a `UsePrefersReducedDataOptions`. See [`fn@crate::use_prefers_reduced_data`] for how to use.
+pub fn use_prefers_reduced_data_with_options(
+    options: UsePrefersReducedDataOptions,
+) -> Signal<bool> {
+    #[cfg(not(feature = "ssr"))]
+    {
+        let _ = options;
+        crate::use_media_query("(prefers-reduced-data: reduce)")
+    }
+    #[cfg(feature = "ssr")]
+    {
+        Signal::derive(move || (options.ssr_save_data_header_getter)() == Some("on".to_string()))
+    }
+}
+
+/// Options for [`fn@crate::use_prefers_reduced_data_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UsePrefersReducedDataOptions {
+    /// Getter function to return the string value of the
+    /// [`Save-Data`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Save-Data)
+    /// header.
+    /// When you use one of the features `"axum"`, `"actix"` or `"spin"` there's a valid default
+    /// implementation provided.
+    #[allow(dead_code)]
+    pub(crate) ssr_save_data_header_getter: Arc<dyn Fn() -> Option<String> + Send + Sync>,
+}
+
+impl Default for UsePrefersReducedDataOptions {
+    fn default() -> Self {
+        Self {
+            ssr_save_data_header_getter: Arc::new(move || {
+                get_header!(
+                    HeaderName::from_static("save-data"),
+                    use_prefers_reduced_data,
+                    ssr_save_data_header_getter
+                )
+            }),
+        }
+    }
+}
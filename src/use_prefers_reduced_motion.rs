@@ -14,8 +14,6 @@ use std::sync::Arc;
 /// ```
 /// # use leptos::prelude::*;
 /// # use leptos_use::use_prefers_reduced_motion;
-/// # #[cfg(feature = "docs")]
-/// # use leptos_use::docs::BooleanDisplay;
 /// #
 /// # #[component]
 /// # fn Demo() -> impl IntoView {
@@ -23,7 +21,7 @@ use std::sync::Arc;
 ///
 /// view! {
 ///     <div>
-///         <p>Prefers reduced motions: <BooleanDisplay value=is_reduced_motion_preferred/></p>
+///         <p>Prefers reduced motions: {move || is_reduced_motion_preferred.get()}</p>
 ///         <p>
 ///             Update reduce motion preference
 ///             <a href="https://developer.mozilla.org/en-US/docs/Web/CSS/@media/prefers-reduced-motion#user_preferences">
@@ -57,6 +55,7 @@ use std::sync::Arc;
 /// * [`fn@crate::use_media_query`]
 /// * [`fn@crate::use_preferred_contrast`]
 /// * [`fn@crate::use_preferred_dark`]
+/// * [`fn@crate::use_prefers_reduced_data`]
 pub fn use_prefers_reduced_motion() -> Signal<bool> {
     use_prefers_reduced_motion_with_options(UsePrefersReducedMotionOptions::default())
 }
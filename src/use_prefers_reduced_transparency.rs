@@ -0,0 +1,93 @@
+use crate::utils::get_header;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Reactive [reduced transparency preference](https://developer.mozilla.org/en-US/docs/Web/CSS/@media/prefers-reduced-transparency).
+///
+/// Use this to replace translucent or blurred backgrounds with opaque ones.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_prefers_reduced_transparency;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let is_reduced_transparency_preferred = use_prefers_reduced_transparency();
+///
+/// view! {
+///     <div class:backdrop-blur=move || !is_reduced_transparency_preferred.get()>"..."</div>
+/// }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server this will try to read the
+/// [`Sec-CH-Prefers-Reduced-Transparency` header](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Sec-CH-Prefers-Reduced-Transparency)
+/// to indicate the preference for reduced transparency.
+/// Please have a look at the linked documentation above to see browser support
+/// as well as potential serve requirements.
+///
+/// > If you're using `axum` you have to enable the `"axum"` feature in your Cargo.toml.
+/// > In case it's `actix-web` enable the feature `"actix"`, for `spin` enable `"spin"`.
+///
+/// ### Bring your own header
+///
+/// In case you're neither using Axum, Actix nor Spin, or the default implementation is not to your
+/// liking, you can provide your own way of reading the reduced transparency header value using the option
+/// [`crate::UsePrefersReducedTransparencyOptions::ssr_transparency_header_getter`].
+///
+/// ## See also
+///
+/// * [`fn@crate::use_media_query`]
+/// * [`fn@crate::use_forced_colors`]
+/// * [`fn@crate::use_prefers_reduced_motion`]
+pub fn use_prefers_reduced_transparency() -> Signal<bool> {
+    use_prefers_reduced_transparency_with_options(UsePrefersReducedTransparencyOptions::default())
+}
+
+/// Version of [`fn@crate::use_prefers_reduced_transparency`] that takes a `UsePrefersReducedTransparencyOptions`. See [`fn@crate::use_prefers_reduced_transparency`] for how to use.
+pub fn use_prefers_reduced_transparency_with_options(
+    options: UsePrefersReducedTransparencyOptions,
+) -> Signal<bool> {
+    #[cfg(not(feature = "ssr"))]
+    {
+        let _ = options;
+        crate::use_media_query("(prefers-reduced-transparency: reduce)")
+    }
+    #[cfg(feature = "ssr")]
+    {
+        Signal::derive(move || {
+            (options.ssr_transparency_header_getter)() == Some("reduce".to_string())
+        })
+    }
+}
+
+/// Options for [`fn@crate::use_prefers_reduced_transparency_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UsePrefersReducedTransparencyOptions {
+    /// Getter function to return the string value of the
+    /// [`Sec-CH-Prefers-Reduced-Transparency`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Sec-CH-Prefers-Reduced-Transparency)
+    /// header.
+    /// When you use one of the features `"axum"`, `"actix"` or `"spin"` there's a valid default
+    /// implementation provided.
+    #[allow(dead_code)]
+    pub(crate) ssr_transparency_header_getter: Arc<dyn Fn() -> Option<String> + Send + Sync>,
+}
+
+impl Default for UsePrefersReducedTransparencyOptions {
+    fn default() -> Self {
+        Self {
+            ssr_transparency_header_getter: Arc::new(move || {
+                get_header!(
+                    HeaderName::from_static("sec-ch-prefers-reduced-transparency"),
+                    use_prefers_reduced_transparency,
+                    ssr_transparency_header_getter
+                )
+            }),
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::sendwrap_fn;
-use crate::utils::Pausable;
+use crate::utils::{Pausable, UseVisibilityPause};
 use cfg_if::cfg_if;
 use default_struct_builder::DefaultBuilder;
 use leptos::prelude::*;
@@ -35,14 +35,40 @@ use std::rc::Rc;
 /// You can use `use_raf_fn_with_options` and set `immediate` to `false`. In that case
 /// you have to call `resume()` before the `callback` is executed.
 ///
+/// ### Pause when hidden
+///
+/// Browsers throttle animation frames of background tabs. To stop the loop completely while the
+/// tab is hidden and avoid a jump in `delta` when it comes back, set `pause_when_hidden` to `true`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_raf_fn_with_options, UseRafFnOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (position, set_position) = signal(0.0);
+///
+/// use_raf_fn_with_options(
+///     move |args| set_position.update(|position| *position += args.delta * 0.1),
+///     UseRafFnOptions::default().pause_when_hidden(true),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// See [`UseVisibilityPause`] for more control, e.g. to report the time spent hidden as `delta`
+/// of the first frame after resuming.
+///
 /// ## SendWrapped Return
 ///
 /// The returned closures `pause` and `resume` are sendwrapped functions. They can
-/// only be called from the same thread that called `use_interval_fn`.
+/// only be called from the same thread that called `use_raf_fn`.
 ///
 /// ## Server-Side Rendering
 ///
-/// On the server this does basically nothing. The provided closure will never be called.
+/// On the server the provided closure will never be called. The returned `pause` and `resume`
+/// are no-ops and `is_active` is always `false`. The option `pause_when_hidden` has no effect.
 pub fn use_raf_fn(
     callback: impl Fn(UseRafFnCallbackArgs) + 'static,
 ) -> Pausable<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
@@ -54,7 +80,10 @@ pub fn use_raf_fn_with_options(
     callback: impl Fn(UseRafFnCallbackArgs) + 'static,
     options: UseRafFnOptions,
 ) -> Pausable<impl Fn() + Clone + Send + Sync, impl Fn() + Clone + Send + Sync> {
-    let UseRafFnOptions { immediate } = options;
+    let UseRafFnOptions {
+        immediate,
+        visibility_pause,
+    } = options;
 
     let raf_handle = Rc::new(Cell::new(None::<i32>));
 
@@ -62,6 +91,11 @@ pub fn use_raf_fn_with_options(
 
     let loop_ref = Rc::new(RefCell::new(Box::new(|_: f64| {}) as Box<dyn Fn(f64)>));
 
+    let previous_frame_timestamp = Rc::new(Cell::new(0.0_f64));
+
+    // Added to the delta of the next frame to catch up on the time the document was hidden
+    let catch_up_delta = Rc::new(Cell::new(0.0_f64));
+
     let request_next_frame = {
         cfg_if! { if #[cfg(feature = "ssr")] {
             move || ()
@@ -93,7 +127,8 @@ pub fn use_raf_fn_with_options(
     let loop_fn = {
         #[allow(clippy::clone_on_copy)]
         let request_next_frame = request_next_frame.clone();
-        let previous_frame_timestamp = Cell::new(0.0_f64);
+        let previous_frame_timestamp = Rc::clone(&previous_frame_timestamp);
+        let catch_up_delta = Rc::clone(&catch_up_delta);
 
         move |timestamp: f64| {
             if !is_active.try_get_untracked().unwrap_or_default() {
@@ -105,7 +140,7 @@ pub fn use_raf_fn_with_options(
                 timestamp - prev_timestamp
             } else {
                 0.0
-            };
+            } + catch_up_delta.take();
 
             #[cfg(debug_assertions)]
             let zone = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
@@ -124,7 +159,10 @@ pub fn use_raf_fn_with_options(
     let _ = loop_ref.replace(Box::new(loop_fn));
 
     let resume = sendwrap_fn!(move || {
+        #[cfg(not(feature = "ssr"))]
         if !is_active.get_untracked() {
+            // The first frame after resuming reports a delta of 0
+            previous_frame_timestamp.set(0.0);
             set_active.set(true);
             request_next_frame();
         }
@@ -144,6 +182,14 @@ pub fn use_raf_fn_with_options(
         resume();
     }
 
+    crate::utils::use_visibility_pause(
+        visibility_pause,
+        is_active.into(),
+        pause.clone(),
+        resume.clone(),
+        move |hidden_ms| catch_up_delta.set(hidden_ms),
+    );
+
     on_cleanup({
         let pause = pause.clone();
         #[allow(clippy::redundant_closure)]
@@ -163,11 +209,33 @@ pub struct UseRafFnOptions {
     /// Start the requestAnimationFrame loop immediately on creation. Defaults to `true`.
     /// If false, the loop will only start when you call `resume()`.
     immediate: bool,
+
+    /// How the loop behaves while the document is hidden. See [`UseVisibilityPause`].
+    /// Defaults to not pausing.
+    visibility_pause: UseVisibilityPause,
 }
 
 impl Default for UseRafFnOptions {
     fn default() -> Self {
-        Self { immediate: true }
+        Self {
+            immediate: true,
+            visibility_pause: UseVisibilityPause::default(),
+        }
+    }
+}
+
+impl UseRafFnOptions {
+    /// Pause the loop while the document is hidden (e.g. the tab is in the background) and resume
+    /// it when it becomes visible again. The first frame after resuming reports a `delta` of `0`.
+    /// Shortcut for `visibility_pause(UseVisibilityPause::default().pause_when_hidden(true))`.
+    pub fn pause_when_hidden(self, pause_when_hidden: bool) -> Self {
+        Self {
+            visibility_pause: UseVisibilityPause {
+                pause_when_hidden,
+                ..self.visibility_pause
+            },
+            ..self
+        }
     }
 }
 
@@ -4,7 +4,7 @@ use default_struct_builder::DefaultBuilder;
 use leptos::reactive::wrappers::read::Signal;
 
 cfg_if! { if #[cfg(not(feature = "ssr"))] {
-    use crate::{sendwrap_fn, use_supported};
+    use crate::{sendwrap_fn, use_supported_immediately};
     use std::cell::RefCell;
     use std::rc::Rc;
     use wasm_bindgen::prelude::*;
@@ -45,9 +45,49 @@ cfg_if! { if #[cfg(not(feature = "ssr"))] {
 /// # }
 /// ```
 ///
+/// ### Multiple targets
+///
+/// One observer can watch many elements. You can either pass multiple targets like a `Vec<NodeRef>`
+/// or add and remove elements with the returned `observe` and `unobserve` functions.
+/// Use [`web_sys::ResizeObserverEntry::target`] in the callback to find out to which element an
+/// entry belongs.
+///
+/// ```
+/// # use leptos::{html::Div, prelude::*};
+/// # use leptos_use::{use_resize_observer, UseResizeObserverReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (widths, set_widths) = signal(std::collections::HashMap::<String, f64>::new());
+///
+/// let UseResizeObserverReturn { observe, .. } = use_resize_observer(
+///     Vec::<web_sys::Element>::new(),
+///     move |entries, _| {
+///         set_widths.update(|widths| {
+///             for entry in entries {
+///                 widths.insert(entry.target().id(), entry.content_rect().width());
+///             }
+///         });
+///     },
+/// );
+///
+/// let panel = NodeRef::<Div>::new();
+///
+/// Effect::new(move || {
+///     if let Some(panel) = panel.get() {
+///         observe(&panel);
+///     }
+/// });
+///
+/// view! {
+///     <div id="panel-1" node_ref=panel>"Panel 1"</div>
+/// }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
-/// The returned closure `stop` is a sendwrapped function. It can
+/// The returned closures `stop`, `observe` and `unobserve` are sendwrapped functions. They can
 /// only be called from the same thread that called `use_resize_observer`.
 ///
 /// ## Server-Side Rendering
@@ -60,7 +100,11 @@ cfg_if! { if #[cfg(not(feature = "ssr"))] {
 pub fn use_resize_observer<Els, M, F>(
     target: Els,
     callback: F,
-) -> UseResizeObserverReturn<impl Fn() + Clone + Send + Sync>
+) -> UseResizeObserverReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn(&web_sys::Element) + Clone + Send + Sync,
+    impl Fn(&web_sys::Element) + Clone + Send + Sync,
+>
 where
     Els: IntoElementsMaybeSignal<web_sys::Element, M>,
     F: FnMut(Vec<web_sys::ResizeObserverEntry>, web_sys::ResizeObserver) + 'static,
@@ -74,7 +118,11 @@ pub fn use_resize_observer_with_options<Els, M, F>(
     target: Els,
     mut callback: F,
     options: UseResizeObserverOptions,
-) -> UseResizeObserverReturn<impl Fn() + Clone + Send + Sync>
+) -> UseResizeObserverReturn<
+    impl Fn() + Clone + Send + Sync,
+    impl Fn(&web_sys::Element) + Clone + Send + Sync,
+    impl Fn(&web_sys::Element) + Clone + Send + Sync,
+>
 where
     Els: IntoElementsMaybeSignal<web_sys::Element, M>,
     F: FnMut(Vec<web_sys::ResizeObserverEntry>, web_sys::ResizeObserver) + 'static,
@@ -84,6 +132,8 @@ where
         UseResizeObserverReturn {
             is_supported: Signal::derive(|| true),
             stop: || {},
+            observe: |_: &web_sys::Element| {},
+            unobserve: |_: &web_sys::Element| {},
         }
     }
 
@@ -110,7 +160,10 @@ where
 
         let observer: Rc<RefCell<Option<web_sys::ResizeObserver>>> = Rc::new(RefCell::new(None));
 
-        let is_supported = use_supported(|| js!("ResizeObserver" in &window()));
+        // Targets added with `observe`. They are kept when the observer is re-created.
+        let observed: Rc<RefCell<Vec<web_sys::Element>>> = Rc::new(RefCell::new(vec![]));
+
+        let is_supported = use_supported_immediately(|| js!("ResizeObserver" in &window()));
 
         let cleanup = {
             let observer = Rc::clone(&observer);
@@ -128,13 +181,17 @@ where
 
         let stop_watch = {
             let cleanup = cleanup.clone();
+            let observer = Rc::clone(&observer);
+            let observed = Rc::clone(&observed);
+            let options = options.clone();
 
             let stop = Effect::watch(
                 move || targets.get(),
                 move |targets, _, _| {
                     cleanup();
 
-                    if is_supported.get_untracked() && !targets.is_empty() {
+                    // Always create the observer so targets can be added later with `observe`.
+                    if is_supported.get_untracked() {
                         let obs = web_sys::ResizeObserver::new(
                             closure_js.clone().as_ref().unchecked_ref(),
                         )
@@ -144,6 +201,10 @@ where
                             let target = target.clone();
                             obs.observe_with_options(&target, &options.clone().into());
                         }
+
+                        for target in observed.borrow().iter() {
+                            obs.observe_with_options(target, &options.clone().into());
+                        }
                         observer.replace(Some(obs));
                     }
                 },
@@ -164,7 +225,36 @@ where
             move || stop()
         });
 
-        UseResizeObserverReturn { is_supported, stop }
+        let observe = {
+            let observer = Rc::clone(&observer);
+            let observed = Rc::clone(&observed);
+
+            sendwrap_fn!(move |target: &web_sys::Element| {
+                if observed.borrow().contains(target) {
+                    return;
+                }
+                observed.borrow_mut().push(target.clone());
+
+                if let Some(observer) = observer.borrow().as_ref() {
+                    observer.observe_with_options(target, &options.clone().into());
+                }
+            })
+        };
+
+        let unobserve = sendwrap_fn!(move |target: &web_sys::Element| {
+            observed.borrow_mut().retain(|el| el != target);
+
+            if let Some(observer) = observer.borrow().as_ref() {
+                observer.unobserve(target);
+            }
+        });
+
+        UseResizeObserverReturn {
+            is_supported,
+            stop,
+            observe,
+            unobserve,
+        }
     }
 }
 
@@ -188,9 +278,18 @@ impl From<UseResizeObserverOptions> for web_sys::ResizeObserverOptions {
 }
 
 /// The return value of [`use_resize_observer`].
-pub struct UseResizeObserverReturn<F: Fn() + Clone + Send + Sync> {
+pub struct UseResizeObserverReturn<StopFn, ObserveFn, UnobserveFn>
+where
+    StopFn: Fn() + Clone + Send + Sync,
+    ObserveFn: Fn(&web_sys::Element) + Clone + Send + Sync,
+    UnobserveFn: Fn(&web_sys::Element) + Clone + Send + Sync,
+{
     /// Whether the browser supports the ResizeObserver API
     pub is_supported: Signal<bool>,
     /// A function to stop and detach the ResizeObserver
-    pub stop: F,
+    pub stop: StopFn,
+    /// Adds an element to the observed targets of the same ResizeObserver
+    pub observe: ObserveFn,
+    /// Removes an element that has been added with `observe` from the observed targets
+    pub unobserve: UnobserveFn,
 }
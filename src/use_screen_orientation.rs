@@ -0,0 +1,193 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{js, sendwrap_fn, use_supported_immediately};
+use leptos::prelude::*;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{OrientationLockType, OrientationType};
+
+/// Reactive [Screen Orientation API](https://developer.mozilla.org/en-US/docs/Web/API/Screen_Orientation_API).
+///
+/// Provides the current orientation type and angle of the screen and lets you lock the orientation.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_screen_orientation, UseScreenOrientationReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseScreenOrientationReturn {
+///     is_supported,
+///     orientation,
+///     angle,
+///     ..
+/// } = use_screen_orientation();
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Locking
+///
+/// Games and video players often want to stay in landscape. Call `lock` to lock the orientation
+/// and `unlock` to release it again. Browsers usually only allow locking while the document is in
+/// fullscreen. If locking fails the reason is available in `error`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_screen_orientation, UseScreenOrientationReturn, UseScreenOrientationError};
+/// # use web_sys::OrientationLockType;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseScreenOrientationReturn {
+///     lock,
+///     unlock,
+///     error,
+///     ..
+/// } = use_screen_orientation();
+///
+/// view! {
+///     <button on:click=move |_| lock(OrientationLockType::Landscape)>"Lock landscape"</button>
+///     <button on:click=move |_| unlock()>"Unlock"</button>
+///     <Show when=move || matches!(error.get(), Some(UseScreenOrientationError::NotFullscreen))>
+///         "Please enter fullscreen first"
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `lock` and `unlock` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_screen_orientation`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is `false`, `orientation` is `None` and `angle` is `0`.
+/// Calling `lock` or `unlock` does nothing.
+pub fn use_screen_orientation() -> UseScreenOrientationReturn<
+    impl Fn(OrientationLockType) + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+> {
+    let is_supported = use_supported_immediately(|| {
+        js!("screen" in &window()) && js!("orientation" in &window().screen().unwrap())
+    });
+
+    let (orientation, set_orientation) = signal(None::<OrientationType>);
+    let (angle, set_angle) = signal(0_u16);
+    let (error, set_error) = signal_local(None::<UseScreenOrientationError>);
+
+    let screen_orientation = move || {
+        if is_supported.get_untracked() {
+            window().screen().ok().map(|screen| screen.orientation())
+        } else {
+            None
+        }
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::use_event_listener;
+
+        let update = move || {
+            if let Some(screen_orientation) = screen_orientation() {
+                set_orientation.set(screen_orientation.type_().ok());
+                set_angle.set(screen_orientation.angle().unwrap_or_default());
+            }
+        };
+
+        update();
+
+        if let Some(screen_orientation) = screen_orientation() {
+            let _ = use_event_listener(screen_orientation, leptos::ev::change, move |_| update());
+        }
+    }
+
+    let lock = sendwrap_fn!(move |lock_type: OrientationLockType| {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(screen_orientation) = screen_orientation() else {
+                set_error.set(Some(UseScreenOrientationError::NotSupported));
+                return;
+            };
+
+            match screen_orientation.lock(lock_type) {
+                Ok(promise) => {
+                    leptos::task::spawn_local(async move {
+                        match crate::js_fut!(promise).await {
+                            Ok(_) => set_error.set(None),
+                            Err(err) => set_error.set(Some(err.into())),
+                        }
+                    });
+                }
+                Err(err) => set_error.set(Some(err.into())),
+            }
+        }
+    });
+
+    let unlock = sendwrap_fn!(move || {
+        if let Some(screen_orientation) = screen_orientation() {
+            if let Err(err) = screen_orientation.unlock() {
+                set_error.set(Some(err.into()));
+            }
+        }
+    });
+
+    UseScreenOrientationReturn {
+        is_supported,
+        orientation: orientation.into(),
+        angle: angle.into(),
+        lock,
+        unlock,
+        error: error.into(),
+    }
+}
+
+/// Return type of [`use_screen_orientation`].
+#[derive(Clone)]
+pub struct UseScreenOrientationReturn<LockFn, UnlockFn>
+where
+    LockFn: Fn(OrientationLockType) + Clone + Send + Sync,
+    UnlockFn: Fn() + Clone + Send + Sync,
+{
+    /// Whether the Screen Orientation API is supported by the browser.
+    pub is_supported: Signal<bool>,
+
+    /// The current orientation type of the screen. `None` if not supported.
+    pub orientation: Signal<Option<OrientationType>>,
+
+    /// The current orientation angle of the screen in degrees.
+    pub angle: Signal<u16>,
+
+    /// Locks the orientation of the screen to the given type.
+    pub lock: LockFn,
+
+    /// Releases a previously set orientation lock.
+    pub unlock: UnlockFn,
+
+    /// The error of the latest call to `lock` or `unlock`. Reset to `None` when locking succeeds.
+    pub error: Signal<Option<UseScreenOrientationError>, LocalStorage>,
+}
+
+#[derive(Error, Debug, Clone)]
+pub enum UseScreenOrientationError {
+    #[error("locking the screen orientation is not supported")]
+    NotSupported,
+    #[error("locking the screen orientation requires fullscreen")]
+    NotFullscreen,
+    #[error("screen orientation error: {0:?}")]
+    Js(JsValue),
+}
+
+impl From<JsValue> for UseScreenOrientationError {
+    fn from(value: JsValue) -> Self {
+        match value.dyn_ref::<web_sys::DomException>().map(|e| e.name()) {
+            Some(name) if name == "NotSupportedError" => Self::NotSupported,
+            Some(name) if name == "SecurityError" => Self::NotFullscreen,
+            _ => Self::Js(value),
+        }
+    }
+}
@@ -7,12 +7,15 @@ use leptos::reactive::wrappers::read::Signal;
 use std::rc::Rc;
 
 cfg_if! { if #[cfg(not(feature = "ssr"))] {
+use crate::core::now;
 use crate::use_event_listener::use_event_listener_with_options;
 use crate::{
-    sendwrap_fn, use_debounce_fn_with_arg, use_throttle_fn_with_arg_and_options, ThrottleOptions,
+    sendwrap_fn, use_debounce_fn_with_arg, use_mutation_observer_with_options, use_resize_observer,
+    use_throttle_fn_with_arg_and_options, ThrottleOptions, UseMutationObserverOptions,
 };
 use leptos::ev;
 use leptos::ev::scrollend;
+use std::cell::Cell;
 use wasm_bindgen::JsCast;
 
 /// We have to check if the scroll amount is close enough to some threshold in order to
@@ -50,6 +53,29 @@ const ARRIVED_STATE_THRESHOLD_PIXELS: f64 = 1.0;
 /// # }
 /// ```
 ///
+/// ### Velocity
+///
+/// `velocity_x` and `velocity_y` contain the current scroll speed in pixels per second.
+/// They are positive when scrolling right / down and negative when scrolling left / up.
+/// Once scrolling stops they go back to `0.0`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_scroll, UseScrollReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let element = NodeRef::<Div>::new();
+///
+/// let UseScrollReturn { velocity_y, .. } = use_scroll(element);
+///
+/// let is_scrolling_fast = Signal::derive(move || velocity_y.get().abs() > 2000.0);
+/// #
+/// # view! { <div node_ref=element>"..."</div> }
+/// # }
+/// ```
+///
 /// ### With Offsets
 ///
 /// You can provide offsets when you use [`use_scroll_with_options`].
@@ -212,6 +238,9 @@ where
 
     let (is_scrolling, set_is_scrolling) = signal(false);
 
+    let (velocity_x, set_velocity_x) = signal(0.0);
+    let (velocity_y, set_velocity_y) = signal(0.0);
+
     let arrived_state = RwSignal::new(Directions {
         left: true,
         right: false,
@@ -272,6 +301,8 @@ where
                 }
 
                 set_is_scrolling.set(false);
+                set_velocity_x.set(0.0);
+                set_velocity_y.set(0.0);
                 directions.update(|directions| {
                     directions.left = false;
                     directions.right = false;
@@ -361,15 +392,27 @@ where
             }
         };
 
+        let last_scroll_time = Rc::new(Cell::new(0.0));
+
         let on_scroll_handler = {
             let on_scroll = Rc::clone(&options.on_scroll);
 
             move |e: web_sys::Event| {
                 let target: web_sys::Element = event_target(&e);
 
+                let prev_x = internal_x.get_untracked();
+                let prev_y = internal_y.get_untracked();
+
                 set_arrived_state(target);
                 set_is_scrolling.set(true);
 
+                let time = now();
+                let elapsed = time - last_scroll_time.replace(time);
+                if elapsed > 0.0 {
+                    set_velocity_x.set((internal_x.get_untracked() - prev_x) / elapsed * 1000.0);
+                    set_velocity_y.set((internal_y.get_untracked() - prev_y) / elapsed * 1000.0);
+                }
+
                 on_scroll_end_debounced.clone()(e.clone());
                 on_scroll.clone()(e);
             }
@@ -426,11 +469,24 @@ where
             options.event_listener_options,
         );
 
-        measure = sendwrap_fn!(move || {
+        let update_arrived_state = move || {
             if let Some(el) = signal.try_get_untracked().flatten() {
                 set_arrived_state(el);
             }
-        });
+        };
+
+        // The arrived state changes when the content or the element itself is resized without
+        // scrolling, e.g. when items are appended for infinite scrolling.
+        let _ = use_mutation_observer_with_options(
+            signal,
+            move |_, _| update_arrived_state(),
+            UseMutationObserverOptions::default()
+                .child_list(true)
+                .subtree(true),
+        );
+        let _ = use_resize_observer(signal, move |_, _| update_arrived_state());
+
+        measure = sendwrap_fn!(move || update_arrived_state());
     }
 
     UseScrollReturn {
@@ -441,6 +497,8 @@ where
         is_scrolling: is_scrolling.into(),
         arrived_state: arrived_state.into(),
         directions: directions.into(),
+        velocity_x: velocity_x.into(),
+        velocity_y: velocity_y.into(),
         measure,
     }
 }
@@ -530,12 +588,21 @@ where
     pub is_scrolling: Signal<bool>,
 
     /// Sets the field that represents a direction to true if the
-    /// element is scrolled all the way to that side.
+    /// element is scrolled all the way to that side. Also updated when the content or the
+    /// element is resized.
     pub arrived_state: Signal<Directions>,
 
     /// The directions in which the element is being scrolled are set to true.
     pub directions: Signal<Directions>,
 
+    /// Horizontal scroll velocity in pixels per second. Positive when scrolling to the right.
+    /// Goes back to `0.0` when scrolling stops.
+    pub velocity_x: Signal<f64>,
+
+    /// Vertical scroll velocity in pixels per second. Positive when scrolling down.
+    /// Goes back to `0.0` when scrolling stops.
+    pub velocity_y: Signal<f64>,
+
     /// Re-evaluates the `arrived_state`.
     pub measure: MFn,
 }
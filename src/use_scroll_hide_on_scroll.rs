@@ -0,0 +1,107 @@
+use crate::use_window_scroll;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+
+/// Reactive visibility of a sticky header that hides on scroll.
+///
+/// The returned signal is `true` when the header should be visible. This is the case when the page
+/// is scrolled up or the scroll position is within `threshold` pixels of the top.
+/// When the page is scrolled down past `threshold` the signal becomes `false`.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_scroll_hide_on_scroll;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let is_header_visible = use_scroll_hide_on_scroll(64.0);
+///
+/// view! {
+///     <header
+///         class="sticky top-0 transition-transform"
+///         class:-translate-y-full=move || !is_header_visible.get()
+///     >
+///         "..."
+///     </header>
+/// }
+/// # }
+/// ```
+///
+/// Small scroll movements are ignored so that the header doesn't flicker. You can configure this
+/// with the `tolerance` option.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_scroll_hide_on_scroll_with_options, UseScrollHideOnScrollOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let is_header_visible = use_scroll_hide_on_scroll_with_options(
+///     64.0,
+///     UseScrollHideOnScrollOptions::default().tolerance(20.0),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server the scroll position is always `0.0`, so this always returns `true`.
+pub fn use_scroll_hide_on_scroll(threshold: f64) -> Signal<bool> {
+    use_scroll_hide_on_scroll_with_options(threshold, UseScrollHideOnScrollOptions::default())
+}
+
+/// Version of [`use_scroll_hide_on_scroll`] that takes a `UseScrollHideOnScrollOptions`. See [`use_scroll_hide_on_scroll`] for how to use.
+pub fn use_scroll_hide_on_scroll_with_options(
+    threshold: f64,
+    options: UseScrollHideOnScrollOptions,
+) -> Signal<bool> {
+    let UseScrollHideOnScrollOptions { tolerance } = options;
+
+    let (_, y) = use_window_scroll();
+
+    let (visible, set_visible) = signal(true);
+    let last_y = StoredValue::new(y.get_untracked());
+
+    Effect::watch(
+        move || y.get(),
+        move |y, _, _| {
+            let y = *y;
+
+            if y <= threshold {
+                set_visible.set(true);
+                last_y.set_value(y);
+                return;
+            }
+
+            let delta = y - last_y.get_value();
+
+            if delta.abs() <= tolerance {
+                return;
+            }
+
+            set_visible.set(delta < 0.0);
+            last_y.set_value(y);
+        },
+        false,
+    );
+
+    visible.into()
+}
+
+/// Options for [`use_scroll_hide_on_scroll_with_options`].
+#[derive(DefaultBuilder, Clone, Copy)]
+pub struct UseScrollHideOnScrollOptions {
+    /// Distance in pixels that has to be scrolled in one direction before the visibility changes.
+    /// Defaults to `5.0`.
+    tolerance: f64,
+}
+
+impl Default for UseScrollHideOnScrollOptions {
+    fn default() -> Self {
+        Self { tolerance: 5.0 }
+    }
+}
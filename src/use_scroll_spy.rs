@@ -0,0 +1,193 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::IntoElementsMaybeSignal;
+use crate::{
+    use_event_listener_with_options, use_intersection_observer_with_options, use_window,
+    UseEventListenerOptions, UseIntersectionObserverOptions,
+};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::{resize, scroll};
+use leptos::prelude::*;
+
+/// Reactive index of the section that is currently in view.
+///
+/// This is the typical "active section" highlight of a documentation sidebar or a one-page navigation.
+/// The active section is the first one (in the order they are provided) that intersects the viewport
+/// below the `offset` from the top. When the page is scrolled to the very bottom, the last section is
+/// active, even if it isn't the first visible one.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Section;
+/// # use leptos_use::use_scroll_spy;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let intro = NodeRef::<Section>::new();
+/// let usage = NodeRef::<Section>::new();
+/// let api = NodeRef::<Section>::new();
+///
+/// let active = use_scroll_spy(vec![intro, usage, api]);
+///
+/// view! {
+///     <nav>
+///         <a href="#intro" class:active=move || active.get() == Some(0)>"Intro"</a>
+///         <a href="#usage" class:active=move || active.get() == Some(1)>"Usage"</a>
+///         <a href="#api" class:active=move || active.get() == Some(2)>"API"</a>
+///     </nav>
+///     <section id="intro" node_ref=intro>"..."</section>
+///     <section id="usage" node_ref=usage>"..."</section>
+///     <section id="api" node_ref=api>"..."</section>
+/// }
+/// # }
+/// ```
+///
+/// If you have a sticky header you should set the `offset` to its height so that sections hidden
+/// behind it are not considered to be in view.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Section;
+/// # use leptos_use::{use_scroll_spy_with_options, UseScrollSpyOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let intro = NodeRef::<Section>::new();
+/// # let usage = NodeRef::<Section>::new();
+/// #
+/// let active = use_scroll_spy_with_options(
+///     vec![intro, usage],
+///     UseScrollSpyOptions::default().offset(64.0),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server this returns a `Signal` that always contains `None`.
+pub fn use_scroll_spy<Els, M>(sections: Els) -> Signal<Option<usize>>
+where
+    Els: IntoElementsMaybeSignal<web_sys::Element, M>,
+{
+    use_scroll_spy_with_options(sections, UseScrollSpyOptions::default())
+}
+
+/// Version of [`use_scroll_spy`] that takes a `UseScrollSpyOptions`. See [`use_scroll_spy`] for how to use.
+pub fn use_scroll_spy_with_options<Els, M>(
+    sections: Els,
+    options: UseScrollSpyOptions,
+) -> Signal<Option<usize>>
+where
+    Els: IntoElementsMaybeSignal<web_sys::Element, M>,
+{
+    let UseScrollSpyOptions {
+        offset,
+        bottom_threshold,
+    } = options;
+
+    let sections = sections.into_elements_maybe_signal();
+
+    let intersecting = RwSignal::new(Vec::<bool>::new());
+    let (is_at_bottom, set_at_bottom) = signal(false);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use_intersection_observer_with_options(
+            sections,
+            move |entries, _| {
+                let elements = sections.get_untracked();
+
+                intersecting.update(|intersecting| {
+                    intersecting.resize(elements.len(), false);
+
+                    for entry in entries {
+                        let target = entry.target();
+
+                        if let Some(index) =
+                            elements.iter().position(|el| el.as_ref() == Some(&target))
+                        {
+                            intersecting[index] = entry.is_intersecting();
+                        }
+                    }
+                });
+            },
+            UseIntersectionObserverOptions::default()
+                .root_margin(format!("-{offset}px 0px 0px 0px")),
+        );
+
+        let update_at_bottom = move || {
+            let window = window();
+
+            let scroll_y = window.scroll_y().unwrap_or_default();
+            let inner_height = window
+                .inner_height()
+                .ok()
+                .and_then(|h| h.as_f64())
+                .unwrap_or_default();
+            let scroll_height = document()
+                .document_element()
+                .map(|el| el.scroll_height() as f64)
+                .unwrap_or_default();
+
+            set_at_bottom
+                .set(scroll_y > 0.0 && scroll_y + inner_height >= scroll_height - bottom_threshold);
+        };
+
+        update_at_bottom();
+
+        let listener_options = UseEventListenerOptions::default().passive(true);
+
+        let _ = use_event_listener_with_options(
+            use_window(),
+            scroll,
+            move |_| update_at_bottom(),
+            listener_options,
+        );
+        let _ = use_event_listener_with_options(
+            use_window(),
+            resize,
+            move |_| update_at_bottom(),
+            listener_options,
+        );
+    }
+
+    Signal::derive(move || {
+        let count = sections.with(|sections| sections.len());
+
+        if count > 0 && is_at_bottom.get() {
+            return Some(count - 1);
+        }
+
+        intersecting.with(|intersecting| {
+            intersecting
+                .iter()
+                .take(count)
+                .position(|is_intersecting| *is_intersecting)
+        })
+    })
+}
+
+/// Options for [`use_scroll_spy_with_options`].
+#[derive(DefaultBuilder, Clone, Copy)]
+pub struct UseScrollSpyOptions {
+    /// Distance in pixels from the top of the viewport that is considered the start of the visible
+    /// area. Set this to the height of a sticky header. Defaults to `0.0`.
+    offset: f64,
+
+    /// Distance in pixels from the bottom of the page at which the last section is always considered
+    /// active. Defaults to `1.0`.
+    bottom_threshold: f64,
+}
+
+impl Default for UseScrollSpyOptions {
+    fn default() -> Self {
+        Self {
+            offset: 0.0,
+            bottom_threshold: 1.0,
+        }
+    }
+}
@@ -8,7 +8,7 @@ use leptos::{
 use send_wrapper::SendWrapper;
 use std::sync::Arc;
 use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
-use web_sys::ServiceWorkerRegistration;
+use web_sys::{RegistrationOptions, ServiceWorkerRegistration, ServiceWorkerUpdateViaCache};
 
 use crate::{js_fut, sendwrap_fn, use_window};
 
@@ -40,6 +40,45 @@ use crate::{js_fut, sendwrap_fn, use_window};
 /// # }
 /// ```
 ///
+/// If your app is not served from the root you can specify the `scope` of the registration.
+/// With `update_via_cache` you control if the HTTP cache is used when checking for an updated worker.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_service_worker_with_options, UseServiceWorkerOptions};
+/// # use web_sys::ServiceWorkerUpdateViaCache;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let sw = use_service_worker_with_options(
+///     UseServiceWorkerOptions::default()
+///         .script_url("/my-app/service-worker.js")
+///         .scope("/my-app/".to_string())
+///         .update_via_cache(ServiceWorkerUpdateViaCache::None),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// To check for a new worker on demand (e.g. when the window gains focus) call `check_for_update`.
+/// It calls [`registration.update()`](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerRegistration/update).
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::ev::focus;
+/// # use leptos_use::{use_event_listener, use_service_worker, use_window, UseServiceWorkerReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseServiceWorkerReturn { check_for_update, .. } = use_service_worker();
+///
+/// let _ = use_event_listener(use_window(), focus, move |_| check_for_update());
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
 /// The returned closures `check_for_update` and `skip_waiting` are sendwrapped functions. They can
@@ -79,7 +118,11 @@ pub fn use_service_worker_with_options(
     let update_sw = create_action_update();
 
     // Immediately create or update the SW registration.
-    create_or_update_registration.dispatch(ServiceWorkerScriptUrl(options.script_url.to_string()));
+    create_or_update_registration.dispatch(ServiceWorkerRegistrationParams {
+        script_url: options.script_url.clone(),
+        scope: options.scope.clone(),
+        update_via_cache: options.update_via_cache,
+    });
 
     // And parse the result into individual signals.
     let registration: Signal<
@@ -184,6 +227,15 @@ pub struct UseServiceWorkerOptions {
     #[builder(into)]
     script_url: String,
 
+    /// The scope of the registration. This is the URL path that the ServiceWorker controls.
+    /// By default, this is the directory of `script_url`.
+    #[builder(into)]
+    scope: Option<String>,
+
+    /// Whether the HTTP cache is used when checking for an updated ServiceWorker script and its imports.
+    /// The default is `ServiceWorkerUpdateViaCache::Imports`.
+    update_via_cache: ServiceWorkerUpdateViaCache,
+
     /// The message sent to a waiting ServiceWorker when you call the `skip_waiting` callback.
     /// The callback is part of the return type of [`use_service_worker`]!
     /// The default message is 'skipWaiting'.
@@ -199,6 +251,8 @@ impl Default for UseServiceWorkerOptions {
     fn default() -> Self {
         Self {
             script_url: "service-worker.js".into(),
+            scope: None,
+            update_via_cache: ServiceWorkerUpdateViaCache::Imports,
             skip_waiting_message: "skipWaiting".into(),
             on_controller_change: Arc::new(move || {
                 use std::ops::Deref;
@@ -233,7 +287,7 @@ where
     /// Whether a SW is active.
     pub active: Signal<bool>,
 
-    /// Check for a ServiceWorker update.
+    /// Check for a ServiceWorker update by calling `registration.update()`.
     pub check_for_update: CheckFn,
 
     /// Call this to activate a new ("waiting") SW if one is available.
@@ -241,7 +295,11 @@ where
     pub skip_waiting: SkipFn,
 }
 
-struct ServiceWorkerScriptUrl(pub String);
+struct ServiceWorkerRegistrationParams {
+    script_url: String,
+    scope: Option<String>,
+    update_via_cache: ServiceWorkerUpdateViaCache,
+}
 
 #[derive(Debug, Clone)]
 pub enum ServiceWorkerRegistrationError {
@@ -273,18 +331,28 @@ fn create_action_update() -> Action<
 
 /// A leptos action which asynchronously creates or updates and than retrieves the ServiceWorkerRegistration.
 fn create_action_create_or_update_registration() -> Action<
-    ServiceWorkerScriptUrl,
+    ServiceWorkerRegistrationParams,
     Result<SendWrapper<ServiceWorkerRegistration>, SendWrapper<JsValue>>,
 > {
-    Action::new_unsync(move |script_url: &ServiceWorkerScriptUrl| {
-        let script_url = script_url.0.to_owned();
+    Action::new_unsync(move |params: &ServiceWorkerRegistrationParams| {
+        let script_url = params.script_url.clone();
+
+        let registration_options = RegistrationOptions::new();
+        if let Some(scope) = &params.scope {
+            registration_options.set_scope(scope);
+        }
+        registration_options.set_update_via_cache(params.update_via_cache);
+        let registration_options = SendWrapper::new(registration_options);
+
         async move {
             if let Some(navigator) = use_window().navigator() {
-                js_fut!(navigator.service_worker().register(script_url.as_str()))
-                    .await
-                    .and_then(|ok| ok.dyn_into::<ServiceWorkerRegistration>())
-                    .map(SendWrapper::new)
-                    .map_err(SendWrapper::new)
+                js_fut!(navigator
+                    .service_worker()
+                    .register_with_options(script_url.as_str(), &registration_options))
+                .await
+                .and_then(|ok| ok.dyn_into::<ServiceWorkerRegistration>())
+                .map(SendWrapper::new)
+                .map_err(SendWrapper::new)
             } else {
                 Err(SendWrapper::new(JsValue::from_str("no navigator")))
             }
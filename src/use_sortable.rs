@@ -0,0 +1,348 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::IntoElementMaybeSignal;
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::sync::Arc;
+use std::time::Duration;
+use wasm_bindgen::JsCast;
+
+/// Reorder a list by dragging its items.
+///
+/// The children of `container` are the items of the list. Their order has to correspond to the
+/// order of the items in `list`, like when they are rendered with `<For>`. When an item is dropped
+/// onto another item, it is moved to that position in `list`.
+///
+/// The dragging is pointer based, so it works with mouse, touch and pen alike.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Ul;
+/// # use leptos_use::use_sortable;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let container = NodeRef::<Ul>::new();
+/// let fruits = RwSignal::new(vec![
+///     "Apple".to_string(),
+///     "Banana".to_string(),
+///     "Cherry".to_string(),
+/// ]);
+///
+/// use_sortable(container, fruits);
+///
+/// view! {
+///     <ul node_ref=container>
+///         <For each=move || fruits.get() key=|fruit| fruit.clone() let:fruit>
+///             <li>{fruit}</li>
+///         </For>
+///     </ul>
+/// }
+/// # }
+/// ```
+///
+/// ### Options
+///
+/// With `handle` only elements matching this CSS selector start the dragging. The dragged item
+/// gets the CSS class `ghost_class` which defaults to `"sortable-ghost"`. When an item has been
+/// moved the items are animated to their new positions for `animation` milliseconds.
+/// `on_update` is called with the old and the new index after `list` has been updated.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_sortable_with_options, UseSortableOptions, UseSortableReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let container = NodeRef::<Div>::new();
+/// # let tasks = RwSignal::new(vec!["Write code".to_string(), "Test code".to_string()]);
+/// let UseSortableReturn { is_dragging, over_index, .. } = use_sortable_with_options(
+///     container,
+///     tasks,
+///     UseSortableOptions::default()
+///         .handle(".drag-handle")
+///         .animation(200.0)
+///         .ghost_class("is-dragged")
+///         .on_update(|args| {
+///             leptos::logging::log!("Moved from {} to {}", args.old_index, args.new_index);
+///         }),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// > On touch devices the browser scrolls instead of emitting pointer events. Set the CSS property
+/// > `touch-action: none` on the items or handles to prevent this.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this amounts to a no-op.
+pub fn use_sortable<El, M, T>(container: El, list: RwSignal<Vec<T>>) -> UseSortableReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+    T: Send + Sync + 'static,
+{
+    use_sortable_with_options(container, list, UseSortableOptions::default())
+}
+
+/// Version of [`use_sortable`] that takes a `UseSortableOptions`. See [`use_sortable`] for how to use.
+pub fn use_sortable_with_options<El, M, T>(
+    container: El,
+    list: RwSignal<Vec<T>>,
+    options: UseSortableOptions,
+) -> UseSortableReturn
+where
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+    T: Send + Sync + 'static,
+{
+    let UseSortableOptions {
+        handle,
+        animation,
+        ghost_class,
+        disabled,
+        on_update,
+    } = options;
+
+    let (dragging_index, set_dragging_index) = signal(None::<usize>);
+    let (over_index, set_over_index) = signal(None::<usize>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::{use_event_listener, use_window};
+        use leptos::ev::{pointercancel, pointerdown, pointermove, pointerup};
+
+        let container = container.into_element_maybe_signal();
+
+        let dragged_el = StoredValue::new_local(None::<web_sys::Element>);
+
+        // Returns the child of the container that contains `el` together with its index
+        let find_item = move |el: Option<web_sys::Element>| {
+            let container = container.get_untracked()?;
+
+            let mut item = el?;
+            loop {
+                let parent = item.parent_element()?;
+                if parent == container {
+                    break;
+                }
+                item = parent;
+            }
+
+            let children = container.children();
+            (0..children.length())
+                .find(|i| children.item(*i).as_ref() == Some(&item))
+                .map(|i| (item, i as usize))
+        };
+
+        let _ = use_event_listener(container, pointerdown, {
+            let ghost_class = ghost_class.clone();
+
+            move |event| {
+                if disabled.get_untracked() || event.button() != 0 {
+                    return;
+                }
+
+                let target = event_target::<web_sys::Element>(&event);
+
+                if let Some(handle) = &handle {
+                    if !matches!(target.closest(handle), Ok(Some(_))) {
+                        return;
+                    }
+                }
+
+                if let Some((item, index)) = find_item(Some(target)) {
+                    // prevent text selection while dragging
+                    event.prevent_default();
+
+                    let _ = item.class_list().add_1(&ghost_class);
+                    dragged_el.set_value(Some(item));
+
+                    set_dragging_index.set(Some(index));
+                    set_over_index.set(Some(index));
+                }
+            }
+        });
+
+        let _ = use_event_listener(use_window(), pointermove, move |event| {
+            if dragging_index.get_untracked().is_none() {
+                return;
+            }
+
+            let el =
+                document().element_from_point(event.client_x() as f32, event.client_y() as f32);
+
+            if let Some((_, index)) = find_item(el) {
+                if over_index.get_untracked() != Some(index) {
+                    set_over_index.set(Some(index));
+                }
+            }
+        });
+
+        let end_drag = move |commit: bool| {
+            let Some(old_index) = dragging_index.get_untracked() else {
+                return;
+            };
+            let new_index = over_index.get_untracked();
+
+            if let Some(item) = dragged_el.get_value() {
+                let _ = item.class_list().remove_1(&ghost_class);
+            }
+            dragged_el.set_value(None);
+            set_dragging_index.set(None);
+            set_over_index.set(None);
+
+            let Some(new_index) = new_index.filter(|new_index| commit && *new_index != old_index)
+            else {
+                return;
+            };
+
+            let rects = container
+                .get_untracked()
+                .map(|container| item_rects(&container))
+                .unwrap_or_default();
+
+            let mut moved = false;
+            list.update(|list| {
+                if old_index < list.len() && new_index < list.len() {
+                    let item = list.remove(old_index);
+                    list.insert(new_index, item);
+                    moved = true;
+                }
+            });
+
+            if moved {
+                on_update(UseSortableUpdateArgs {
+                    old_index,
+                    new_index,
+                });
+
+                if animation > 0.0 {
+                    animate_items(rects, animation);
+                }
+            }
+        };
+
+        let _ = use_event_listener(use_window(), pointerup, {
+            let end_drag = end_drag.clone();
+            move |_| end_drag(true)
+        });
+
+        let _ = use_event_listener(use_window(), pointercancel, move |_| end_drag(false));
+    }
+
+    UseSortableReturn {
+        is_dragging: Signal::derive(move || dragging_index.get().is_some()),
+        dragging_index: dragging_index.into(),
+        over_index: over_index.into(),
+    }
+}
+
+/// Returns the current bounding rects of all children of `container`.
+#[cfg(not(feature = "ssr"))]
+fn item_rects(container: &web_sys::Element) -> Vec<(web_sys::HtmlElement, web_sys::DomRect)> {
+    let children = container.children();
+
+    (0..children.length())
+        .filter_map(|i| children.item(i))
+        .filter_map(|child| child.dyn_into::<web_sys::HtmlElement>().ok())
+        .map(|child| {
+            let rect = child.get_bounding_client_rect();
+            (child, rect)
+        })
+        .collect()
+}
+
+/// Animates the items from their previous positions to their current ones after the DOM has been
+/// updated.
+#[cfg(not(feature = "ssr"))]
+fn animate_items(items: Vec<(web_sys::HtmlElement, web_sys::DomRect)>, duration: f64) {
+    request_animation_frame(move || {
+        for (item, old_rect) in &items {
+            let new_rect = item.get_bounding_client_rect();
+            let dx = old_rect.left() - new_rect.left();
+            let dy = old_rect.top() - new_rect.top();
+
+            if dx == 0.0 && dy == 0.0 {
+                continue;
+            }
+
+            let style = item.style();
+            let _ = style.set_property("transition", "none");
+            let _ = style.set_property("transform", &format!("translate({dx}px, {dy}px)"));
+
+            // force a reflow so the browser picks up the start of the transition
+            let _ = item.offset_width();
+
+            let _ = style.set_property("transition", &format!("transform {duration}ms"));
+            let _ = style.remove_property("transform");
+        }
+
+        set_timeout(
+            move || {
+                for (item, _) in items {
+                    let _ = item.style().remove_property("transition");
+                }
+            },
+            Duration::from_millis(duration as u64),
+        );
+    });
+}
+
+/// Options for [`use_sortable_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseSortableOptions {
+    /// CSS selector of the elements inside an item that start the dragging.
+    /// Defaults to `None` which means the whole item can be dragged.
+    #[builder(into)]
+    handle: Option<String>,
+
+    /// Duration in milliseconds of the animation of the items moving to their new positions.
+    /// Set to `0.0` to disable the animation. Defaults to `150.0`.
+    animation: f64,
+
+    /// CSS class that is added to the item while it is being dragged. Defaults to `"sortable-ghost"`.
+    #[builder(into)]
+    ghost_class: String,
+
+    /// Disables the sorting while `true`. Defaults to `false`.
+    #[builder(into)]
+    disabled: Signal<bool>,
+
+    /// Callback that is called after an item has been moved and `list` has been updated.
+    on_update: Arc<dyn Fn(UseSortableUpdateArgs) + Send + Sync>,
+}
+
+impl Default for UseSortableOptions {
+    fn default() -> Self {
+        Self {
+            handle: None,
+            animation: 150.0,
+            ghost_class: "sortable-ghost".to_string(),
+            disabled: Signal::default(),
+            on_update: Arc::new(|_| {}),
+        }
+    }
+}
+
+/// Argument of the `on_update` callback of [`UseSortableOptions`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UseSortableUpdateArgs {
+    /// Index of the item before it was moved
+    pub old_index: usize,
+    /// Index of the item after it was moved
+    pub new_index: usize,
+}
+
+/// Return type of [`use_sortable`].
+pub struct UseSortableReturn {
+    /// Whether an item is being dragged
+    pub is_dragging: Signal<bool>,
+    /// Index of the item that is being dragged
+    pub dragging_index: Signal<Option<usize>>,
+    /// Index of the item the dragged item is currently over. This is where it will be moved to when dropped.
+    pub over_index: Signal<Option<usize>>,
+}
@@ -1,3 +1,4 @@
+use default_struct_builder::DefaultBuilder;
 use leptos::prelude::*;
 use leptos::reactive::wrappers::read::Signal;
 use std::cmp::Ordering;
@@ -74,6 +75,45 @@ use std::ops::DerefMut;
 /// ```
 ///
 /// Please note that these two ways of sorting are equivalent.
+///
+/// ### Large lists
+///
+/// The functions above sort the whole list from scratch whenever the source changes. For large
+/// lists where only a few items change at a time, like a table with thousands of rows where a
+/// single cell is updated, use `use_sorted_by_with_options` with `memoize` enabled.
+/// The order of the last sort is then reused as the starting point so that sorting an almost
+/// sorted list takes roughly linear time. Subscribers are only notified if the sorted list actually
+/// changed and the returned `reordered` tells you whether the last update changed the order of the
+/// items.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_sorted_by_with_options, UseSortedOptions, UseSortedReturn};
+/// #
+/// #[derive(Clone, PartialEq)]
+/// pub struct Row {
+///     pub id: usize,
+///     pub price: u32,
+/// }
+///
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (rows, set_rows) = signal(
+///     (0..10_000).map(|id| Row { id, price: (id as u32 * 7) % 1000 }).collect::<Vec<_>>(),
+/// );
+///
+/// let UseSortedReturn { sorted, reordered }: UseSortedReturn<Vec<Row>> = use_sorted_by_with_options(
+///     rows,
+///     |a: &Row, b: &Row| a.price.cmp(&b.price),
+///     UseSortedOptions::default().memoize(true),
+/// );
+///
+/// // only a single cell changes -> cheap to sort again
+/// set_rows.update(|rows| rows[42].price = 500);
+/// #
+/// # view! { }
+/// # }
+/// ```
 pub fn use_sorted<S, I, T>(iterable: S) -> Signal<I>
 where
     S: Into<Signal<I>>,
@@ -121,3 +161,84 @@ where
         iterable
     })
 }
+
+/// Version of [`use_sorted_by`] that takes a `UseSortedOptions`. See [`use_sorted`] for how to use.
+pub fn use_sorted_by_with_options<S, I, T, F>(
+    iterable: S,
+    cmp_fn: F,
+    options: UseSortedOptions,
+) -> UseSortedReturn<I>
+where
+    S: Into<Signal<I>>,
+    I: DerefMut<Target = [T]> + Clone + PartialEq + Send + Sync + 'static,
+    T: Clone,
+    F: Fn(&T, &T) -> Ordering + Send + Sync + 'static,
+{
+    let UseSortedOptions { memoize } = options;
+
+    let iterable = iterable.into();
+
+    // Indices into the source in sorted order as of the last update
+    let order = StoredValue::new(Vec::<usize>::new());
+    let order_changed = StoredValue::new(false);
+
+    let sorted = Memo::new(move |_| {
+        iterable.with(|source| {
+            let previous_order = order.get_value();
+
+            let mut new_order = if memoize && previous_order.len() == source.len() {
+                // almost sorted already if only a few items changed
+                previous_order.clone()
+            } else {
+                (0..source.len()).collect::<Vec<_>>()
+            };
+
+            new_order.sort_by(|a, b| cmp_fn(&source[*a], &source[*b]));
+
+            let mut sorted = source.clone();
+            for (target, index) in sorted.iter_mut().zip(new_order.iter()) {
+                *target = source[*index].clone();
+            }
+
+            order_changed.set_value(new_order != previous_order);
+            order.set_value(new_order);
+
+            sorted
+        })
+    });
+
+    let reordered = Memo::new(move |_| {
+        // `sorted` isn't notified if the result is equal so track the source as well.
+        // Reading `sorted` makes sure it has been updated before the flag is read.
+        iterable.track();
+        sorted.with(|_| ());
+        order_changed.get_value()
+    });
+
+    UseSortedReturn {
+        sorted: sorted.into(),
+        reordered: reordered.into(),
+    }
+}
+
+/// Options for [`use_sorted_by_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseSortedOptions {
+    /// If `true` the order of the last sort is reused as the starting point when the source
+    /// changes. Sorting a list where only a few items changed then takes roughly linear time.
+    /// Defaults to `false`.
+    memoize: bool,
+}
+
+/// Return type of [`use_sorted_by_with_options`].
+pub struct UseSortedReturn<I>
+where
+    I: Send + Sync + 'static,
+{
+    /// The sorted list
+    pub sorted: Signal<I>,
+
+    /// Whether the last update changed the order of the items. This is also `true` if items have
+    /// been added or removed.
+    pub reordered: Signal<bool>,
+}
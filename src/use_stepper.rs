@@ -0,0 +1,191 @@
+use leptos::prelude::*;
+
+/// State machine for multi-step wizards and onboarding flows.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_stepper;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let stepper = use_stepper(vec!["user", "billing", "confirm"]);
+///
+/// view! {
+///     <Show when=move || stepper.is_current(&"user")>"User information"</Show>
+///     <Show when=move || stepper.is_current(&"billing")>"Billing address"</Show>
+///     <Show when=move || stepper.is_current(&"confirm")>"Confirm your order"</Show>
+///
+///     <button disabled=stepper.is_first on:click=move |_| stepper.previous()>"Back"</button>
+///     <button disabled=stepper.is_last on:click=move |_| stepper.next()>"Next"</button>
+/// }
+/// # }
+/// ```
+///
+/// `goto` accepts either the key of a step or its index.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_stepper;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let stepper = use_stepper(vec!["user", "billing", "confirm"]);
+///
+/// stepper.goto(&"billing");
+/// assert_eq!(stepper.index.get_untracked(), 1);
+///
+/// stepper.goto(2);
+/// assert_eq!(stepper.current.get_untracked(), Some("confirm"));
+///
+/// assert!(stepper.is_after(&"user"));
+/// assert!(!stepper.is_before(&"user"));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// `is_after` returns `true` if the current step comes after the given step, i.e. the given step
+/// has been completed. `is_before` is the opposite.
+///
+/// ## Server-Side Rendering
+///
+/// This works the same on the server.
+pub fn use_stepper<K, L>(steps: L) -> UseStepperReturn<K>
+where
+    K: Clone + PartialEq + Send + Sync + 'static,
+    L: Into<Signal<Vec<K>>>,
+{
+    let steps = steps.into();
+
+    let (raw_index, set_index) = signal(0_usize);
+
+    let index = Signal::derive(move || {
+        let len = steps.read().len();
+        raw_index.get().min(len.saturating_sub(1))
+    });
+
+    let current = Signal::derive(move || steps.read().get(index.get()).cloned());
+
+    let is_first = Signal::derive(move || index.get() == 0);
+
+    let is_last = Signal::derive(move || index.get() + 1 >= steps.read().len());
+
+    UseStepperReturn {
+        steps,
+        index,
+        current,
+        is_first,
+        is_last,
+        set_index,
+    }
+}
+
+/// Return type of [`use_stepper`].
+pub struct UseStepperReturn<K>
+where
+    K: Send + Sync + 'static,
+{
+    /// All steps.
+    pub steps: Signal<Vec<K>>,
+
+    /// Index of the current step.
+    pub index: Signal<usize>,
+
+    /// The current step. `None` if there are no steps.
+    pub current: Signal<Option<K>>,
+
+    /// Whether the current step is the first one.
+    pub is_first: Signal<bool>,
+
+    /// Whether the current step is the last one.
+    pub is_last: Signal<bool>,
+
+    set_index: WriteSignal<usize>,
+}
+
+impl<K> Clone for UseStepperReturn<K>
+where
+    K: Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for UseStepperReturn<K> where K: Send + Sync + 'static {}
+
+impl<K> UseStepperReturn<K>
+where
+    K: Clone + PartialEq + Send + Sync + 'static,
+{
+    /// Goes to the given step. Accepts either the key of the step or its index.
+    /// Does nothing if the step doesn't exist.
+    pub fn goto(&self, step: impl StepperTarget<K>) {
+        let index = self.steps.with_untracked(|steps| step.step_index(steps));
+
+        if let Some(index) = index {
+            self.set_index.set(index);
+        }
+    }
+
+    /// Goes to the next step. Does nothing if the current step is the last one.
+    pub fn next(&self) {
+        if !self.is_last.get_untracked() {
+            self.set_index.set(self.index.get_untracked() + 1);
+        }
+    }
+
+    /// Goes to the previous step. Does nothing if the current step is the first one.
+    pub fn previous(&self) {
+        if !self.is_first.get_untracked() {
+            self.set_index.set(self.index.get_untracked() - 1);
+        }
+    }
+
+    /// Whether the given step is the current one. This is reactive.
+    pub fn is_current(&self, step: &K) -> bool {
+        self.current.with(|current| current.as_ref() == Some(step))
+    }
+
+    /// Whether the current step comes before the given one. This is reactive.
+    pub fn is_before(&self, step: &K) -> bool {
+        self.position(step)
+            .map(|position| self.index.get() < position)
+            .unwrap_or_default()
+    }
+
+    /// Whether the current step comes after the given one. This is reactive.
+    pub fn is_after(&self, step: &K) -> bool {
+        self.position(step)
+            .map(|position| self.index.get() > position)
+            .unwrap_or_default()
+    }
+
+    fn position(&self, step: &K) -> Option<usize> {
+        self.steps
+            .with(|steps| steps.iter().position(|s| s == step))
+    }
+}
+
+/// Target of [`UseStepperReturn::goto`]. Implemented for a reference to a step key and for an index.
+pub trait StepperTarget<K> {
+    /// Returns the index of the step in `steps` if it exists.
+    fn step_index(self, steps: &[K]) -> Option<usize>;
+}
+
+impl<K> StepperTarget<K> for usize {
+    fn step_index(self, steps: &[K]) -> Option<usize> {
+        (self < steps.len()).then_some(self)
+    }
+}
+
+impl<K> StepperTarget<K> for &K
+where
+    K: PartialEq,
+{
+    fn step_index(self, steps: &[K]) -> Option<usize> {
+        steps.iter().position(|step| step == self)
+    }
+}
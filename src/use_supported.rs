@@ -1,6 +1,9 @@
 use leptos::prelude::*;
 
-/// SSR compatibe `is_supported`
+/// SSR compatible feature detection.
+///
+/// Takes a predicate that checks if a browser feature is available and returns it as a signal.
+/// This way you can build feature detection for any API, even the ones this crate doesn't wrap.
 ///
 /// ## Usage
 ///
@@ -14,21 +17,58 @@ use leptos::prelude::*;
 ///     || js!("getBattery" in &window().navigator())
 /// );
 ///
-/// if is_supported.get() {
-///     // do something
+/// view! {
+///     <Show when=move || is_supported.get() fallback=|| "Battery API not supported">
+///         "Battery API available"
+///     </Show>
 /// }
-/// #    view! { }
 /// # }
 /// ```
+///
+/// The predicate is evaluated only once on the client, when `use_supported` is called.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the predicate is never called and the returned signal is always `false`.
+/// This makes it safe to access browser APIs like `window()` inside the predicate.
+///
+/// On the client the signal stays `false` until the component is mounted, i.e. until after
+/// hydration, and is updated to the result of the predicate then. This way the server and the
+/// client render the same content during hydration.
 pub fn use_supported(callback: impl Fn() -> bool + Send + Sync + 'static) -> Signal<bool> {
     #[cfg(feature = "ssr")]
     {
         let _ = callback;
-        Signal::derive(|| false)
+        Signal::stored(false)
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let is_supported = callback();
+
+        // Effects only run after hydration
+        let (is_mounted, set_mounted) = signal(false);
+        Effect::new(move |_| set_mounted.set(true));
+
+        Signal::derive(move || is_mounted.get() && is_supported)
+    }
+}
+
+/// Like [`use_supported`] but on the client the result of the predicate is available right away.
+/// Used internally where the value decides what to set up.
+// Unused if only the `use_supported` feature is enabled.
+#[allow(dead_code)]
+pub(crate) fn use_supported_immediately(
+    callback: impl Fn() -> bool + Send + Sync + 'static,
+) -> Signal<bool> {
+    #[cfg(feature = "ssr")]
+    {
+        let _ = callback;
+        Signal::stored(false)
     }
 
     #[cfg(not(feature = "ssr"))]
     {
-        Signal::derive(callback)
+        Signal::stored(callback())
     }
 }
@@ -92,6 +92,39 @@ use std::sync::Arc;
 /// # }
 /// ```
 ///
+/// ### Limit the number of rows
+///
+/// With the option `max_rows` the textarea stops growing once it reaches the given number of rows.
+/// From then on it becomes scrollable.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Textarea;
+/// # use leptos_use::{use_textarea_autosize_with_options, UseTextareaAutosizeOptions, UseTextareaAutosizeReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let textarea = NodeRef::new();
+///
+/// let UseTextareaAutosizeReturn {
+///     content,
+///     set_content,
+///     ..
+/// } = use_textarea_autosize_with_options(
+///     textarea,
+///     UseTextareaAutosizeOptions::default().max_rows(Some(5)),
+/// );
+///
+/// view! {
+///     <textarea
+///         prop:value=content
+///         on:input=move |evt| set_content.set(event_target_value(&evt))
+///         node_ref=textarea
+///     />
+/// }
+/// # }
+/// ```
+///
 /// ## SendWrapped Return
 ///
 /// The returned closure `trigger_resize` is a sendwrapped function. It can
@@ -136,6 +169,7 @@ where
             on_resize,
             style_target,
             style_prop,
+            max_rows,
         } = options;
 
         let (content, set_content) = content.into_signal();
@@ -148,25 +182,46 @@ where
                 if let Some(textarea) = textarea {
                     let mut height = "".to_string();
 
+                    let style = window().get_computed_style(textarea).ok().flatten();
+                    let style_num = |prop: &str| {
+                        style
+                            .as_ref()
+                            .map(|style| {
+                                parse_num(&style.get_property_value(prop).unwrap_or_default())
+                            })
+                            .unwrap_or_default() as i32
+                    };
+
                     let border_offset =
-                        if let Ok(Some(style)) = window().get_computed_style(textarea) {
-                            (parse_num(
-                                &style
-                                    .get_property_value("border-top-width")
-                                    .unwrap_or_default(),
-                            ) + parse_num(
-                                &style
-                                    .get_property_value("border-bottom-width")
-                                    .unwrap_or_default(),
-                            )) as i32
-                        } else {
-                            0
-                        };
+                        style_num("border-top-width") + style_num("border-bottom-width");
 
                     web_sys::HtmlElement::style(textarea)
                         .set_property(&style_prop, "1px")
                         .ok();
-                    set_textarea_scroll_height.set(textarea.scroll_height() + border_offset + 1);
+
+                    let mut scroll_height = textarea.scroll_height() + border_offset + 1;
+
+                    if let Some(max_rows) = max_rows {
+                        let line_height = match style_num("line-height") {
+                            // `line-height: normal` is roughly 1.2 times the font size
+                            0 => (style_num("font-size") as f64 * 1.2).round() as i32,
+                            line_height => line_height,
+                        };
+                        let max_height = max_rows as i32 * line_height
+                            + style_num("padding-top")
+                            + style_num("padding-bottom")
+                            + border_offset;
+
+                        let textarea_style = web_sys::HtmlElement::style(textarea);
+                        if scroll_height > max_height {
+                            scroll_height = max_height;
+                            textarea_style.set_property("overflow-y", "auto").ok();
+                        } else {
+                            textarea_style.remove_property("overflow-y").ok();
+                        }
+                    }
+
+                    set_textarea_scroll_height.set(scroll_height);
 
                     if let Some(style_target) = style_target.get() {
                         // If style target is provided update its height
@@ -286,6 +341,10 @@ pub struct UseTextareaAutosizeOptions {
     /// Should be `"height"` or `"min-height"`. Default value is `"height"`.
     #[builder(into)]
     style_prop: String,
+
+    /// Maximum number of rows the textarea grows to. Past this limit the height stays fixed and
+    /// the textarea becomes scrollable with `overflow-y: auto`. Defaults to `None` (no limit).
+    max_rows: Option<u32>,
 }
 
 impl Default for UseTextareaAutosizeOptions {
@@ -296,6 +355,7 @@ impl Default for UseTextareaAutosizeOptions {
             on_resize: Arc::new(|| ()),
             style_target: Default::default(),
             style_prop: "height".to_string(),
+            max_rows: None,
         }
     }
 }
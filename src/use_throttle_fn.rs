@@ -72,8 +72,9 @@ pub use crate::utils::ThrottleOptions;
 ///
 /// ## Server-Side Rendering
 ///
-/// Internally this uses `setTimeout` which is not supported on the server. So usually calling
-/// a throttled function on the server will simply be ignored.
+/// Internally this uses `setTimeout` which is not supported on the server. On the server only
+/// leading calls are executed, trailing calls are dropped. The returned function can always be
+/// called safely and returns the last return value (if any).
 pub fn use_throttle_fn<F, R>(
     func: F,
     ms: impl Into<Signal<f64>> + 'static,
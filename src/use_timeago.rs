@@ -0,0 +1,260 @@
+use crate::core::now;
+use crate::{use_interval_fn_with_options, UseIntervalFnOptions};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::sync::Arc;
+
+const SECOND: f64 = 1000.0;
+const MINUTE: f64 = 60.0 * SECOND;
+const HOUR: f64 = 60.0 * MINUTE;
+const DAY: f64 = 24.0 * HOUR;
+const WEEK: f64 = 7.0 * DAY;
+const MONTH: f64 = 30.0 * DAY;
+const YEAR: f64 = 365.0 * DAY;
+
+/// Reactive time ago string like `"3 minutes ago"` or `"in 2 days"`.
+///
+/// The string is updated automatically. Close to now it is updated every second, further away
+/// less often. How often at most can be configured with the option `max_interval`.
+///
+/// ## Usage
+///
+/// The time is given as milliseconds since the Unix epoch.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_timeago;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (created_at, set_created_at) = signal(js_sys::Date::now() - 3.0 * 60.0 * 1000.0);
+///
+/// let time_ago = use_timeago(created_at);
+///
+/// view! { <p>"Created " {time_ago}</p> } // Created 3 minutes ago
+/// # }
+/// ```
+///
+/// To use a [`chrono::DateTime`] just convert it to milliseconds.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_timeago;
+/// # use chrono::{DateTime, Utc};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (due, set_due) = signal(Utc::now());
+///
+/// let time_ago = use_timeago(Signal::derive(move || due.get().timestamp_millis() as f64));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Messages
+///
+/// The messages can be customized with the option `messages`, for example to translate them.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_timeago_with_options, UseTimeagoMessages, UseTimeagoOptions};
+/// # use std::sync::Arc;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let (created_at, set_created_at) = signal(0.0);
+/// #
+/// let time_ago = use_timeago_with_options(
+///     created_at,
+///     UseTimeagoOptions::default().messages(UseTimeagoMessages {
+///         just_now: "gerade eben".to_string(),
+///         past: Arc::new(|n| format!("vor {n}")),
+///         future: Arc::new(|n| format!("in {n}")),
+///         minute: Arc::new(|n| format!("{n} {}", if n == 1 { "Minute" } else { "Minuten" })),
+///         ..Default::default()
+///     }),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// If you need the formatting without the reactivity, use [`format_timeago`].
+///
+/// ## Server-Side Rendering
+///
+/// On the server the string is rendered once relative to the current time and never updated.
+pub fn use_timeago(time: impl Into<Signal<f64>>) -> Signal<String> {
+    use_timeago_with_options(time, UseTimeagoOptions::default())
+}
+
+/// Version of [`use_timeago`] that takes a `UseTimeagoOptions`. See [`use_timeago`] for how to use.
+pub fn use_timeago_with_options(
+    time: impl Into<Signal<f64>>,
+    options: UseTimeagoOptions,
+) -> Signal<String> {
+    let UseTimeagoOptions {
+        messages,
+        max_interval,
+        show_second,
+    } = options;
+
+    let time = time.into();
+
+    let (now_ts, set_now_ts) = signal(now());
+
+    let interval = Memo::new(move |_| {
+        let diff = (now_ts.get() - time.get()).abs();
+
+        let interval = if diff < MINUTE {
+            SECOND
+        } else if diff < HOUR {
+            15.0 * SECOND
+        } else {
+            MINUTE
+        };
+
+        (interval as u64).min(max_interval).max(1)
+    });
+
+    let _ = use_interval_fn_with_options(
+        move || set_now_ts.set(now()),
+        interval,
+        UseIntervalFnOptions::default(),
+    );
+
+    Signal::derive(move || format_timeago(time.get(), now_ts.get(), &messages, show_second))
+}
+
+/// Formats the time `from` relative to `now` (both in milliseconds since the Unix epoch) as a
+/// string like `"3 minutes ago"` or `"in 2 days"`. This is what [`use_timeago`] uses internally.
+///
+/// If `show_second` is `false`, everything within a minute is formatted as `messages.just_now`.
+pub fn format_timeago(
+    from: f64,
+    now: f64,
+    messages: &UseTimeagoMessages,
+    show_second: bool,
+) -> String {
+    if from.is_nan() || now.is_nan() {
+        return messages.invalid.clone();
+    }
+
+    let diff = now - from;
+    let abs_diff = diff.abs();
+
+    if abs_diff < if show_second { SECOND } else { MINUTE } {
+        return messages.just_now.clone();
+    }
+
+    let units: [(f64, &TimeagoUnitFn); 7] = [
+        (YEAR, &messages.year),
+        (MONTH, &messages.month),
+        (WEEK, &messages.week),
+        (DAY, &messages.day),
+        (HOUR, &messages.hour),
+        (MINUTE, &messages.minute),
+        (SECOND, &messages.second),
+    ];
+
+    let (unit, format_unit) = units
+        .into_iter()
+        .find(|(unit, _)| abs_diff >= *unit)
+        .unwrap_or((SECOND, &messages.second));
+
+    let value = format_unit((abs_diff / unit).round() as u64);
+
+    if diff > 0.0 {
+        (messages.past)(value)
+    } else {
+        (messages.future)(value)
+    }
+}
+
+/// Options for [`use_timeago_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseTimeagoOptions {
+    /// The messages that are used to build the string. Defaults to English.
+    messages: UseTimeagoMessages,
+
+    /// The maximum interval in milliseconds in which the string is updated. Close to now the
+    /// string is updated more often. Defaults to `60_000` (one minute).
+    max_interval: u64,
+
+    /// If `true`, times within a minute are shown in seconds instead of `just_now`.
+    /// Defaults to `false`.
+    show_second: bool,
+}
+
+impl Default for UseTimeagoOptions {
+    fn default() -> Self {
+        Self {
+            messages: UseTimeagoMessages::default(),
+            max_interval: 60_000,
+            show_second: false,
+        }
+    }
+}
+
+type TimeagoUnitFn = Arc<dyn Fn(u64) -> String + Send + Sync>;
+type TimeagoDirectionFn = Arc<dyn Fn(String) -> String + Send + Sync>;
+
+/// Messages for [`use_timeago`] and [`format_timeago`].
+///
+/// The unit functions receive the rounded number of units and return the formatted amount like
+/// `"3 minutes"`. `past` and `future` receive this amount and return the full string.
+#[derive(Clone)]
+pub struct UseTimeagoMessages {
+    /// Used for times very close to now. Defaults to `"just now"`.
+    pub just_now: String,
+    /// Used if the time is invalid (`NaN`). Defaults to `"invalid date"`.
+    pub invalid: String,
+    /// Formats a time in the past. Defaults to `"{n} ago"`.
+    pub past: TimeagoDirectionFn,
+    /// Formats a time in the future. Defaults to `"in {n}"`.
+    pub future: TimeagoDirectionFn,
+    /// Formats an amount of seconds
+    pub second: TimeagoUnitFn,
+    /// Formats an amount of minutes
+    pub minute: TimeagoUnitFn,
+    /// Formats an amount of hours
+    pub hour: TimeagoUnitFn,
+    /// Formats an amount of days
+    pub day: TimeagoUnitFn,
+    /// Formats an amount of weeks
+    pub week: TimeagoUnitFn,
+    /// Formats an amount of months
+    pub month: TimeagoUnitFn,
+    /// Formats an amount of years
+    pub year: TimeagoUnitFn,
+}
+
+fn english_unit(unit: &'static str) -> TimeagoUnitFn {
+    Arc::new(move |n| {
+        if n == 1 {
+            format!("{n} {unit}")
+        } else {
+            format!("{n} {unit}s")
+        }
+    })
+}
+
+impl Default for UseTimeagoMessages {
+    fn default() -> Self {
+        Self {
+            just_now: "just now".to_string(),
+            invalid: "invalid date".to_string(),
+            past: Arc::new(|n| format!("{n} ago")),
+            future: Arc::new(|n| format!("in {n}")),
+            second: english_unit("second"),
+            minute: english_unit("minute"),
+            hour: english_unit("hour"),
+            day: english_unit("day"),
+            week: english_unit("week"),
+            month: english_unit("month"),
+            year: english_unit("year"),
+        }
+    }
+}
@@ -0,0 +1,182 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::{use_mutation_observer_with_options, UseMutationObserverOptions};
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+
+/// Reactive document title.
+///
+/// Leptos' `<Title>` component is the way to go if you use `leptos_meta`. This function is
+/// convenient for dynamic titles set from within any component or for apps without a router.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_title;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (title, set_title) = use_title();
+///
+/// set_title.set("New Title".to_string());
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// You can provide an initial title and a template. Every occurrence of `%s` in the template is
+/// replaced by the title. The returned `title` signal always contains the title without the template.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_title_with_options, UseTitleOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (title, set_title) = use_title_with_options(
+///     UseTitleOptions::default()
+///         .initial("Home")
+///         .template("%s | My App"),
+/// );
+///
+/// // document.title == "Home | My App"
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Observe external changes
+///
+/// With the option `observe` a [`MutationObserver`](https://developer.mozilla.org/en-US/docs/Web/API/MutationObserver)
+/// watches the `<title>` element so changes made outside of this function are reflected in `title`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_title_with_options, UseTitleOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (title, set_title) = use_title_with_options(
+///     UseTitleOptions::default()
+///         .observe(true)
+///         .restore_on_cleanup(true),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `title` contains the initial title (or an empty string) and setting it has no
+/// effect on any document. Use `leptos_meta`'s `<Title>` to render the title on the server.
+pub fn use_title() -> (Signal<String>, WriteSignal<String>) {
+    use_title_with_options(UseTitleOptions::default())
+}
+
+/// Version of [`use_title`] that takes a `UseTitleOptions`. See [`use_title`] for how to use.
+pub fn use_title_with_options(options: UseTitleOptions) -> (Signal<String>, WriteSignal<String>) {
+    let UseTitleOptions {
+        initial,
+        template,
+        observe,
+        restore_on_cleanup,
+    } = options;
+
+    #[cfg(feature = "ssr")]
+    let (title, set_title) = signal(initial.unwrap_or_default());
+
+    #[cfg(not(feature = "ssr"))]
+    let (title, set_title) = {
+        let original_title = document().title();
+
+        let (title, set_title) = signal(initial.unwrap_or_else(|| original_title.clone()));
+
+        let format = {
+            let template = template.clone();
+
+            move |title: &str| match &template {
+                Some(template) => template.replace("%s", title),
+                None => title.to_string(),
+            }
+        };
+
+        Effect::watch(
+            move || title.get(),
+            {
+                let format = format.clone();
+
+                move |title: &String, _, _| {
+                    let formatted = format(title);
+
+                    if document().title() != formatted {
+                        document().set_title(&formatted);
+                    }
+                }
+            },
+            true,
+        );
+
+        if observe {
+            let _ = use_mutation_observer_with_options::<_, Option<web_sys::Element>, _>(
+                document().head().map(web_sys::Element::from),
+                move |_, _| {
+                    let document_title = document().title();
+
+                    if title.with_untracked(|title| format(title) != document_title) {
+                        set_title.set(strip_template(template.as_deref(), document_title));
+                    }
+                },
+                UseMutationObserverOptions::default()
+                    .child_list(true)
+                    .subtree(true)
+                    .character_data(true),
+            );
+        }
+
+        if restore_on_cleanup {
+            on_cleanup(move || document().set_title(&original_title));
+        }
+
+        (title, set_title)
+    };
+
+    (title.into(), set_title)
+}
+
+/// Removes the parts of the template around `%s` from `title` if it matches the template.
+#[cfg(not(feature = "ssr"))]
+fn strip_template(template: Option<&str>, title: String) -> String {
+    if let Some((prefix, suffix)) = template.and_then(|template| template.split_once("%s")) {
+        if title.len() >= prefix.len() + suffix.len()
+            && title.starts_with(prefix)
+            && title.ends_with(suffix)
+        {
+            return title[prefix.len()..title.len() - suffix.len()].to_string();
+        }
+    }
+
+    title
+}
+
+/// Options for [`use_title_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseTitleOptions {
+    /// Initial title. If `None` the current `document.title` is used. Defaults to `None`.
+    #[builder(into)]
+    initial: Option<String>,
+
+    /// Template for the document title. Every `%s` is replaced by the title, for example
+    /// `"%s | My App"`. Defaults to `None`.
+    #[builder(into)]
+    template: Option<String>,
+
+    /// Observe changes of the `<title>` element that are made outside of this function and
+    /// update the returned title accordingly. Defaults to `false`.
+    observe: bool,
+
+    /// Restore the original document title when the calling component is cleaned up.
+    /// Defaults to `false`.
+    restore_on_cleanup: bool,
+}
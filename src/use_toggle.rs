@@ -24,6 +24,7 @@ use leptos::prelude::*;
 /// ## See also
 ///
 /// * [`fn@crate::use_cycle_list`]
+/// * [`fn@crate::use_toggle_persisted`]
 // #[doc(cfg(feature = "use_toggle"))]
 pub fn use_toggle(
     initial_value: impl Into<MaybeRwSignal<bool>>,
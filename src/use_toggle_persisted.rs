@@ -0,0 +1,143 @@
+use crate::core::MaybeRwSignal;
+use crate::storage::{use_storage_with_options, StorageType, UseStorageOptions};
+use crate::{
+    sync_signal_with_options, use_url_search_params, SyncSignalOptions, UrlParams,
+    UrlSearchParamsMode, UseToggleReturn,
+};
+use codee::string::FromToStringCodec;
+use leptos::prelude::*;
+
+/// Like [`fn@crate::use_toggle`] but the value is persisted.
+///
+/// The value can be kept in storage so it survives reloads, or in a URL search parameter so it's
+/// also shareable through links. The given initial value is only used if nothing has been
+/// persisted yet.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_toggle_persisted, TogglePersistence, UseToggleReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// // persisted in local storage under the key "sidebar-open"
+/// let UseToggleReturn { toggle, value, .. } =
+///     use_toggle_persisted(true, TogglePersistence::local_storage("sidebar-open"));
+///
+/// // persisted in the URL like `/path?advanced=true`
+/// let UseToggleReturn { value: advanced, .. } =
+///     use_toggle_persisted(false, TogglePersistence::url_param("advanced"));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server there is no storage and no URL to read from, so the value always starts with the
+/// given initial value.
+///
+/// ## See also
+///
+/// * [`fn@crate::use_toggle`]
+/// * [`fn@crate::storage::use_storage`]
+/// * [`fn@crate::use_url_search_params`]
+pub fn use_toggle_persisted(
+    initial_value: impl Into<MaybeRwSignal<bool>>,
+    persistence: TogglePersistence,
+) -> UseToggleReturn<impl Fn() + Clone + Send + Sync + 'static> {
+    let initial_value = initial_value.into();
+
+    let (value, set_value) = match persistence {
+        TogglePersistence::Storage { storage, key } => {
+            let (value, set_value, _) = use_storage_with_options::<bool, FromToStringCodec>(
+                storage,
+                key,
+                UseStorageOptions::default().initial_value(initial_value),
+            );
+            (value, set_value)
+        }
+        TogglePersistence::UrlParam { mode, name } => {
+            use_url_param_signal(initial_value, mode, name)
+        }
+    };
+
+    let toggle = move || {
+        set_value.update(|v| *v = !*v);
+    };
+
+    UseToggleReturn {
+        toggle,
+        value,
+        set_value,
+    }
+}
+
+/// Syncs the value with the URL search parameter `name`. The parameter wins if present.
+fn use_url_param_signal(
+    initial_value: MaybeRwSignal<bool>,
+    mode: UrlSearchParamsMode,
+    name: String,
+) -> (Signal<bool>, WriteSignal<bool>) {
+    let (value, set_value) = initial_value.into_signal();
+    let (params, set_params) = use_url_search_params(mode);
+
+    let parse = {
+        let name = name.clone();
+        move |params: &UrlParams| params.get(&name).and_then(|v| v.parse().ok())
+    };
+
+    if let Some(url_value) = params.with_untracked(&parse) {
+        set_value.set(url_value);
+    }
+
+    let _ = sync_signal_with_options(
+        (params, set_params),
+        (value, set_value),
+        SyncSignalOptions::with_assigns(
+            move |value: &mut bool, params: &UrlParams| {
+                if let Some(url_value) = parse(params) {
+                    *value = url_value;
+                }
+            },
+            move |params: &mut UrlParams, value: &bool| {
+                params.insert(name.clone(), value.to_string());
+            },
+        )
+        .immediate(false),
+    );
+
+    (value, set_value)
+}
+
+/// Where the value of [`use_toggle_persisted`] is persisted.
+pub enum TogglePersistence {
+    /// The value is persisted in the given storage under `key`.
+    Storage { storage: StorageType, key: String },
+
+    /// The value is persisted in the URL search parameter `name` as `true` or `false`.
+    UrlParam {
+        mode: UrlSearchParamsMode,
+        name: String,
+    },
+}
+
+impl TogglePersistence {
+    /// Persist the value in local storage under the given key.
+    pub fn local_storage(key: impl Into<String>) -> Self {
+        Self::Storage {
+            storage: StorageType::Local,
+            key: key.into(),
+        }
+    }
+
+    /// Persist the value in the normal query string of the URL (`UrlSearchParamsMode::History`)
+    /// under the given parameter name.
+    pub fn url_param(name: impl Into<String>) -> Self {
+        Self::UrlParam {
+            mode: UrlSearchParamsMode::History,
+            name: name.into(),
+        }
+    }
+}
@@ -0,0 +1,164 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::core::IntoElementMaybeSignal;
+use crate::{use_event_listener_with_options, use_window, UseEventListenerOptions, UseWindow};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::{touchcancel, touchend, touchmove, touchstart};
+use leptos::prelude::*;
+use std::marker::PhantomData;
+
+/// Reactive list of all active touch points.
+///
+/// In contrast to [`fn@crate::use_mouse`] which only tracks a single coordinate, this tracks every
+/// finger that currently touches the screen. This is the basis for gesture detection or for
+/// visualizing touch points.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_touches, TouchPoint};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let touches = use_touches();
+///
+/// view! {
+///     <For
+///         each=move || touches.get()
+///         key=|touch| touch.identifier
+///         let:touch
+///     >
+///         <div
+///             class="touch-indicator"
+///             style:left=format!("{}px", touch.x)
+///             style:top=format!("{}px", touch.y)
+///             style:width=format!("{}px", touch.radius_x * 2.0)
+///             style:height=format!("{}px", touch.radius_y * 2.0)
+///         />
+///     </For>
+/// }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server this returns a `Signal` that always contains an empty `Vec`.
+pub fn use_touches() -> Signal<Vec<TouchPoint>> {
+    use_touches_with_options(UseTouchesOptions::default())
+}
+
+/// Version of [`use_touches`] that takes a `UseTouchesOptions`. See [`use_touches`] for how to use.
+pub fn use_touches_with_options<El, M>(options: UseTouchesOptions<El, M>) -> Signal<Vec<TouchPoint>>
+where
+    El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+{
+    let UseTouchesOptions {
+        target, coord_type, ..
+    } = options;
+
+    let (touches, set_touches) = signal(Vec::<TouchPoint>::new());
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let handler = move |event: web_sys::TouchEvent| {
+            let list = event.touches();
+
+            set_touches.set(
+                (0..list.length())
+                    .filter_map(|i| list.get(i))
+                    .map(|touch| TouchPoint::from_touch(&touch, coord_type))
+                    .collect(),
+            );
+        };
+
+        let target = target.into_element_maybe_signal();
+        let listener_options = UseEventListenerOptions::default().passive(true);
+
+        let _ = use_event_listener_with_options(target, touchstart, handler, listener_options);
+        let _ = use_event_listener_with_options(target, touchmove, handler, listener_options);
+        let _ = use_event_listener_with_options(target, touchend, handler, listener_options);
+        let _ = use_event_listener_with_options(target, touchcancel, handler, listener_options);
+    }
+
+    touches.into()
+}
+
+/// Options for [`use_touches_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseTouchesOptions<El, M>
+where
+    El: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+{
+    /// Listen to touch events on `target`. Defaults to `window`
+    target: El,
+
+    /// Which coordinates of the touches should be used for `x` and `y`.
+    /// Defaults to `UseTouchesCoordType::Page`.
+    coord_type: UseTouchesCoordType,
+
+    #[builder(skip)]
+    _marker: PhantomData<M>,
+}
+
+impl<M> Default for UseTouchesOptions<UseWindow, M>
+where
+    UseWindow: IntoElementMaybeSignal<web_sys::EventTarget, M>,
+{
+    fn default() -> Self {
+        Self {
+            target: use_window(),
+            coord_type: UseTouchesCoordType::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Defines which coordinates are read from a touch.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UseTouchesCoordType {
+    /// Relative to the whole document (`pageX` / `pageY`)
+    #[default]
+    Page,
+    /// Relative to the viewport (`clientX` / `clientY`)
+    Client,
+    /// Relative to the screen (`screenX` / `screenY`)
+    Screen,
+}
+
+/// A single active touch point as returned by [`use_touches`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TouchPoint {
+    /// Unique identifier of this touch for as long as the finger stays on the surface.
+    pub identifier: i32,
+    /// Horizontal position in the coordinate system defined by `coord_type`.
+    pub x: f64,
+    /// Vertical position in the coordinate system defined by `coord_type`.
+    pub y: f64,
+    /// Horizontal radius of the ellipse that most closely circumscribes the touch area.
+    pub radius_x: f64,
+    /// Vertical radius of the ellipse that most closely circumscribes the touch area.
+    pub radius_y: f64,
+    /// Pressure between `0.0` and `1.0`. `0.0` if the device doesn't support it.
+    pub force: f64,
+}
+
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+impl TouchPoint {
+    fn from_touch(touch: &web_sys::Touch, coord_type: UseTouchesCoordType) -> Self {
+        let (x, y) = match coord_type {
+            UseTouchesCoordType::Page => (touch.page_x(), touch.page_y()),
+            UseTouchesCoordType::Client => (touch.client_x(), touch.client_y()),
+            UseTouchesCoordType::Screen => (touch.screen_x(), touch.screen_y()),
+        };
+
+        Self {
+            identifier: touch.identifier(),
+            x: x as f64,
+            y: y as f64,
+            radius_x: touch.radius_x() as f64,
+            radius_y: touch.radius_y() as f64,
+            force: touch.force() as f64,
+        }
+    }
+}
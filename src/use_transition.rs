@@ -0,0 +1,407 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use default_struct_builder::DefaultBuilder;
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Transition between values.
+///
+/// The returned signal follows `source` but instead of jumping to a new value it animates towards
+/// it over the given `duration` using a `requestAnimationFrame` loop. If `source` changes while a
+/// transition is running, the transition is retargeted and starts from the current output value.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_transition_with_options, TransitionEasing, UseTransitionOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (source, set_source) = signal(0.0);
+///
+/// let output: Signal<f64> = use_transition_with_options(
+///     source,
+///     UseTransitionOptions::default()
+///         .duration(1000.0)
+///         .easing(TransitionEasing::EaseInOutCubic),
+/// );
+///
+/// view! {
+///     <p>{move || format!("{:.0}", output.get())}</p>
+///     <button on:click=move |_| set_source.update(|value| *value += 100.0)>"+100"</button>
+/// }
+/// # }
+/// ```
+///
+/// ### Easing
+///
+/// Besides the presets in [`TransitionEasing`] you can use any cubic bézier curve defined by its
+/// two control points, like in CSS, or provide a custom easing function that maps the progress
+/// `0.0..=1.0` to the eased progress.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_transition_with_options, TransitionEasing, UseTransitionOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let (source, set_source) = signal(0.0);
+/// let bezier: Signal<f64> = use_transition_with_options(
+///     source,
+///     UseTransitionOptions::default().easing(TransitionEasing::CubicBezier(0.75, 0.0, 0.25, 1.0)),
+/// );
+///
+/// let custom: Signal<f64> = use_transition_with_options(
+///     source,
+///     UseTransitionOptions::default().easing(TransitionEasing::custom(|t| t * t)),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Tuples, Arrays and Vectors
+///
+/// Tuples like `(f64, f64)`, fixed size arrays like `[f64; 3]` and `Vec<f64>` are transitioned
+/// component-wise with the same easing and duration. This is useful for colors or positions. Together with [`fn@crate::use_css_var`] you
+/// get a smoothly transitioning CSS variable.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_css_var, use_transition};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (color, set_color) = signal([255.0, 0.0, 0.0]);
+///
+/// let transitioned = use_transition(color);
+///
+/// let (_, set_css_color) = use_css_var("--color");
+///
+/// Effect::new(move || {
+///     let [r, g, b] = transitioned.get();
+///     set_css_color.set(format!("rgb({r:.0}, {g:.0}, {b:.0})"));
+/// });
+///
+/// view! {
+///     <button on:click=move |_| set_color.set([0.0, 0.0, 255.0])>"Blue"</button>
+/// }
+/// # }
+/// ```
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_transition;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (position, set_position) = signal((0.0, 0.0));
+/// let position: Signal<(f64, f64)> = use_transition(position);
+///
+/// let (bars, set_bars) = signal(vec![10.0, 20.0, 30.0]);
+/// let bars: Signal<Vec<f64>> = use_transition(bars);
+///
+/// view! {
+///     <div style:transform=move || {
+///         let (x, y) = position.get();
+///         format!("translate({x}px, {y}px)")
+///     }></div>
+///     <button on:click=move |_| set_position.set((100.0, 50.0))>"Move"</button>
+///     <button on:click=move |_| set_bars.set(vec![30.0, 20.0, 10.0, 40.0])>"Update"</button>
+/// }
+/// # }
+/// ```
+///
+/// If the length of a `Vec` changes, see [`TransitionValue`] for how the values are interpolated.
+///
+/// To transition your own types implement [`TransitionValue`].
+///
+/// ## Server-Side Rendering
+///
+/// On the server there is no animation. The returned signal is always equal to `source`.
+pub fn use_transition<T>(source: impl Into<Signal<T>>) -> Signal<T>
+where
+    T: TransitionValue,
+{
+    use_transition_with_options(source, UseTransitionOptions::default())
+}
+
+/// Version of [`use_transition`] that takes a `UseTransitionOptions`. See [`use_transition`] for how to use.
+pub fn use_transition_with_options<T>(
+    source: impl Into<Signal<T>>,
+    options: UseTransitionOptions,
+) -> Signal<T>
+where
+    T: TransitionValue,
+{
+    let source = source.into();
+
+    #[cfg(feature = "ssr")]
+    {
+        source
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::utils::Pausable;
+        use crate::{use_raf_fn_with_options, UseRafFnOptions};
+
+        let UseTransitionOptions { duration, easing } = options;
+
+        let (output, set_output) = signal(source.get_untracked());
+
+        let from = StoredValue::new(source.get_untracked());
+        let to = StoredValue::new(source.get_untracked());
+        let started_at = StoredValue::new(None::<f64>);
+        let pause_ref = StoredValue::new(None::<Arc<dyn Fn() + Send + Sync>>);
+
+        let Pausable { pause, resume, .. } = use_raf_fn_with_options(
+            move |args| {
+                let started_at = started_at
+                    .try_update_value(|started_at| *started_at.get_or_insert(args.timestamp))
+                    .unwrap_or(args.timestamp);
+
+                let progress = ((args.timestamp - started_at) / duration).clamp(0.0, 1.0);
+
+                let from = from.get_value();
+                let to = to.get_value();
+                set_output.set(T::interpolate(&from, &to, easing.apply(progress)));
+
+                if progress >= 1.0 {
+                    if let Some(pause) = pause_ref.get_value() {
+                        pause();
+                    }
+                }
+            },
+            UseRafFnOptions::default().immediate(false),
+        );
+
+        pause_ref.set_value(Some(Arc::new(pause)));
+
+        Effect::watch(
+            move || source.get(),
+            move |value, _, _| {
+                if duration <= 0.0 {
+                    set_output.set(value.clone());
+                    return;
+                }
+
+                from.set_value(output.get_untracked());
+                to.set_value(value.clone());
+                started_at.set_value(None);
+
+                resume();
+            },
+            false,
+        );
+
+        output.into()
+    }
+}
+
+/// Options for [`use_transition_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseTransitionOptions {
+    /// Duration of the transition in milliseconds. Defaults to `1000.0`.
+    duration: f64,
+
+    /// Easing function of the transition. Defaults to [`TransitionEasing::Linear`].
+    #[builder(into)]
+    easing: TransitionEasing,
+}
+
+impl Default for UseTransitionOptions {
+    fn default() -> Self {
+        Self {
+            duration: 1000.0,
+            easing: TransitionEasing::Linear,
+        }
+    }
+}
+
+/// Values that can be transitioned by [`use_transition`].
+pub trait TransitionValue: Clone + Send + Sync + 'static {
+    /// Interpolates between `from` and `to`. `progress` is usually in the range `0.0..=1.0` but
+    /// can be outside of it for easings that overshoot like [`TransitionEasing::EaseInOutBack`].
+    fn interpolate(from: &Self, to: &Self, progress: f64) -> Self;
+}
+
+impl TransitionValue for f64 {
+    fn interpolate(from: &Self, to: &Self, progress: f64) -> Self {
+        from + (to - from) * progress
+    }
+}
+
+impl<const N: usize> TransitionValue for [f64; N] {
+    fn interpolate(from: &Self, to: &Self, progress: f64) -> Self {
+        std::array::from_fn(|i| f64::interpolate(&from[i], &to[i], progress))
+    }
+}
+
+impl TransitionValue for (f64, f64) {
+    fn interpolate(from: &Self, to: &Self, progress: f64) -> Self {
+        (
+            f64::interpolate(&from.0, &to.0, progress),
+            f64::interpolate(&from.1, &to.1, progress),
+        )
+    }
+}
+
+impl TransitionValue for (f64, f64, f64) {
+    fn interpolate(from: &Self, to: &Self, progress: f64) -> Self {
+        (
+            f64::interpolate(&from.0, &to.0, progress),
+            f64::interpolate(&from.1, &to.1, progress),
+            f64::interpolate(&from.2, &to.2, progress),
+        )
+    }
+}
+
+/// The result always has the length of `to`. Elements that are missing in `from` are set to the
+/// value in `to` right away and elements that are missing in `to` are dropped right away.
+///
+/// ```
+/// # use leptos_use::TransitionValue;
+/// #
+/// let from = vec![0.0, 10.0];
+/// let to = vec![10.0, 20.0, 30.0];
+///
+/// assert_eq!(Vec::interpolate(&from, &to, 0.5), vec![5.0, 15.0, 30.0]);
+/// assert_eq!(Vec::interpolate(&to, &from, 0.5), vec![5.0, 15.0]);
+/// ```
+impl TransitionValue for Vec<f64> {
+    fn interpolate(from: &Self, to: &Self, progress: f64) -> Self {
+        to.iter()
+            .enumerate()
+            .map(|(i, to)| match from.get(i) {
+                Some(from) => f64::interpolate(from, to, progress),
+                None => *to,
+            })
+            .collect()
+    }
+}
+
+/// Easing functions for [`use_transition`]. The presets correspond to the ones of
+/// [easings.net](https://easings.net/).
+#[derive(Clone, Default)]
+pub enum TransitionEasing {
+    #[default]
+    Linear,
+    Ease,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    EaseInSine,
+    EaseOutSine,
+    EaseInOutSine,
+    EaseInQuad,
+    EaseOutQuad,
+    EaseInOutQuad,
+    EaseInCubic,
+    EaseOutCubic,
+    EaseInOutCubic,
+    EaseInQuart,
+    EaseOutQuart,
+    EaseInOutQuart,
+    EaseInQuint,
+    EaseOutQuint,
+    EaseInOutQuint,
+    EaseInExpo,
+    EaseOutExpo,
+    EaseInOutExpo,
+    EaseInCirc,
+    EaseOutCirc,
+    EaseInOutCirc,
+    EaseInBack,
+    EaseOutBack,
+    EaseInOutBack,
+
+    /// Cubic bézier curve given by the control points `(x1, y1, x2, y2)` just like
+    /// the CSS function `cubic-bezier()`.
+    CubicBezier(f64, f64, f64, f64),
+
+    /// Custom easing function that maps the linear progress `0.0..=1.0` to the eased progress.
+    Custom(Arc<dyn Fn(f64) -> f64 + Send + Sync>),
+}
+
+impl TransitionEasing {
+    /// Creates a [`TransitionEasing::Custom`] from the given function.
+    pub fn custom(easing: impl Fn(f64) -> f64 + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(easing))
+    }
+
+    /// Applies the easing to the linear progress `t` which is in the range `0.0..=1.0`.
+    pub fn apply(&self, t: f64) -> f64 {
+        let (x1, y1, x2, y2) = match self {
+            Self::Linear => return t,
+            Self::Custom(easing) => return easing(t),
+            Self::CubicBezier(x1, y1, x2, y2) => (*x1, *y1, *x2, *y2),
+            Self::Ease => (0.25, 0.1, 0.25, 1.0),
+            Self::EaseIn => (0.42, 0.0, 1.0, 1.0),
+            Self::EaseOut => (0.0, 0.0, 0.58, 1.0),
+            Self::EaseInOut => (0.42, 0.0, 0.58, 1.0),
+            Self::EaseInSine => (0.12, 0.0, 0.39, 0.0),
+            Self::EaseOutSine => (0.61, 1.0, 0.88, 1.0),
+            Self::EaseInOutSine => (0.37, 0.0, 0.63, 1.0),
+            Self::EaseInQuad => (0.11, 0.0, 0.5, 0.0),
+            Self::EaseOutQuad => (0.5, 1.0, 0.89, 1.0),
+            Self::EaseInOutQuad => (0.45, 0.0, 0.55, 1.0),
+            Self::EaseInCubic => (0.32, 0.0, 0.67, 0.0),
+            Self::EaseOutCubic => (0.33, 1.0, 0.68, 1.0),
+            Self::EaseInOutCubic => (0.65, 0.0, 0.35, 1.0),
+            Self::EaseInQuart => (0.5, 0.0, 0.75, 0.0),
+            Self::EaseOutQuart => (0.25, 1.0, 0.5, 1.0),
+            Self::EaseInOutQuart => (0.76, 0.0, 0.24, 1.0),
+            Self::EaseInQuint => (0.64, 0.0, 0.78, 0.0),
+            Self::EaseOutQuint => (0.22, 1.0, 0.36, 1.0),
+            Self::EaseInOutQuint => (0.83, 0.0, 0.17, 1.0),
+            Self::EaseInExpo => (0.7, 0.0, 0.84, 0.0),
+            Self::EaseOutExpo => (0.16, 1.0, 0.3, 1.0),
+            Self::EaseInOutExpo => (0.87, 0.0, 0.13, 1.0),
+            Self::EaseInCirc => (0.55, 0.0, 1.0, 0.45),
+            Self::EaseOutCirc => (0.0, 0.55, 0.45, 1.0),
+            Self::EaseInOutCirc => (0.85, 0.0, 0.15, 1.0),
+            Self::EaseInBack => (0.36, 0.0, 0.66, -0.56),
+            Self::EaseOutBack => (0.34, 1.56, 0.64, 1.0),
+            Self::EaseInOutBack => (0.68, -0.6, 0.32, 1.6),
+        };
+
+        cubic_bezier(t, x1, y1, x2, y2)
+    }
+}
+
+impl<F> From<F> for TransitionEasing
+where
+    F: Fn(f64) -> f64 + Send + Sync + 'static,
+{
+    fn from(easing: F) -> Self {
+        Self::custom(easing)
+    }
+}
+
+/// Evaluates the cubic bézier curve with the control points `(x1, y1)` and `(x2, y2)` at `x`.
+fn cubic_bezier(x: f64, x1: f64, y1: f64, x2: f64, y2: f64) -> f64 {
+    if x1 == y1 && x2 == y2 {
+        return x;
+    }
+
+    let a = |a1: f64, a2: f64| 1.0 - 3.0 * a2 + 3.0 * a1;
+    let b = |a1: f64, a2: f64| 3.0 * a2 - 6.0 * a1;
+    let c = |a1: f64| 3.0 * a1;
+
+    let calc_bezier = |t: f64, a1: f64, a2: f64| ((a(a1, a2) * t + b(a1, a2)) * t + c(a1)) * t;
+    let slope = |t: f64, a1: f64, a2: f64| 3.0 * a(a1, a2) * t * t + 2.0 * b(a1, a2) * t + c(a1);
+
+    // Find `t` for `x` with Newton-Raphson iteration
+    let mut t = x;
+    for _ in 0..4 {
+        let current_slope = slope(t, x1, x2);
+        if current_slope == 0.0 {
+            break;
+        }
+        t -= (calc_bezier(t, x1, x2) - x) / current_slope;
+    }
+
+    calc_bezier(t, y1, y2)
+}
@@ -0,0 +1,369 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{use_event_listener, use_window};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::{hashchange, popstate};
+use leptos::prelude::*;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Reactive [URLSearchParams](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams).
+///
+/// Reads and writes the query parameters of the current url without navigating. Changes are
+/// written with the History API and changes caused by the browser's back and forward buttons are
+/// picked up by listening to `popstate` and `hashchange`. After writing, a `popstate` event is
+/// dispatched on the window so routers and other listeners pick up the new url as well.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_url_search_params, UrlSearchParamsMode};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (params, set_params) = use_url_search_params(UrlSearchParamsMode::History);
+///
+/// let filter = move || params.with(|params| params.get("filter").cloned().unwrap_or_default());
+///
+/// view! {
+///     <input
+///         prop:value=filter
+///         on:input=move |evt| {
+///             set_params.update(|params| {
+///                 params.insert("filter".to_string(), event_target_value(&evt));
+///             });
+///         }
+///     />
+/// }
+/// # }
+/// ```
+///
+/// ### Modes
+///
+/// - `UrlSearchParamsMode::History` uses the normal query string like in `/path?filter=abc`.
+/// - `UrlSearchParamsMode::Hash` uses a query string inside the hash like in `/#/path?filter=abc`.
+///   This is what hash based routers do.
+/// - `UrlSearchParamsMode::HashParams` uses the whole hash as query string like in `/#filter=abc`.
+///
+/// ### Push instead of replace
+///
+/// By default the current history entry is replaced. To create a new history entry for every change
+/// set the option `write_mode` to `UrlSearchParamsWriteMode::Push`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_url_search_params_with_options, UrlSearchParamsMode, UrlSearchParamsWriteMode, UseUrlSearchParamsOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (params, set_params) = use_url_search_params_with_options(
+///     UrlSearchParamsMode::History,
+///     UseUrlSearchParamsOptions::default().write_mode(UrlSearchParamsWriteMode::Push),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// A key can appear multiple times like in `?tag=a&tag=b`. Use [`UrlParams::get_all`] and
+/// [`UrlParams::append`] for such keys.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_url_search_params, UrlSearchParamsMode};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (params, set_params) = use_url_search_params(UrlSearchParamsMode::History);
+///
+/// let tags = move || params.with(|params| params.get_all("tag").join(", "));
+///
+/// view! {
+///     <p>"Tags: " {tags}</p>
+///     <button on:click=move |_| set_params.update(|params| params.append("tag", "new"))>
+///         "Add tag"
+///     </button>
+/// }
+/// # }
+/// ```
+///
+/// Parameters with empty values are removed from the url. The parameters are written sorted by
+/// key so the url doesn't change when the parameters don't. Values of the same key keep their
+/// order.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the params are always empty and setting them has no effect.
+#[allow(clippy::type_complexity)]
+pub fn use_url_search_params(
+    mode: UrlSearchParamsMode,
+) -> (Signal<UrlParams>, WriteSignal<UrlParams>) {
+    use_url_search_params_with_options(mode, UseUrlSearchParamsOptions::default())
+}
+
+/// Version of [`use_url_search_params`] that takes a `UseUrlSearchParamsOptions`. See [`use_url_search_params`] for how to use.
+#[allow(clippy::type_complexity)]
+pub fn use_url_search_params_with_options(
+    mode: UrlSearchParamsMode,
+    options: UseUrlSearchParamsOptions,
+) -> (Signal<UrlParams>, WriteSignal<UrlParams>) {
+    let UseUrlSearchParamsOptions { write_mode } = options;
+
+    let (params, set_params) = signal(UrlParams::default());
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let read = move || {
+            let location = window().location();
+            let search = location.search().unwrap_or_default();
+            let hash = location.hash().unwrap_or_default();
+
+            let query = match mode {
+                UrlSearchParamsMode::History => search.as_str(),
+                UrlSearchParamsMode::Hash => hash.split_once('?').map_or("", |(_, query)| query),
+                UrlSearchParamsMode::HashParams => hash.trim_start_matches('#'),
+            };
+
+            parse_query(query)
+        };
+
+        let update_from_url = move || {
+            let url_params = read();
+
+            if params.with_untracked(|params| to_query(params) != to_query(&url_params)) {
+                set_params.set(url_params);
+            }
+        };
+
+        update_from_url();
+
+        Effect::watch(
+            move || params.get(),
+            move |params: &UrlParams, _, _| {
+                let query = to_query(params);
+
+                if query == to_query(&read()) {
+                    return;
+                }
+
+                let location = window().location();
+                let pathname = location.pathname().unwrap_or_default();
+                let search = location.search().unwrap_or_default();
+                let hash = location.hash().unwrap_or_default();
+
+                let prefixed = |separator: &str| {
+                    if query.is_empty() {
+                        String::new()
+                    } else {
+                        format!("{separator}{query}")
+                    }
+                };
+
+                let url = match mode {
+                    UrlSearchParamsMode::History => format!("{pathname}{}{hash}", prefixed("?")),
+                    UrlSearchParamsMode::Hash => {
+                        let hash_path =
+                            hash.split_once('?').map_or(hash.as_str(), |(path, _)| path);
+                        let hash_path = if hash_path.is_empty() { "#" } else { hash_path };
+
+                        format!("{pathname}{search}{hash_path}{}", prefixed("?"))
+                    }
+                    UrlSearchParamsMode::HashParams => {
+                        format!("{pathname}{search}{}", prefixed("#"))
+                    }
+                };
+
+                if let Ok(history) = window().history() {
+                    let result = match write_mode {
+                        UrlSearchParamsWriteMode::Replace => {
+                            history.replace_state_with_url(&JsValue::NULL, "", Some(&url))
+                        }
+                        UrlSearchParamsWriteMode::Push => {
+                            history.push_state_with_url(&JsValue::NULL, "", Some(&url))
+                        }
+                    };
+
+                    // The History API doesn't fire `popstate` by itself
+                    if result.is_ok() {
+                        if let Ok(event) = web_sys::PopStateEvent::new("popstate") {
+                            let _ = window().dispatch_event(&event);
+                        }
+                    }
+                }
+            },
+            false,
+        );
+
+        let _ = use_event_listener(use_window(), popstate, move |_| update_from_url());
+        let _ = use_event_listener(use_window(), hashchange, move |_| update_from_url());
+    }
+
+    (params.into(), set_params)
+}
+
+#[cfg(not(feature = "ssr"))]
+fn parse_query(query: &str) -> UrlParams {
+    let mut params = UrlParams::default();
+
+    if let Ok(search_params) = web_sys::UrlSearchParams::new_with_str(query) {
+        if let Ok(Some(entries)) = js_sys::try_iter(&search_params) {
+            for entry in entries.flatten() {
+                let entry = entry.unchecked_into::<js_sys::Array>();
+
+                if let (Some(key), Some(value)) =
+                    (entry.get(0).as_string(), entry.get(1).as_string())
+                {
+                    params.append(key, value);
+                }
+            }
+        }
+    }
+
+    params
+}
+
+#[cfg(not(feature = "ssr"))]
+fn to_query(params: &UrlParams) -> String {
+    let mut entries = params
+        .iter()
+        .filter(|(_, value)| !value.is_empty())
+        .collect::<Vec<_>>();
+    // stable so the values of the same key keep their order
+    entries.sort_by_key(|(key, _)| *key);
+
+    let search_params = web_sys::UrlSearchParams::new().expect("failed to create URLSearchParams");
+    for (key, value) in entries {
+        search_params.append(key, value);
+    }
+
+    search_params.to_string().into()
+}
+
+/// The params returned by [`use_url_search_params`] in the order they appear in the url.
+/// A key can appear multiple times.
+///
+/// ```
+/// # use leptos_use::UrlParams;
+/// #
+/// let mut params = UrlParams::from(vec![
+///     ("tag".to_string(), "a".to_string()),
+///     ("page".to_string(), "1".to_string()),
+///     ("tag".to_string(), "b".to_string()),
+/// ]);
+///
+/// assert_eq!(params.get("tag"), Some(&"a".to_string()));
+/// assert_eq!(params.get_all("tag"), vec!["a", "b"]);
+///
+/// params.insert("tag", "c");
+/// assert_eq!(params.get_all("tag"), vec!["c"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UrlParams(Vec<(String, String)>);
+
+impl UrlParams {
+    /// The first value of `key`.
+    pub fn get(&self, key: &str) -> Option<&String> {
+        self.0
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value)
+    }
+
+    /// All values of `key` in order.
+    pub fn get_all(&self, key: &str) -> Vec<&str> {
+        self.0
+            .iter()
+            .filter(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+            .collect()
+    }
+
+    /// Whether `key` is present.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.0.iter().any(|(k, _)| k == key)
+    }
+
+    /// Sets `key` to `value` replacing all previous values of `key`.
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        let key = key.into();
+        let value = value.into();
+
+        // keep the position of the first value and drop the others
+        let mut found = false;
+        self.0.retain_mut(|(k, v)| {
+            if k != &key {
+                true
+            } else if found {
+                false
+            } else {
+                found = true;
+                *v = value.clone();
+                true
+            }
+        });
+
+        if !found {
+            self.0.push((key, value));
+        }
+    }
+
+    /// Adds another value for `key` keeping the existing ones.
+    pub fn append(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.0.push((key.into(), value.into()));
+    }
+
+    /// Removes all values of `key`.
+    pub fn remove(&mut self, key: &str) {
+        self.0.retain(|(k, _)| k != key);
+    }
+
+    /// Iterates over all key-value pairs in order.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.0.iter().map(|(key, value)| (key, value))
+    }
+
+    /// Whether there are no params.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<Vec<(String, String)>> for UrlParams {
+    fn from(params: Vec<(String, String)>) -> Self {
+        Self(params)
+    }
+}
+
+impl From<UrlParams> for Vec<(String, String)> {
+    fn from(params: UrlParams) -> Self {
+        params.0
+    }
+}
+
+/// Where the params are stored in the url. See [`use_url_search_params`] for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlSearchParamsMode {
+    /// Normal query string like `/path?filter=abc`
+    History,
+    /// Query string inside the hash like `/#/path?filter=abc`
+    Hash,
+    /// The hash itself as query string like `/#filter=abc`
+    HashParams,
+}
+
+/// How changes are written to the browser history.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UrlSearchParamsWriteMode {
+    /// Replace the current history entry with `history.replaceState()`.
+    #[default]
+    Replace,
+    /// Create a new history entry with `history.pushState()`.
+    Push,
+}
+
+/// Options for [`use_url_search_params_with_options`].
+#[derive(DefaultBuilder, Default, Clone, Copy)]
+pub struct UseUrlSearchParamsOptions {
+    /// How changes are written to the browser history. Defaults to `UrlSearchParamsWriteMode::Replace`.
+    write_mode: UrlSearchParamsWriteMode,
+}
@@ -0,0 +1,572 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::IntoElementMaybeSignal;
+use crate::{
+    sendwrap_fn, use_element_size, use_event_listener_with_options, use_resize_observer,
+    UseElementSizeReturn, UseEventListenerOptions,
+};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::scroll;
+use leptos::prelude::*;
+use leptos::reactive::wrappers::read::Signal;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Renders only the visible part of a huge list.
+///
+/// Computes which items are visible in the scroll container `container` from its scroll position
+/// and the size of the items. Only these items (plus a few more, see option `overscan`) have to be
+/// rendered. The wrapper around the items is sized such that the container can be scrolled as if
+/// all items were rendered.
+///
+/// ## Usage
+///
+/// Put the `container_props.style` on the scroll container and the `wrapper_props.style` on a
+/// single wrapper element inside of it. The container needs a fixed height (or width if the list
+/// is horizontal) and every item has to be rendered with the `item_size` you provided.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_virtual_list, UseVirtualListReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let container = NodeRef::<Div>::new();
+/// let items = Signal::stored((0..100_000).map(|i| format!("Row {i}")).collect::<Vec<_>>());
+///
+/// let UseVirtualListReturn {
+///     visible_items,
+///     container_props,
+///     wrapper_props,
+///     ..
+/// } = use_virtual_list(container, items, 30.0);
+///
+/// view! {
+///     <div node_ref=container style=container_props.style style:height="300px">
+///         <div style=wrapper_props.style>
+///             <For each=move || visible_items.get() key=|item| item.index let:item>
+///                 <div style="height: 30px">{item.data}</div>
+///             </For>
+///         </div>
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// ### Different item sizes
+///
+/// If the items have different but known sizes you can provide a function that returns the size
+/// of the item at an index.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_virtual_list, UseVirtualListReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let container = NodeRef::<Div>::new();
+/// # let items = Signal::stored((0..1000).collect::<Vec<usize>>());
+/// #
+/// // every tenth item is a section header
+/// let UseVirtualListReturn { visible_items, .. } = use_virtual_list(
+///     container,
+///     items,
+///     |index: usize| if index % 10 == 0 { 50.0 } else { 30.0 },
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Measured item sizes
+///
+/// If the size of the items depends on their content, like chat messages, use
+/// `VirtualListItemSize::Measured` with an estimated size. Rendered items are measured with a
+/// `ResizeObserver` and the positions of all items are updated accordingly. Items that haven't
+/// been rendered yet are assumed to have the estimated size.
+///
+/// For this to work the items have to be the direct children of the wrapper element and each
+/// of them needs a `data-index` attribute with its index. Margins of the items are not measured
+/// so use padding instead.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_virtual_list, UseVirtualListReturn, VirtualListItemSize};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let container = NodeRef::<Div>::new();
+/// let (messages, set_messages) = signal(Vec::<String>::new());
+///
+/// let UseVirtualListReturn {
+///     visible_items,
+///     container_props,
+///     wrapper_props,
+///     scroll_to,
+/// } = use_virtual_list(container, messages, VirtualListItemSize::Measured(60.0));
+///
+/// view! {
+///     <div node_ref=container style=container_props.style style:height="500px">
+///         <div style=wrapper_props.style>
+///             <For each=move || visible_items.get() key=|item| item.index let:item>
+///                 <div data-index=item.index>{item.data}</div>
+///             </For>
+///         </div>
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// The sizes are stored by index. When the number of items changes, all measured sizes are
+/// discarded and the rendered items are measured again.
+///
+/// ### Scroll to an item
+///
+/// The returned `scroll_to` scrolls the container such that the item at the given index is at
+/// the top (or left). With measured item sizes the scroll position is corrected once more
+/// after the items around the target have been measured.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_virtual_list_with_options, UseVirtualListOptions, UseVirtualListReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let container = NodeRef::<Div>::new();
+/// # let items = Signal::stored((0..1000).collect::<Vec<usize>>());
+/// #
+/// let UseVirtualListReturn { scroll_to, .. } = use_virtual_list_with_options(
+///     container,
+///     items,
+///     100.0,
+///     UseVirtualListOptions::default().horizontal(true).overscan(2),
+/// );
+///
+/// view! {
+///     <button on:click=move |_| scroll_to(500)>"Go to 500"</button>
+/// }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closure `scroll_to` is a sendwrapped function. It can
+/// only be called from the same thread that called `use_virtual_list`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the size of the container is unknown so only the first `overscan + 1` items
+/// are returned in `visible_items`. `scroll_to` does nothing.
+pub fn use_virtual_list<T, El, M>(
+    container: El,
+    items: impl Into<Signal<Vec<T>>>,
+    item_size: impl Into<VirtualListItemSize>,
+) -> UseVirtualListReturn<T, impl Fn(usize) + Clone + Send + Sync>
+where
+    T: Clone + Send + Sync + 'static,
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    use_virtual_list_with_options(
+        container,
+        items,
+        item_size,
+        UseVirtualListOptions::default(),
+    )
+}
+
+/// Version of [`use_virtual_list`] that takes a `UseVirtualListOptions`. See [`use_virtual_list`] for how to use.
+pub fn use_virtual_list_with_options<T, El, M>(
+    container: El,
+    items: impl Into<Signal<Vec<T>>>,
+    item_size: impl Into<VirtualListItemSize>,
+    options: UseVirtualListOptions,
+) -> UseVirtualListReturn<T, impl Fn(usize) + Clone + Send + Sync>
+where
+    T: Clone + Send + Sync + 'static,
+    El: IntoElementMaybeSignal<web_sys::Element, M>,
+{
+    let UseVirtualListOptions {
+        overscan,
+        horizontal,
+    } = options;
+
+    let container = container.into_element_maybe_signal();
+    let items = items.into();
+    let item_size = item_size.into();
+
+    let (scroll_offset, set_scroll_offset) = signal(0.0);
+
+    let UseElementSizeReturn { width, height, .. } = use_element_size(container);
+    let viewport_size = Signal::derive(move || {
+        if horizontal {
+            width.get()
+        } else {
+            height.get()
+        }
+    });
+
+    let is_measured = matches!(item_size, VirtualListItemSize::Measured(_));
+    let measured_sizes = RwSignal::new(HashMap::<usize, f64>::new());
+
+    let layout = Memo::new(move |_| {
+        measured_sizes.with(|measured_sizes| {
+            VirtualListLayout::new(&item_size, items.with(|items| items.len()), measured_sizes)
+        })
+    });
+
+    let range = Memo::new(move |_| {
+        layout.with(|layout| {
+            let scroll_offset = scroll_offset.get();
+
+            let start = layout.index_at(scroll_offset).saturating_sub(overscan);
+            let end = (layout.index_at(scroll_offset + viewport_size.get()) + 1 + overscan)
+                .min(layout.len());
+
+            start..end.max(start)
+        })
+    });
+
+    let visible_items = Signal::derive(move || {
+        let range = range.get();
+
+        items.with(|items| {
+            items[range.start.min(items.len())..range.end.min(items.len())]
+                .iter()
+                .cloned()
+                .zip(range.start..)
+                .map(|(data, index)| VirtualListItem { index, data })
+                .collect()
+        })
+    });
+
+    let container_style = if horizontal {
+        "overflow-x: auto;"
+    } else {
+        "overflow-y: auto;"
+    };
+
+    let wrapper_style = Signal::derive(move || {
+        let start = range.get().start;
+
+        layout.with(|layout| {
+            let offset = layout.offset(start);
+            let size = layout.total_size() - offset;
+
+            if horizontal {
+                format!("height: 100%; width: {size}px; margin-left: {offset}px; display: flex;")
+            } else {
+                format!("width: 100%; height: {size}px; margin-top: {offset}px;")
+            }
+        })
+    });
+
+    let update_scroll_offset = move || {
+        if let Some(container) = container.get_untracked() {
+            set_scroll_offset.set(if horizontal {
+                container.scroll_left() as f64
+            } else {
+                container.scroll_top() as f64
+            });
+        }
+    };
+
+    let _ = use_event_listener_with_options(
+        container,
+        scroll,
+        move |_| update_scroll_offset(),
+        UseEventListenerOptions::default().passive(true),
+    );
+
+    let scroll_to_index = move |index: usize| {
+        if let Some(container) = container.get_untracked() {
+            let offset = layout.with_untracked(|layout| layout.offset(index)) as i32;
+
+            if horizontal {
+                container.set_scroll_left(offset);
+            } else {
+                container.set_scroll_top(offset);
+            }
+
+            update_scroll_offset();
+        }
+    };
+
+    // Index that `scroll_to` has to scroll to again after the next measurement
+    let pending_scroll_index = StoredValue::new(None::<usize>);
+
+    if is_measured {
+        // The sizes are stored by index so they are outdated once items are inserted or removed
+        Effect::watch(
+            move || items.with(|items| items.len()),
+            move |_, _, _| measured_sizes.update(|measured_sizes| measured_sizes.clear()),
+            false,
+        );
+
+        let (rendered_items, set_rendered_items) = signal_local(Vec::<web_sys::Element>::new());
+
+        // The items are rendered when effects run so they can be collected here
+        Effect::new(move |_| {
+            visible_items.track();
+
+            let items = container
+                .get_untracked()
+                .and_then(|container| container.first_element_child())
+                .map(|wrapper| {
+                    let children = wrapper.children();
+
+                    (0..children.length())
+                        .filter_map(|i| children.item(i))
+                        .filter(|child| child.has_attribute("data-index"))
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            set_rendered_items.set(items);
+        });
+
+        let _ = use_resize_observer(rendered_items, move |entries, _| {
+            let mut changed = false;
+
+            measured_sizes.update_untracked(|measured_sizes| {
+                for entry in entries {
+                    let target = entry.target();
+
+                    let Some(index) = target
+                        .get_attribute("data-index")
+                        .and_then(|index| index.parse::<usize>().ok())
+                    else {
+                        continue;
+                    };
+
+                    let rect = target.get_bounding_client_rect();
+                    let size = if horizontal {
+                        rect.width()
+                    } else {
+                        rect.height()
+                    };
+
+                    let previous = measured_sizes.insert(index, size);
+
+                    if !matches!(previous, Some(previous) if (previous - size).abs() <= 0.5) {
+                        changed = true;
+                    }
+                }
+            });
+
+            if changed {
+                measured_sizes.notify();
+
+                if let Some(index) = pending_scroll_index
+                    .try_update_value(Option::take)
+                    .flatten()
+                {
+                    scroll_to_index(index);
+                }
+            }
+        });
+    }
+
+    let scroll_to = sendwrap_fn!(move |index: usize| {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if is_measured {
+                pending_scroll_index.set_value(Some(index));
+            }
+
+            scroll_to_index(index);
+        }
+    });
+
+    UseVirtualListReturn {
+        visible_items,
+        container_props: UseVirtualListContainerProps {
+            style: container_style,
+        },
+        wrapper_props: UseVirtualListWrapperProps {
+            style: wrapper_style,
+        },
+        scroll_to,
+    }
+}
+
+/// Size of the items of [`use_virtual_list`] in pixels. This is the height of the items or
+/// their width if the list is horizontal.
+#[derive(Clone)]
+pub enum VirtualListItemSize {
+    /// All items have the same size.
+    Fixed(f64),
+
+    /// The size of every item is computed from its index.
+    Variable(Arc<dyn Fn(usize) -> f64 + Send + Sync>),
+
+    /// The items are measured after they have been rendered. The value is the estimated size
+    /// of items that haven't been measured yet.
+    Measured(f64),
+}
+
+impl From<f64> for VirtualListItemSize {
+    fn from(size: f64) -> Self {
+        Self::Fixed(size)
+    }
+}
+
+impl<F> From<F> for VirtualListItemSize
+where
+    F: Fn(usize) -> f64 + Send + Sync + 'static,
+{
+    fn from(size: F) -> Self {
+        Self::Variable(Arc::new(size))
+    }
+}
+
+/// Positions of the items along the scroll axis.
+#[derive(Clone, PartialEq)]
+enum VirtualListLayout {
+    Fixed {
+        size: f64,
+        len: usize,
+    },
+
+    /// Prefix sums of the item sizes. The first entry is `0.0` and the last one is the total size.
+    Variable {
+        offsets: Vec<f64>,
+    },
+}
+
+impl VirtualListLayout {
+    fn new(
+        item_size: &VirtualListItemSize,
+        len: usize,
+        measured_sizes: &HashMap<usize, f64>,
+    ) -> Self {
+        match item_size {
+            VirtualListItemSize::Fixed(size) => Self::Fixed { size: *size, len },
+            VirtualListItemSize::Variable(item_size) => {
+                Self::from_sizes(len, |index| item_size(index))
+            }
+            VirtualListItemSize::Measured(estimated_size) => Self::from_sizes(len, |index| {
+                measured_sizes
+                    .get(&index)
+                    .copied()
+                    .unwrap_or(*estimated_size)
+            }),
+        }
+    }
+
+    fn from_sizes(len: usize, item_size: impl Fn(usize) -> f64) -> Self {
+        let mut offsets = Vec::with_capacity(len + 1);
+        let mut offset = 0.0;
+        offsets.push(offset);
+
+        for index in 0..len {
+            offset += item_size(index);
+            offsets.push(offset);
+        }
+
+        Self::Variable { offsets }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Fixed { len, .. } => *len,
+            Self::Variable { offsets } => offsets.len() - 1,
+        }
+    }
+
+    fn total_size(&self) -> f64 {
+        self.offset(self.len())
+    }
+
+    /// Start of the item at `index`. Indices past the end are clamped.
+    fn offset(&self, index: usize) -> f64 {
+        let index = index.min(self.len());
+
+        match self {
+            Self::Fixed { size, .. } => index as f64 * size,
+            Self::Variable { offsets } => offsets[index],
+        }
+    }
+
+    /// Index of the item that contains `offset`. Clamped to the last item.
+    fn index_at(&self, offset: f64) -> usize {
+        let last_index = self.len().saturating_sub(1);
+
+        let index = match self {
+            Self::Fixed { size, .. } if *size > 0.0 => (offset.max(0.0) / size) as usize,
+            Self::Fixed { .. } => 0,
+            Self::Variable { offsets } => offsets
+                .partition_point(|item_offset| *item_offset <= offset)
+                .saturating_sub(1),
+        };
+
+        index.min(last_index)
+    }
+}
+
+/// Options for [`use_virtual_list_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseVirtualListOptions {
+    /// Number of items that are rendered before and after the visible ones so that scrolling
+    /// doesn't show empty space before the next render. Defaults to `5`.
+    overscan: usize,
+
+    /// If `true` the list is scrolled horizontally and the item size is the width of the items.
+    /// Defaults to `false`.
+    horizontal: bool,
+}
+
+impl Default for UseVirtualListOptions {
+    fn default() -> Self {
+        Self {
+            overscan: 5,
+            horizontal: false,
+        }
+    }
+}
+
+/// An item returned in `visible_items` of [`use_virtual_list`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct VirtualListItem<T> {
+    /// Index of the item in the list
+    pub index: usize,
+
+    /// The item itself
+    pub data: T,
+}
+
+/// Props for the scroll container of [`use_virtual_list`].
+#[derive(Clone, Copy)]
+pub struct UseVirtualListContainerProps {
+    /// Style that makes the container scrollable
+    pub style: &'static str,
+}
+
+/// Props for the wrapper of the items of [`use_virtual_list`].
+#[derive(Clone, Copy)]
+pub struct UseVirtualListWrapperProps {
+    /// Style that sizes and offsets the wrapper such that the visible items are in the right place
+    pub style: Signal<String>,
+}
+
+/// Return type of [`use_virtual_list`].
+pub struct UseVirtualListReturn<T, ScrollToFn>
+where
+    T: Send + Sync + 'static,
+    ScrollToFn: Fn(usize) + Clone + Send + Sync,
+{
+    /// The items that have to be rendered, i.e. the visible ones and the overscan
+    pub visible_items: Signal<Vec<VirtualListItem<T>>>,
+
+    /// Props for the scroll container
+    pub container_props: UseVirtualListContainerProps,
+
+    /// Props for the wrapper element inside the container that contains the items
+    pub wrapper_props: UseVirtualListWrapperProps,
+
+    /// Scrolls the container to the item at the given index
+    pub scroll_to: ScrollToFn,
+}
@@ -1,8 +1,10 @@
-use crate::{use_supported, use_window};
+use crate::{use_supported_immediately, use_window};
 use cfg_if::cfg_if;
 use default_struct_builder::DefaultBuilder;
 use leptos::prelude::*;
 use leptos::reactive::wrappers::read::Signal;
+use std::future::Future;
+use std::pin::Pin;
 use std::rc::Rc;
 use wasm_bindgen::JsValue;
 
@@ -40,12 +42,60 @@ use wasm_bindgen::JsValue;
 /// # }
 /// ```
 ///
+/// ### Permission
+///
+/// The current permission is available as the signal `permission`. It's not requested
+/// automatically. `show` requests it if it hasn't been granted yet. To request it explicitly,
+/// e.g. from an "Enable notifications" button, call `request_permission`. The returned future
+/// resolves with the new permission.
+///
+/// Browsers only show the permission prompt in response to a user interaction. So
+/// `request_permission` (and the first `show`) has to be called from an event handler like a
+/// click handler. Otherwise the request is ignored or denied right away.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos::task::spawn_local;
+/// # use leptos_use::{use_web_notification, NotificationPermission, UseWebNotificationReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebNotificationReturn {
+///     permission,
+///     request_permission,
+///     ..
+/// } = use_web_notification();
+///
+/// let enable = move |_| {
+///     let request_permission = request_permission.clone();
+///     spawn_local(async move {
+///         if request_permission().await == NotificationPermission::Denied {
+///             leptos::logging::log!("Notifications have been denied");
+///         }
+///     });
+/// };
+///
+/// view! {
+///     <Show when=move || permission.get() != NotificationPermission::Granted>
+///         <button on:click=enable.clone()>"Enable notifications"</button>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `show`, `close` and `request_permission` are sendwrapped functions. They
+/// can only be called from the same thread that called `use_web_notification`.
+///
 /// ## Server-Side Rendering
 ///
 /// This function is basically ignored on the server. You can safely call `show` but it will do nothing.
+/// `request_permission` resolves to `NotificationPermission::Default`.
 pub fn use_web_notification() -> UseWebNotificationReturn<
     impl Fn(ShowOptions) + Clone + Send + Sync,
     impl Fn() + Clone + Send + Sync,
+    impl Fn() -> WebNotificationPermissionFuture + Clone + Send + Sync,
 > {
     use_web_notification_with_options(UseWebNotificationOptions::default())
 }
@@ -56,8 +106,9 @@ pub fn use_web_notification_with_options(
 ) -> UseWebNotificationReturn<
     impl Fn(ShowOptions) + Clone + Send + Sync,
     impl Fn() + Clone + Send + Sync,
+    impl Fn() -> WebNotificationPermissionFuture + Clone + Send + Sync,
 > {
-    let is_supported = use_supported(browser_supports_notifications);
+    let is_supported = use_supported_immediately(browser_supports_notifications);
 
     let (notification, set_notification) = signal_local(None::<web_sys::Notification>);
 
@@ -70,6 +121,10 @@ pub fn use_web_notification_with_options(
 
         let show = move |_: ShowOptions| ();
         let close = move || ();
+        let request_permission = move || {
+            Box::pin(async { NotificationPermission::Default })
+                as WebNotificationPermissionFuture
+        };
     } else {
         use crate::use_event_listener;
         use leptos::ev::visibilitychange;
@@ -139,8 +194,15 @@ pub fn use_web_notification_with_options(
                 let on_error_closure = on_error_closure.clone();
                 let on_show_closure = on_show_closure.clone();
 
+                // Requested synchronously so it's still part of the user gesture
+                let permission_request = (web_sys::Notification::permission()
+                    != web_sys::NotificationPermission::Granted)
+                    .then(request_web_notification_permission);
+
                 leptos::task::spawn_local(async move {
-                    set_permission.set(request_web_notification_permission().await);
+                    if let Some(permission_request) = permission_request {
+                        set_permission.set(await_web_notification_permission(permission_request).await);
+                    }
 
                     let mut notification_options = web_sys::NotificationOptions::from(&options);
                     options_override.override_notification_options(&mut notification_options);
@@ -174,9 +236,29 @@ pub fn use_web_notification_with_options(
             }
         };
 
-        leptos::task::spawn_local(async move {
-            set_permission.set(request_web_notification_permission().await);
-        });
+        let request_permission = {
+            let request_permission = move || {
+                if !is_supported.get_untracked() {
+                    return Box::pin(async { NotificationPermission::Default })
+                        as WebNotificationPermissionFuture;
+                }
+
+                // Requested synchronously so it's still part of the user gesture
+                let permission_request = request_web_notification_permission();
+
+                Box::pin(async move {
+                    let permission = await_web_notification_permission(permission_request).await;
+                    set_permission.try_set(permission);
+                    permission
+                }) as WebNotificationPermissionFuture
+            };
+            let wrapped_request_permission = SendWrapper::new(request_permission);
+            move || wrapped_request_permission()
+        };
+
+        if is_supported.get_untracked() {
+            set_permission.set(web_sys::Notification::permission().into());
+        }
 
         on_cleanup(close);
 
@@ -201,6 +283,7 @@ pub fn use_web_notification_with_options(
         show,
         close,
         permission: permission.into(),
+        request_permission,
     }
 }
 
@@ -509,27 +592,41 @@ impl From<web_sys::NotificationPermission> for NotificationPermission {
     }
 }
 
-/// Use `window.Notification.requestPosition()`. Returns a future that should be awaited
-/// at least once before using [`use_web_notification`] to make sure
-/// you have the permission to send notifications.
+/// Calls `window.Notification.requestPermission()` and returns the pending promise.
 #[cfg(not(feature = "ssr"))]
-async fn request_web_notification_permission() -> NotificationPermission {
-    if let Ok(notification_permission) = web_sys::Notification::request_permission() {
-        let _ = crate::js_fut!(notification_permission).await;
+fn request_web_notification_permission() -> Option<js_sys::Promise> {
+    web_sys::Notification::request_permission().ok()
+}
+
+/// Awaits the promise returned by [`request_web_notification_permission`] and returns the
+/// resulting permission.
+#[cfg(not(feature = "ssr"))]
+async fn await_web_notification_permission(
+    promise: Option<js_sys::Promise>,
+) -> NotificationPermission {
+    if let Some(promise) = promise {
+        let _ = crate::js_fut!(promise).await;
     }
 
     web_sys::Notification::permission().into()
 }
 
+/// Future returned by [`UseWebNotificationReturn::request_permission`].
+pub type WebNotificationPermissionFuture = Pin<Box<dyn Future<Output = NotificationPermission>>>;
+
 /// Return type for [`use_web_notification`].
-pub struct UseWebNotificationReturn<ShowFn, CloseFn>
+pub struct UseWebNotificationReturn<ShowFn, CloseFn, RequestPermissionFn>
 where
     ShowFn: Fn(ShowOptions) + Clone + Send + Sync,
     CloseFn: Fn() + Clone + Send + Sync,
+    RequestPermissionFn: Fn() -> WebNotificationPermissionFuture + Clone + Send + Sync,
 {
     pub is_supported: Signal<bool>,
     pub notification: Signal<Option<web_sys::Notification>, LocalStorage>,
     pub show: ShowFn,
     pub close: CloseFn,
     pub permission: Signal<NotificationPermission>,
+    /// Requests the permission to show notifications. Has to be called from a user interaction
+    /// like a click handler. Resolves with the new permission.
+    pub request_permission: RequestPermissionFn,
 }
@@ -0,0 +1,275 @@
+use crate::{js, sendwrap_fn, use_event_listener, use_supported_immediately};
+use codee::{CodecError, Decoder, Encoder};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::messageerror;
+use leptos::prelude::*;
+use std::marker::PhantomData;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Reactive [Web Worker](https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API/Using_web_workers).
+///
+/// Creates a `Worker` from a script url, sends messages to it and exposes the latest received
+/// message reactively. The worker is terminated automatically when the component is cleaned up.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_web_worker, UseWebWorkerReturn};
+/// # use codee::string::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebWorkerReturn {
+///     data,
+///     post,
+///     terminate,
+///     status,
+///     error,
+///     ..
+/// } = use_web_worker::<u64, u64, FromToStringCodec>("/workers/fibonacci.js");
+///
+/// post(&40);
+///
+/// view! {
+///     <p>"Result: " {move || data.get()}</p>
+///     <button on:click=move |_| terminate()>"Stop"</button>
+/// }
+/// # }
+/// ```
+///
+/// Messages are (en)decoded via the given codec and sent to the worker as strings. The worker
+/// has to answer with strings in the same format. You can use any of the string codecs or a
+/// binary codec wrapped in `Base64`.
+///
+/// > Please check [the codec chapter](https://leptos-use.rs/codecs.html) to see what codecs are
+/// > available and what feature flags they require.
+///
+/// ```js
+/// // /workers/fibonacci.js
+/// const fib = (n) => (n < 2 ? n : fib(n - 1) + fib(n - 2));
+///
+/// self.onmessage = (event) => {
+///     self.postMessage(String(fib(Number(event.data))));
+/// };
+/// ```
+///
+/// To use an ES module as worker script, set the option `module` to `true`.
+///
+/// ## SendWrapped Return
+///
+/// The returned closures `post` and `terminate` are sendwrapped functions. They can
+/// only be called from the same thread that called `use_web_worker`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no worker is created. `status` is always `WebWorkerStatus::Pending` and
+/// `post` and `terminate` are no-ops.
+pub fn use_web_worker<In, Out, C>(
+    script_url: &str,
+) -> UseWebWorkerReturn<
+    In,
+    Out,
+    impl Fn(&In) + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    C,
+>
+where
+    In: 'static,
+    Out: Send + Sync + 'static,
+    C: Encoder<In, Encoded = String> + Decoder<Out, Encoded = str>,
+{
+    use_web_worker_with_options::<In, Out, C>(script_url, UseWebWorkerOptions::default())
+}
+
+/// Version of [`use_web_worker`] that takes a `UseWebWorkerOptions`. See [`use_web_worker`] for how to use.
+pub fn use_web_worker_with_options<In, Out, C>(
+    script_url: &str,
+    options: UseWebWorkerOptions,
+) -> UseWebWorkerReturn<
+    In,
+    Out,
+    impl Fn(&In) + Clone + Send + Sync,
+    impl Fn() + Clone + Send + Sync,
+    C,
+>
+where
+    In: 'static,
+    Out: Send + Sync + 'static,
+    C: Encoder<In, Encoded = String> + Decoder<Out, Encoded = str>,
+{
+    let UseWebWorkerOptions { module, name } = options;
+
+    let is_supported = use_supported_immediately(|| js!("Worker" in &window()));
+
+    let (worker, set_worker) = signal_local(None::<web_sys::Worker>);
+    let (data, set_data) = signal(None::<Out>);
+    let (status, set_status) = signal(WebWorkerStatus::Pending);
+    let (error, set_error) = signal_local(
+        None::<UseWebWorkerError<<C as Encoder<In>>::Error, <C as Decoder<Out>>::Error>>,
+    );
+
+    let post = sendwrap_fn!(move |value: &In| {
+        if let Some(worker) = worker.get_untracked() {
+            match C::encode(value) {
+                Ok(msg) => {
+                    worker
+                        .post_message(&msg.into())
+                        .map_err(|err| set_error.set(Some(UseWebWorkerError::PostMessage(err))))
+                        .ok();
+                }
+                Err(err) => {
+                    set_error.set(Some(UseWebWorkerError::Codec(CodecError::Encode(err))));
+                }
+            }
+        }
+    });
+
+    let terminate = sendwrap_fn!(move || {
+        if let Some(worker) = worker.get_untracked() {
+            worker.terminate();
+            set_worker.set(None);
+            set_status.set(WebWorkerStatus::Terminated);
+        }
+    });
+
+    if is_supported.get_untracked() {
+        let worker_options = web_sys::WorkerOptions::new();
+        if module {
+            worker_options.set_type(web_sys::WorkerType::Module);
+        }
+        if let Some(name) = &name {
+            worker_options.set_name(name);
+        }
+
+        match web_sys::Worker::new_with_options(script_url, &worker_options) {
+            Ok(new_worker) => {
+                set_worker.set(Some(new_worker));
+                set_status.set(WebWorkerStatus::Running);
+            }
+            Err(err) => {
+                set_error.set(Some(UseWebWorkerError::Create(err)));
+            }
+        }
+
+        let _ = use_event_listener(worker, leptos::ev::message, move |event| {
+            if let Some(msg) = event.data().as_string() {
+                match C::decode(&msg) {
+                    Ok(value) => set_data.set(Some(value)),
+                    Err(err) => {
+                        set_error.set(Some(UseWebWorkerError::Codec(CodecError::Decode(err))))
+                    }
+                }
+            } else {
+                set_error.set(Some(UseWebWorkerError::ValueNotString));
+            }
+        });
+
+        let _ = use_event_listener(worker, messageerror, move |event| {
+            set_error.set(Some(UseWebWorkerError::MessageEvent(event)));
+        });
+
+        let _ = use_event_listener(
+            worker,
+            leptos::ev::Custom::<web_sys::ErrorEvent>::new("error"),
+            move |event| {
+                set_error.set(Some(UseWebWorkerError::ErrorEvent(event)));
+            },
+        );
+    }
+
+    on_cleanup({
+        let terminate = terminate.clone();
+
+        move || {
+            terminate();
+        }
+    });
+
+    UseWebWorkerReturn {
+        is_supported,
+        worker: worker.into(),
+        data: data.into(),
+        post,
+        terminate,
+        status: status.into(),
+        error: error.into(),
+        _marker: PhantomData,
+    }
+}
+
+/// Options for [`use_web_worker_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseWebWorkerOptions {
+    /// Whether the worker script is an ES module. Defaults to `false`.
+    module: bool,
+
+    /// Name of the worker, mainly useful for debugging. Defaults to `None`.
+    #[builder(into)]
+    name: Option<String>,
+}
+
+/// Status of the worker of [`use_web_worker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebWorkerStatus {
+    /// The worker hasn't been created (yet). This is the case on the server, if workers aren't
+    /// supported or if creating the worker failed.
+    Pending,
+    /// The worker has been created and can receive messages
+    Running,
+    /// The worker has been terminated
+    Terminated,
+}
+
+/// Return type of [`use_web_worker`].
+pub struct UseWebWorkerReturn<In, Out, PFn, TFn, C>
+where
+    In: 'static,
+    Out: Send + Sync + 'static,
+    PFn: Fn(&In) + Clone + Send + Sync,
+    TFn: Fn() + Clone + Send + Sync,
+    C: Encoder<In> + Decoder<Out>,
+{
+    /// `true` if this browser supports Web Workers
+    pub is_supported: Signal<bool>,
+
+    /// The worker that is wrapped by this function
+    pub worker: Signal<Option<web_sys::Worker>, LocalStorage>,
+
+    /// Latest message received from the worker
+    pub data: Signal<Option<Out>>,
+
+    /// Sends a message to the worker
+    pub post: PFn,
+
+    /// Terminates the worker immediately
+    pub terminate: TFn,
+
+    /// Current status of the worker
+    pub status: Signal<WebWorkerStatus>,
+
+    /// Latest error
+    pub error: Signal<Option<WebWorkerErrorType<In, Out, C>>, LocalStorage>,
+
+    _marker: PhantomData<In>,
+}
+
+type WebWorkerErrorType<In, Out, C> =
+    UseWebWorkerError<<C as Encoder<In>>::Error, <C as Decoder<Out>>::Error>;
+
+#[derive(Debug, Error)]
+pub enum UseWebWorkerError<E, D> {
+    #[error("failed to create worker")]
+    Create(JsValue),
+    #[error("failed to post message")]
+    PostMessage(JsValue),
+    #[error("worker error")]
+    ErrorEvent(web_sys::ErrorEvent),
+    #[error("worker message error")]
+    MessageEvent(web_sys::MessageEvent),
+    #[error("failed to (de)encode value")]
+    Codec(CodecError<E, D>),
+    #[error("received value is not a string")]
+    ValueNotString,
+}
@@ -1,12 +1,14 @@
 #![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
 
-use crate::{core::ConnectionReadyState, use_interval_fn, ReconnectLimit};
+use crate::core::{now, ConnectionReadyState};
+use crate::{use_interval_fn, ReconnectLimit};
 use cfg_if::cfg_if;
 use codee::{CodecError, Decoder, Encoder, HybridCoderError, HybridDecoder, HybridEncoder};
 use default_struct_builder::DefaultBuilder;
 use js_sys::Array;
 use leptos::{leptos_dom::helpers::TimeoutHandle, prelude::*};
 use std::marker::PhantomData;
+use std::rc::Rc;
 use std::sync::{atomic::AtomicBool, Arc};
 use std::time::Duration;
 use thiserror::Error;
@@ -148,6 +150,197 @@ use web_sys::{BinaryType, CloseEvent, Event, MessageEvent, WebSocket};
 /// }
 /// ```
 ///
+/// #### Latency
+///
+/// If the server answers heartbeats, you can measure the round-trip time by telling which
+/// messages are the answers with the option `heartbeat_pong`. The returned `latency` is the time
+/// between sending the last heartbeat and receiving its answer.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use codee::string::FromToStringCodec;
+/// # use leptos_use::{use_websocket_with_options, UseWebSocketOptions, UseWebSocketReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// #[derive(Default)]
+/// struct Ping;
+///
+/// impl std::fmt::Display for Ping {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "ping")
+///     }
+/// }
+///
+/// let UseWebSocketReturn { latency, .. } =
+///     use_websocket_with_options::<String, String, FromToStringCodec, _, _>(
+///         "wss://echo.websocket.events/",
+///         UseWebSocketOptions::default()
+///             .heartbeat::<Ping, FromToStringCodec>(5_000)
+///             .heartbeat_pong(|message: &[u8]| message == b"pong"),
+///     );
+///
+/// let latency_ms = move || latency.get().map(|latency| latency.as_millis());
+/// #
+/// # view! {}
+/// # }
+/// ```
+///
+/// Heartbeat answers are not passed on as messages. Since all heartbeats look the same, the latency
+/// is only measured if exactly one heartbeat is waiting for an answer. A heartbeat that isn't
+/// answered before the next one is sent counts as lost: the next answer can't be matched to either
+/// of them, so it is ignored and the latency keeps its last value. Answers without an outstanding
+/// heartbeat are ignored as well.
+///
+/// ### Compression
+///
+/// Browsers handle the `permessage-deflate` compression transparently. You can check if the server
+/// agreed to compress messages with the returned `compression` signal.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use codee::string::FromToStringCodec;
+/// # use leptos_use::{use_websocket, UseWebSocketReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebSocketReturn { compression, .. } =
+///     use_websocket::<String, String, FromToStringCodec>("wss://echo.websocket.events/");
+///
+/// view! {
+///     <Show when=move || compression.with(Option::is_some)>
+///         "compressed connection"
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ### Message History
+///
+/// To render messages that arrived before a component was mounted you can let the hook keep
+/// the last received messages with the option `keep_history`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use codee::string::FromToStringCodec;
+/// # use leptos_use::{use_websocket_with_options, UseWebSocketOptions, UseWebSocketReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebSocketReturn { history, .. } =
+///     use_websocket_with_options::<String, String, FromToStringCodec, _, _>(
+///         "wss://echo.websocket.events/",
+///         UseWebSocketOptions::default().keep_history(50),
+///     );
+///
+/// view! {
+///     <ul>
+///         <For each=move || history.get() key=|m| m.clone() let:message>
+///             <li>{message}</li>
+///         </For>
+///     </ul>
+/// }
+/// # }
+/// ```
+///
+/// ### Reconnecting
+///
+/// If the connection is lost it is automatically reestablished after `reconnect_interval`
+/// milliseconds up to `reconnect_limit` times. The number of attempts is available as
+/// `reconnect_attempts` and resets to `0` once the connection is open again.
+/// Call `reconnect` to skip the waiting time, for example when the user knows that the network
+/// is back.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use codee::string::FromToStringCodec;
+/// # use leptos_use::{use_websocket, UseWebSocketReturn};
+/// # use leptos_use::core::ConnectionReadyState;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebSocketReturn {
+///     ready_state,
+///     reconnect_attempts,
+///     reconnect,
+///     ..
+/// } = use_websocket::<String, String, FromToStringCodec>("wss://echo.websocket.events/");
+///
+/// let is_reconnecting = move || {
+///     ready_state.get() != ConnectionReadyState::Open && reconnect_attempts.get() > 0
+/// };
+///
+/// view! {
+///     <Show when=is_reconnecting>
+///         <p>"Reconnecting (attempt " {reconnect_attempts} ")..."</p>
+///         <button on:click={
+///             let reconnect = reconnect.clone();
+///             move |_| reconnect()
+///         }>"Reconnect now"</button>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ### Sending raw data
+///
+/// Besides the typed `send` there are `send_bytes` and `send_text` that write directly to the
+/// socket, bypassing the codec. This is useful to relay already encoded frames.
+/// Like `send` they do nothing if the connection isn't open.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use codee::string::FromToStringCodec;
+/// # use leptos_use::{use_websocket, UseWebSocketReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebSocketReturn {
+///     send_bytes,
+///     send_text,
+///     ..
+/// } = use_websocket::<String, String, FromToStringCodec>("wss://echo.websocket.events/");
+///
+/// let relay = move |frame: Vec<u8>| send_bytes(&frame);
+///
+/// send_text("{\"type\":\"ping\"}");
+/// #
+/// # view! {}
+/// # }
+/// ```
+///
+/// ### Custom Socket Factory
+///
+/// By default the socket is created with the browser's `WebSocket` constructor. You can provide
+/// your own factory, for example to add logging or to pass additional arguments. If it returns an
+/// error, the error is passed to `on_error` as `UseWebSocketError::Create` and the `ready_state`
+/// is set to `Closed`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use codee::string::FromToStringCodec;
+/// # use leptos_use::{use_websocket_with_options, UseWebSocketOptions};
+/// # use web_sys::WebSocket;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let websocket = use_websocket_with_options::<String, String, FromToStringCodec, _, _>(
+///     "wss://echo.websocket.events/",
+///     UseWebSocketOptions::default().socket_factory(|url, _protocols| {
+///         leptos::logging::log!("Connecting to {url}");
+///         WebSocket::new(url)
+///     }),
+/// );
+/// #
+/// # view! {}
+/// # }
+/// ```
+///
+/// The factory can also return your own implementation of [`WebSocketTransport`] instead of a
+/// `web_sys::WebSocket`, e.g. a test double that doesn't need a server. The transport calls the
+/// [`WebSocketTransportHandlers`] it's given when the connection opens, receives a message, fails
+/// or closes. In that case the returned `ws` is `None`.
+///
 /// ## Relative Paths
 ///
 /// If the provided `url` is relative, it will be resolved relative to the current page.
@@ -266,6 +459,7 @@ use web_sys::{BinaryType, CloseEvent, Event, MessageEvent, WebSocket};
 /// ## Server-Side Rendering
 ///
 /// On the server the returned functions amount to no-ops.
+#[allow(clippy::type_complexity)]
 pub fn use_websocket<Tx, Rx, C>(
     url: &str,
 ) -> UseWebSocketReturn<
@@ -274,6 +468,9 @@ pub fn use_websocket<Tx, Rx, C>(
     impl Fn() + Clone + Send + Sync + 'static,
     impl Fn() + Clone + Send + Sync + 'static,
     impl Fn(&Tx) + Clone + Send + Sync + 'static,
+    impl Fn(&[u8]) + Clone + Send + Sync + 'static,
+    impl Fn(&str) + Clone + Send + Sync + 'static,
+    impl Fn() + Clone + Send + Sync + 'static,
 >
 where
     Tx: Send + Sync + 'static,
@@ -302,6 +499,9 @@ pub fn use_websocket_with_options<Tx, Rx, C, Hb, HbCodec>(
     impl Fn() + Clone + Send + Sync + 'static,
     impl Fn() + Clone + Send + Sync + 'static,
     impl Fn(&Tx) + Clone + Send + Sync + 'static,
+    impl Fn(&[u8]) + Clone + Send + Sync + 'static,
+    impl Fn(&str) + Clone + Send + Sync + 'static,
+    impl Fn() + Clone + Send + Sync + 'static,
 >
 where
     Tx: Send + Sync + 'static,
@@ -331,16 +531,45 @@ where
         reconnect_interval,
         immediate,
         protocols,
+        socket_factory,
+        keep_history,
         heartbeat,
+        heartbeat_pong,
     } = options;
 
     let (ready_state, set_ready_state) = signal(ConnectionReadyState::Closed);
     let (message, set_message) = signal(None);
-    let ws_signal = RwSignal::new_local(None::<WebSocket>);
+    let (history, set_history) = signal(Vec::<Rx>::new());
+
+    let push_history = move |value: Rx| {
+        set_history.update(|history| {
+            history.push(value);
+            if history.len() > keep_history {
+                history.drain(..history.len() - keep_history);
+            }
+        });
+    };
+    let (compression, set_compression) = signal(None::<CompressionInfo>);
+    let ws_signal = RwSignal::new_local(None::<Rc<dyn WebSocketTransport>>);
 
     let reconnect_timer_ref: StoredValue<Option<TimeoutHandle>> = StoredValue::new(None);
+    // Incremented whenever a pending reconnect is cancelled so an already scheduled callback
+    // doesn't connect anymore.
+    let reconnect_generation_ref: StoredValue<u64> = StoredValue::new(0);
+
+    let cancel_reconnect = move || {
+        if let Some(reconnect_timer) = reconnect_timer_ref.get_value() {
+            reconnect_timer.clear();
+            reconnect_timer_ref.set_value(None);
+        }
+        reconnect_generation_ref.update_value(|generation| *generation += 1);
+    };
 
     let reconnect_times_ref: StoredValue<u64> = StoredValue::new(0);
+    let (reconnect_attempts, set_reconnect_attempts) = signal(0_u32);
+    let (latency, set_latency) = signal(None::<Duration>);
+    // Time the last heartbeat was sent and how many heartbeats were sent since the last answer
+    let pending_heartbeat_ref: StoredValue<Option<(f64, u32)>> = StoredValue::new(None);
     let manually_closed_ref: StoredValue<bool> = StoredValue::new(false);
 
     let unmounted = Arc::new(AtomicBool::new(false));
@@ -349,16 +578,16 @@ where
 
     let send_str = move |data: &str| {
         if ready_state.get_untracked() == ConnectionReadyState::Open {
-            if let Some(web_socket) = ws_signal.get_untracked() {
-                let _ = web_socket.send_with_str(data);
+            if let Some(transport) = ws_signal.get_untracked() {
+                let _ = transport.send_text(data);
             }
         }
     };
 
     let send_bytes = move |data: &[u8]| {
         if ready_state.get_untracked() == ConnectionReadyState::Open {
-            if let Some(web_socket) = ws_signal.get_untracked() {
-                let _ = web_socket.send_with_u8_array(data);
+            if let Some(transport) = ws_signal.get_untracked() {
+                let _ = transport.send_bytes(data);
             }
         }
     };
@@ -381,6 +610,7 @@ where
         if let Some((pause, _)) = heartbeat_interval_ref.get_value() {
             pause();
         }
+        pending_heartbeat_ref.set_value(None);
     };
 
     #[cfg(not(feature = "ssr"))]
@@ -413,7 +643,14 @@ where
                                             )))
                                         }
                                     },
-                                )
+                                );
+
+                                if ready_state.get_untracked() == ConnectionReadyState::Open {
+                                    pending_heartbeat_ref.update_value(|pending| {
+                                        let unanswered = pending.map_or(0, |(_, count)| count);
+                                        *pending = Some((now(), unanswered + 1));
+                                    });
+                                }
                             },
                             heartbeat.interval,
                         );
@@ -436,18 +673,23 @@ where
                     && !reconnect_limit.is_exceeded_by(reconnect_times_ref.get_value())
                     && ws_signal
                         .get_untracked()
-                        .is_some_and(|ws: WebSocket| ws.ready_state() != WebSocket::OPEN)
+                        .is_some_and(|transport| !transport.is_open())
                     && reconnect_timer_ref.get_value().is_none()
                 {
+                    let generation = reconnect_generation_ref.get_value();
+
                     reconnect_timer_ref.set_value(
                         set_timeout_with_handle(
                             move || {
-                                if unmounted.load(std::sync::atomic::Ordering::Relaxed) {
+                                if unmounted.load(std::sync::atomic::Ordering::Relaxed)
+                                    || reconnect_generation_ref.get_value() != generation
+                                {
                                     return;
                                 }
                                 if let Some(connect) = connect_ref.get_value() {
                                     connect();
                                     reconnect_times_ref.update_value(|current| *current += 1);
+                                    set_reconnect_attempts.update(|attempts| *attempts += 1);
                                 }
                             },
                             Duration::from_millis(reconnect_interval),
@@ -463,121 +705,129 @@ where
             let on_error = Arc::clone(&on_error);
 
             Some(Arc::new(move || {
-                if let Some(reconnect_timer) = reconnect_timer_ref.get_value() {
-                    reconnect_timer.clear();
-                    reconnect_timer_ref.set_value(None);
-                }
+                cancel_reconnect();
 
-                if let Some(web_socket) = ws_signal.get_untracked() {
-                    let _ = web_socket.close();
+                if let Some(transport) = ws_signal.get_untracked() {
+                    let _ = transport.close();
                 }
 
-                let web_socket = {
-                    protocols.with_untracked(|protocols| {
-                        protocols.as_ref().map_or_else(
-                            || WebSocket::new(&url).unwrap_throw(),
-                            |protocols| {
-                                let array = protocols
-                                    .iter()
-                                    .map(|p| JsValue::from(p.clone()))
-                                    .collect::<Array>();
-                                WebSocket::new_with_str_sequence(&url, &JsValue::from(&array))
-                                    .unwrap_throw()
-                            },
-                        )
-                    })
+                let transport = match protocols
+                    .with_untracked(|protocols| socket_factory(&url, protocols.as_deref()))
+                {
+                    Ok(transport) => transport,
+                    Err(err) => {
+                        #[cfg(debug_assertions)]
+                        let zone = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+                        on_error(UseWebSocketError::Create(err));
+
+                        #[cfg(debug_assertions)]
+                        drop(zone);
+
+                        set_ready_state.set(ConnectionReadyState::Closed);
+                        return;
+                    }
                 };
-                web_socket.set_binary_type(BinaryType::Arraybuffer);
                 set_ready_state.set(ConnectionReadyState::Connecting);
 
-                // onopen handler
-                {
+                let on_open_handler = {
                     let unmounted = Arc::clone(&unmounted);
                     let on_open = Arc::clone(&on_open);
+                    let start_heartbeat = start_heartbeat.clone();
 
-                    let onopen_closure = Closure::wrap(Box::new({
-                        let start_heartbeat = start_heartbeat.clone();
+                    move |e: Event| {
+                        if unmounted.load(std::sync::atomic::Ordering::Relaxed) {
+                            return;
+                        }
 
-                        move |e: Event| {
-                            if unmounted.load(std::sync::atomic::Ordering::Relaxed) {
-                                return;
-                            }
+                        #[cfg(debug_assertions)]
+                        let zone = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
 
-                            #[cfg(debug_assertions)]
-                            let zone = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+                        on_open(e);
 
-                            on_open(e);
+                        #[cfg(debug_assertions)]
+                        drop(zone);
 
-                            #[cfg(debug_assertions)]
-                            drop(zone);
+                        set_compression.set(ws_signal.with_untracked(|transport| {
+                            transport.as_ref().and_then(|transport| {
+                                CompressionInfo::from_extensions(&transport.extensions())
+                            })
+                        }));
+                        set_ready_state.set(ConnectionReadyState::Open);
 
-                            set_ready_state.set(ConnectionReadyState::Open);
+                        reconnect_times_ref.set_value(0);
+                        set_reconnect_attempts.set(0);
 
-                            start_heartbeat();
-                        }
-                    })
-                        as Box<dyn FnMut(Event)>);
-                    web_socket.set_onopen(Some(onopen_closure.as_ref().unchecked_ref()));
-                    // Forget the closure to keep it alive
-                    onopen_closure.forget();
-                }
+                        start_heartbeat();
+                    }
+                };
 
-                // onmessage handler
-                {
+                let on_message_handler = {
                     let unmounted = Arc::clone(&unmounted);
                     let on_message = Arc::clone(&on_message);
                     let on_message_raw = Arc::clone(&on_message_raw);
                     let on_message_raw_bytes = Arc::clone(&on_message_raw_bytes);
                     let on_error = Arc::clone(&on_error);
+                    let heartbeat_pong = heartbeat_pong.clone();
 
-                    let onmessage_closure = Closure::wrap(Box::new(move |e: MessageEvent| {
+                    move |message: WebSocketMessage| {
                         if unmounted.load(std::sync::atomic::Ordering::Relaxed) {
                             return;
                         }
 
-                        e.data().dyn_into::<js_sys::ArrayBuffer>().map_or_else(
-                            |_| {
-                                e.data().dyn_into::<js_sys::JsString>().map_or_else(
-                                    |_| {
-                                        unreachable!(
-                                            "message event, received Unknown: {:?}",
-                                            e.data()
-                                        );
-                                    },
-                                    |txt| {
-                                        let txt = String::from(&txt);
+                        if let Some(is_pong) = &heartbeat_pong {
+                            let is_pong = match &message {
+                                WebSocketMessage::Text(txt) => is_pong(txt.as_bytes()),
+                                WebSocketMessage::Binary(bytes) => is_pong(bytes),
+                            };
+
+                            if is_pong {
+                                // If more than one heartbeat is unanswered there's no telling
+                                // which one this answers so it isn't measured.
+                                if let Some((sent_at, 1)) = pending_heartbeat_ref.get_value() {
+                                    set_latency.set(Some(Duration::from_secs_f64(
+                                        (now() - sent_at).max(0.0) / 1000.0,
+                                    )));
+                                }
+                                pending_heartbeat_ref.set_value(None);
+                                return;
+                            }
+                        }
 
-                                        #[cfg(debug_assertions)]
-                                        let zone = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+                        match message {
+                            WebSocketMessage::Text(txt) => {
+                                #[cfg(debug_assertions)]
+                                let zone = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+                                on_message_raw(&txt);
 
-                                        on_message_raw(&txt);
+                                #[cfg(debug_assertions)]
+                                drop(zone);
 
+                                match C::decode_str(&txt) {
+                                    Ok(val) => {
                                         #[cfg(debug_assertions)]
-                                        drop(zone);
+                                        let prev = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
 
-                                        match C::decode_str(&txt) {
-                                            Ok(val) => {
-                                                #[cfg(debug_assertions)]
-                                                let prev = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+                                        on_message(&val);
 
-                                                on_message(&val);
+                                        #[cfg(debug_assertions)]
+                                        drop(prev);
 
-                                                #[cfg(debug_assertions)]
-                                                drop(prev);
+                                        set_message.set(Some(val));
 
-                                                set_message.set(Some(val));
-                                            }
-                                            Err(err) => {
-                                                on_error(CodecError::Decode(err).into());
+                                        if keep_history > 0 {
+                                            if let Ok(val) = C::decode_str(&txt) {
+                                                push_history(val);
                                             }
                                         }
-                                    },
-                                );
-                            },
-                            |array_buffer| {
-                                let array = js_sys::Uint8Array::new(&array_buffer);
-                                let array = array.to_vec();
-
+                                    }
+                                    Err(err) => {
+                                        on_error(CodecError::Decode(err).into());
+                                    }
+                                }
+                            }
+                            WebSocketMessage::Binary(array) => {
                                 #[cfg(debug_assertions)]
                                 let zone = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
 
@@ -597,25 +847,27 @@ where
                                         drop(prev);
 
                                         set_message.set(Some(val));
+
+                                        if keep_history > 0 {
+                                            if let Ok(val) = C::decode_bin(array.as_slice()) {
+                                                push_history(val);
+                                            }
+                                        }
                                     }
                                     Err(err) => {
                                         on_error(CodecError::Decode(err).into());
                                     }
                                 }
-                            },
-                        );
-                    })
-                        as Box<dyn FnMut(MessageEvent)>);
-                    web_socket.set_onmessage(Some(onmessage_closure.as_ref().unchecked_ref()));
-                    onmessage_closure.forget();
-                }
+                            }
+                        }
+                    }
+                };
 
-                // onerror handler
-                {
+                let on_error_handler = {
                     let unmounted = Arc::clone(&unmounted);
                     let on_error = Arc::clone(&on_error);
 
-                    let onerror_closure = Closure::wrap(Box::new(move |e: Event| {
+                    move |e: Event| {
                         if unmounted.load(std::sync::atomic::Ordering::Relaxed) {
                             return;
                         }
@@ -634,19 +886,16 @@ where
                         #[cfg(debug_assertions)]
                         drop(zone);
 
+                        set_latency.set(None);
                         set_ready_state.set(ConnectionReadyState::Closed);
-                    })
-                        as Box<dyn FnMut(Event)>);
-                    web_socket.set_onerror(Some(onerror_closure.as_ref().unchecked_ref()));
-                    onerror_closure.forget();
-                }
+                    }
+                };
 
-                // onclose handler
-                {
+                let on_close_handler = {
                     let unmounted = Arc::clone(&unmounted);
                     let on_close = Arc::clone(&on_close);
 
-                    let onclose_closure = Closure::wrap(Box::new(move |e: CloseEvent| {
+                    move |e: CloseEvent| {
                         if unmounted.load(std::sync::atomic::Ordering::Relaxed) {
                             return;
                         }
@@ -665,36 +914,49 @@ where
                         #[cfg(debug_assertions)]
                         drop(zone);
 
+                        set_compression.set(None);
+                        set_latency.set(None);
                         set_ready_state.set(ConnectionReadyState::Closed);
-                    })
-                        as Box<dyn FnMut(CloseEvent)>);
-                    web_socket.set_onclose(Some(onclose_closure.as_ref().unchecked_ref()));
-                    onclose_closure.forget();
-                }
+                    }
+                };
+
+                transport.set_handlers(WebSocketTransportHandlers {
+                    on_open: Box::new(on_open_handler),
+                    on_message: Box::new(on_message_handler),
+                    on_error: Box::new(on_error_handler),
+                    on_close: Box::new(on_close_handler),
+                });
 
-                ws_signal.set(Some(web_socket));
+                ws_signal.set(Some(transport));
             }))
         });
     }
 
     // Open connection
     let open = move || {
+        cancel_reconnect();
         reconnect_times_ref.set_value(0);
         if let Some(connect) = connect_ref.get_value() {
             connect();
         }
     };
 
+    // Reconnect immediately without waiting for the reconnect interval
+    let reconnect = move || {
+        manually_closed_ref.set_value(false);
+        if let Some(connect) = connect_ref.get_value() {
+            connect();
+            set_reconnect_attempts.update(|attempts| *attempts += 1);
+        }
+    };
+
     // Close connection
-    let close = {
-        reconnect_timer_ref.set_value(None);
-
-        move || {
-            stop_heartbeat();
-            manually_closed_ref.set_value(true);
-            if let Some(web_socket) = ws_signal.get_untracked() {
-                let _ = web_socket.close();
-            }
+    let close = move || {
+        cancel_reconnect();
+        stop_heartbeat();
+        manually_closed_ref.set_value(true);
+        if let Some(transport) = ws_signal.get_untracked() {
+            let _ = transport.close();
         }
     };
 
@@ -714,10 +976,19 @@ where
     UseWebSocketReturn {
         ready_state: ready_state.into(),
         message: message.into(),
-        ws: ws_signal.into(),
+        compression: compression.into(),
+        history: history.into(),
+        reconnect_attempts: reconnect_attempts.into(),
+        latency: latency.into(),
+        ws: Signal::derive_local(move || {
+            ws_signal.with(|transport| transport.as_ref().and_then(|t| t.web_socket()))
+        }),
         open,
         close,
         send,
+        send_bytes,
+        send_text: send_str,
+        reconnect,
         _marker: PhantomData,
     }
 }
@@ -745,6 +1016,144 @@ fn send_with_codec<T, Codec>(
 }
 
 type ArcFnBytes = Arc<dyn Fn(&[u8]) + Send + Sync>;
+type ArcFnIsPong = Arc<dyn Fn(&[u8]) -> bool + Send + Sync>;
+type SocketFactoryFn = Arc<
+    dyn Fn(&str, Option<&[String]>) -> Result<Rc<dyn WebSocketTransport>, JsValue> + Send + Sync,
+>;
+
+fn default_socket_factory(url: &str, protocols: Option<&[String]>) -> Result<WebSocket, JsValue> {
+    match protocols {
+        Some(protocols) => {
+            let array = protocols
+                .iter()
+                .map(|p| JsValue::from(p.clone()))
+                .collect::<Array>();
+            WebSocket::new_with_str_sequence(url, &JsValue::from(&array))
+        }
+        None => WebSocket::new(url),
+    }
+}
+
+/// Connection that [`use_websocket`] sends and receives messages through.
+///
+/// It's implemented for `web_sys::WebSocket` which is used by default. Return your own
+/// implementation from the option `socket_factory`, e.g. to drive `use_websocket` with a test
+/// double instead of a real server.
+pub trait WebSocketTransport {
+    /// Registers the handlers for the events of the connection. Called once right after the
+    /// connection has been created by the `socket_factory`.
+    fn set_handlers(&self, handlers: WebSocketTransportHandlers);
+
+    /// Sends a text message.
+    fn send_text(&self, data: &str) -> Result<(), JsValue>;
+
+    /// Sends a binary message.
+    fn send_bytes(&self, data: &[u8]) -> Result<(), JsValue>;
+
+    /// Closes the connection.
+    fn close(&self) -> Result<(), JsValue>;
+
+    /// Whether the connection is currently open.
+    fn is_open(&self) -> bool;
+
+    /// The extensions selected by the server like
+    /// [`WebSocket.extensions`](https://developer.mozilla.org/en-US/docs/Web/API/WebSocket/extensions).
+    /// Defaults to none.
+    fn extensions(&self) -> String {
+        String::new()
+    }
+
+    /// The underlying `web_sys::WebSocket` that is returned as `ws` by [`use_websocket`].
+    /// Defaults to `None`.
+    fn web_socket(&self) -> Option<WebSocket> {
+        None
+    }
+}
+
+/// Handlers that a [`WebSocketTransport`] calls when something happens on the connection.
+pub struct WebSocketTransportHandlers {
+    /// Call when the connection has been opened.
+    pub on_open: Box<dyn Fn(Event)>,
+    /// Call with every message received.
+    pub on_message: Box<dyn Fn(WebSocketMessage)>,
+    /// Call when an error occurred.
+    pub on_error: Box<dyn Fn(Event)>,
+    /// Call when the connection has been closed.
+    pub on_close: Box<dyn Fn(CloseEvent)>,
+}
+
+/// A message received through a [`WebSocketTransport`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WebSocketMessage {
+    /// Text message
+    Text(String),
+    /// Binary message
+    Binary(Vec<u8>),
+}
+
+impl WebSocketTransport for WebSocket {
+    fn set_handlers(&self, handlers: WebSocketTransportHandlers) {
+        let WebSocketTransportHandlers {
+            on_open,
+            on_message,
+            on_error,
+            on_close,
+        } = handlers;
+
+        self.set_binary_type(BinaryType::Arraybuffer);
+
+        // The closures are forgotten to keep them alive as long as the socket
+        let onopen_closure = Closure::wrap(Box::new(on_open) as Box<dyn Fn(Event)>);
+        self.set_onopen(Some(onopen_closure.as_ref().unchecked_ref()));
+        onopen_closure.forget();
+
+        let onmessage_closure = Closure::wrap(Box::new(move |e: MessageEvent| {
+            let message = if let Some(txt) = e.data().as_string() {
+                WebSocketMessage::Text(txt)
+            } else if let Ok(array_buffer) = e.data().dyn_into::<js_sys::ArrayBuffer>() {
+                WebSocketMessage::Binary(js_sys::Uint8Array::new(&array_buffer).to_vec())
+            } else {
+                unreachable!("message event, received Unknown: {:?}", e.data());
+            };
+
+            on_message(message);
+        }) as Box<dyn Fn(MessageEvent)>);
+        self.set_onmessage(Some(onmessage_closure.as_ref().unchecked_ref()));
+        onmessage_closure.forget();
+
+        let onerror_closure = Closure::wrap(Box::new(on_error) as Box<dyn Fn(Event)>);
+        self.set_onerror(Some(onerror_closure.as_ref().unchecked_ref()));
+        onerror_closure.forget();
+
+        let onclose_closure = Closure::wrap(Box::new(on_close) as Box<dyn Fn(CloseEvent)>);
+        self.set_onclose(Some(onclose_closure.as_ref().unchecked_ref()));
+        onclose_closure.forget();
+    }
+
+    fn send_text(&self, data: &str) -> Result<(), JsValue> {
+        self.send_with_str(data)
+    }
+
+    fn send_bytes(&self, data: &[u8]) -> Result<(), JsValue> {
+        self.send_with_u8_array(data)
+    }
+
+    fn close(&self) -> Result<(), JsValue> {
+        WebSocket::close(self)
+    }
+
+    fn is_open(&self) -> bool {
+        self.ready_state() == WebSocket::OPEN
+    }
+
+    fn extensions(&self) -> String {
+        WebSocket::extensions(self)
+    }
+
+    fn web_socket(&self) -> Option<WebSocket> {
+        Some(self.clone())
+    }
+}
 
 /// Options for [`use_websocket_with_options`].
 #[derive(DefaultBuilder)]
@@ -762,6 +1171,9 @@ where
     /// Heartbeat options
     #[builder(skip)]
     heartbeat: Option<HeartbeatOptions<Hb, HbCodec>>,
+    /// Recognizes the answers to heartbeats to measure the latency.
+    #[builder(skip)]
+    heartbeat_pong: Option<ArcFnIsPong>,
     /// `WebSocket` connect callback.
     on_open: Arc<dyn Fn(Event) + Send + Sync>,
     /// `WebSocket` message callback for typed message decoded by codec.
@@ -793,6 +1205,17 @@ where
     /// Therefore "lazy" protocols should use the `immediate(false)` option and manually call `open()`.
     #[builder(into)]
     protocols: Signal<Option<Vec<String>>>,
+    /// Creates the connection from the url and the sub protocols. Defaults to calling
+    /// `WebSocket::new` or `WebSocket::new_with_str_sequence`.
+    ///
+    /// See [Custom Socket Factory](use_websocket#custom-socket-factory) for an example.
+    #[builder(skip)]
+    socket_factory: SocketFactoryFn,
+    /// Number of received messages to keep in the returned `history`. Defaults to `0` which
+    /// disables the history.
+    ///
+    /// Messages are decoded a second time for the history so `Rx` doesn't have to implement `Clone`.
+    keep_history: usize,
 }
 
 impl<Rx: ?Sized, E, D, Hb, HbCodec> UseWebSocketOptions<Rx, E, D, Hb, HbCodec>
@@ -827,6 +1250,38 @@ where
         }
     }
 
+    /// Creates the connection from the url and the sub protocols instead of using the
+    /// browser's `WebSocket` constructor directly. It can return a `web_sys::WebSocket` or any
+    /// other [`WebSocketTransport`]. An error is passed to `on_error`.
+    pub fn socket_factory<F, T>(self, factory: F) -> Self
+    where
+        F: Fn(&str, Option<&[String]>) -> Result<T, JsValue> + Send + Sync + 'static,
+        T: WebSocketTransport + 'static,
+    {
+        Self {
+            socket_factory: Arc::new(move |url, protocols| {
+                factory(url, protocols)
+                    .map(|transport| Rc::new(transport) as Rc<dyn WebSocketTransport>)
+            }),
+            ..self
+        }
+    }
+
+    /// Recognizes the server's answers to heartbeats. It is called with the raw data of every
+    /// received message, text messages as UTF-8 bytes. If it returns `true` the message is used to
+    /// measure the returned `latency` and is not passed on as a message.
+    ///
+    /// Only has an effect if `heartbeat` is set as well.
+    pub fn heartbeat_pong<F>(self, is_pong: F) -> Self
+    where
+        F: Fn(&[u8]) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            heartbeat_pong: Some(Arc::new(is_pong)),
+            ..self
+        }
+    }
+
     /// Set the data, codec and interval at which the heartbeat is sent. The heartbeat
     /// is the default value of the `NewHb` type.
     pub fn heartbeat<NewHb, NewHbCodec>(
@@ -848,6 +1303,7 @@ where
                 interval,
                 codec: PhantomData::<NewHbCodec>,
             }),
+            heartbeat_pong: self.heartbeat_pong,
             on_open: self.on_open,
             on_message: self.on_message,
             on_message_raw: self.on_message_raw,
@@ -858,6 +1314,8 @@ where
             reconnect_interval: self.reconnect_interval,
             immediate: self.immediate,
             protocols: self.protocols,
+            socket_factory: self.socket_factory,
+            keep_history: self.keep_history,
         }
     }
 }
@@ -866,6 +1324,7 @@ impl<Rx: ?Sized, E, D> Default for UseWebSocketOptions<Rx, E, D, (), DummyEncode
     fn default() -> Self {
         Self {
             heartbeat: None,
+            heartbeat_pong: None,
             on_open: Arc::new(|_| {}),
             on_message: Arc::new(|_| {}),
             on_message_raw: Arc::new(|_| {}),
@@ -876,6 +1335,11 @@ impl<Rx: ?Sized, E, D> Default for UseWebSocketOptions<Rx, E, D, (), DummyEncode
             reconnect_interval: 3000,
             immediate: true,
             protocols: Default::default(),
+            socket_factory: Arc::new(|url, protocols| {
+                default_socket_factory(url, protocols)
+                    .map(|web_socket| Rc::new(web_socket) as Rc<dyn WebSocketTransport>)
+            }),
+            keep_history: 0,
         }
     }
 }
@@ -939,19 +1403,35 @@ where
 
 /// Return type of [`use_websocket`].
 #[derive(Clone)]
-pub struct UseWebSocketReturn<Tx, Rx, OpenFn, CloseFn, SendFn>
+pub struct UseWebSocketReturn<Tx, Rx, OpenFn, CloseFn, SendFn, SendBytesFn, SendTextFn, ReconnectFn>
 where
     Tx: Send + Sync + 'static,
     Rx: Send + Sync + 'static,
     OpenFn: Fn() + Clone + Send + Sync + 'static,
     CloseFn: Fn() + Clone + Send + Sync + 'static,
     SendFn: Fn(&Tx) + Clone + Send + Sync + 'static,
+    SendBytesFn: Fn(&[u8]) + Clone + Send + Sync + 'static,
+    SendTextFn: Fn(&str) + Clone + Send + Sync + 'static,
+    ReconnectFn: Fn() + Clone + Send + Sync + 'static,
 {
     /// The current state of the `WebSocket` connection.
     pub ready_state: Signal<ConnectionReadyState>,
     /// Latest message received from `WebSocket`.
     pub message: Signal<Option<Rx>>,
-    /// The `WebSocket` instance.
+    /// The last received messages, oldest first. Only filled if the option `keep_history` is set.
+    pub history: Signal<Vec<Rx>>,
+    /// The negotiated `permessage-deflate` compression of the current connection.
+    /// `None` if the connection isn't open or the server didn't agree to compress messages.
+    pub compression: Signal<Option<CompressionInfo>>,
+    /// Number of reconnect attempts since the last successful open. Resets to `0` as soon as the
+    /// connection is open again.
+    pub reconnect_attempts: Signal<u32>,
+    /// Round-trip time of the last answered heartbeat. Only measured if the options `heartbeat`
+    /// and `heartbeat_pong` are set. `None` until the first answer is received and while the
+    /// connection is closed.
+    pub latency: Signal<Option<Duration>>,
+    /// The `WebSocket` instance. `None` if the connection is a custom [`WebSocketTransport`]
+    /// that isn't backed by a `web_sys::WebSocket`.
     pub ws: Signal<Option<WebSocket>, LocalStorage>,
     /// Opens the `WebSocket` connection
     pub open: OpenFn,
@@ -959,10 +1439,90 @@ where
     pub close: CloseFn,
     /// Sends data through the socket
     pub send: SendFn,
+    /// Sends raw bytes as a binary message through the socket without using the codec
+    pub send_bytes: SendBytesFn,
+    /// Sends a raw text message through the socket without using the codec
+    pub send_text: SendTextFn,
+    /// Reconnects immediately without waiting for the reconnect interval
+    pub reconnect: ReconnectFn,
 
     _marker: PhantomData<Tx>,
 }
 
+/// Parameters of the `permessage-deflate` extension as negotiated with the server.
+///
+/// The browser compresses and decompresses messages transparently. This only tells you
+/// whether and how this happens.
+/// See [RFC 7692](https://datatracker.ietf.org/doc/html/rfc7692#section-7.1) for the meaning of the parameters.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CompressionInfo {
+    /// The server resets its compression context after every message.
+    pub server_no_context_takeover: bool,
+    /// The client resets its compression context after every message.
+    pub client_no_context_takeover: bool,
+    /// Size of the LZ77 sliding window of the server as a power of two.
+    pub server_max_window_bits: Option<u8>,
+    /// Size of the LZ77 sliding window of the client as a power of two.
+    pub client_max_window_bits: Option<u8>,
+}
+
+impl CompressionInfo {
+    /// Parses the value of [`WebSocket.extensions`](https://developer.mozilla.org/en-US/docs/Web/API/WebSocket/extensions).
+    /// Returns `None` if `permessage-deflate` is not part of it.
+    ///
+    /// ```
+    /// # use leptos_use::CompressionInfo;
+    /// #
+    /// let info = CompressionInfo::from_extensions(
+    ///     "permessage-deflate; client_max_window_bits=15; server_no_context_takeover",
+    /// );
+    ///
+    /// assert_eq!(
+    ///     info,
+    ///     Some(CompressionInfo {
+    ///         server_no_context_takeover: true,
+    ///         client_no_context_takeover: false,
+    ///         server_max_window_bits: None,
+    ///         client_max_window_bits: Some(15),
+    ///     })
+    /// );
+    ///
+    /// assert_eq!(CompressionInfo::from_extensions(""), None);
+    /// ```
+    pub fn from_extensions(extensions: &str) -> Option<Self> {
+        extensions.split(',').find_map(|extension| {
+            let mut params = extension.split(';').map(str::trim);
+
+            if !params.next()?.eq_ignore_ascii_case("permessage-deflate") {
+                return None;
+            }
+
+            let mut info = Self::default();
+
+            for param in params {
+                let (name, value) = param
+                    .split_once('=')
+                    .map(|(name, value)| (name.trim(), Some(value.trim().trim_matches('"'))))
+                    .unwrap_or((param, None));
+
+                match name {
+                    "server_no_context_takeover" => info.server_no_context_takeover = true,
+                    "client_no_context_takeover" => info.client_no_context_takeover = true,
+                    "server_max_window_bits" => {
+                        info.server_max_window_bits = value.and_then(|v| v.parse().ok())
+                    }
+                    "client_max_window_bits" => {
+                        info.client_max_window_bits = value.and_then(|v| v.parse().ok())
+                    }
+                    _ => {}
+                }
+            }
+
+            Some(info)
+        })
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum UseWebSocketError<E, D> {
     #[error("WebSocket error event")]
@@ -971,6 +1531,8 @@ pub enum UseWebSocketError<E, D> {
     Codec(#[from] CodecError<E, D>),
     #[error("WebSocket heartbeat codec error: {0}")]
     HeartbeatCodec(String),
+    #[error("WebSocket could not be created: {0:?}")]
+    Create(JsValue),
 }
 
 fn normalize_url(url: &str) -> String {
@@ -29,6 +29,30 @@ use leptos::prelude::*;
 /// # view! { }
 /// # }
 /// ```
+///
+/// To use anything that doesn't have a convenience method, use `with`. The closure is only
+/// called in the browser. On the server `with` returns `None`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::use_window;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let inner_width = use_window().with(|window| window.inner_width().ok());
+///
+/// if use_window().is_ssr() {
+///     // we're on the server
+/// }
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server this is always `None`, so all methods return `None` or a sensible default instead
+/// of panicking like `leptos::prelude::window()` would.
 pub fn use_window() -> UseWindow {
     cfg_if! { if #[cfg(feature = "ssr")] {
         UseWindow(None)
@@ -49,6 +73,19 @@ impl Deref for UseWindow {
 }
 
 impl UseWindow {
+    /// Calls `f` with the window in the browser and returns its result. Returns `None` on the server
+    /// without calling `f`.
+    #[inline(always)]
+    pub fn with<R>(&self, f: impl FnOnce(&web_sys::Window) -> R) -> Option<R> {
+        self.0.as_ref().map(f)
+    }
+
+    /// Returns `true` on the server where there is no window.
+    #[inline(always)]
+    pub fn is_ssr(&self) -> bool {
+        self.0.is_none()
+    }
+
     impl_ssr_safe_method!(
         /// Returns `Some(Navigator)` in the Browser. `None` otherwise.
         navigator(&self) -> Option<web_sys::Navigator>
@@ -1,9 +1,10 @@
-#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
 
 use crate::{use_event_listener_with_options, use_window, UseEventListenerOptions};
-use cfg_if::cfg_if;
-use leptos::ev::scroll;
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::{resize, scroll};
 use leptos::prelude::*;
+use std::sync::Arc;
 
 /// Reactive window scroll.
 ///
@@ -25,31 +26,139 @@ use leptos::prelude::*;
 /// # }
 /// ```
 ///
+/// ### Progress
+///
+/// [`use_window_scroll_with_options`] additionally returns the scroll progress from `0.0` to `1.0`
+/// relative to the maximum scroll distance. This is useful for reading progress bars. If the
+/// content isn't scrollable in a direction, the progress in this direction is `0.0`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_window_scroll_with_options, UseWindowScrollOptions, UseWindowScrollReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWindowScrollReturn { progress_y, .. } =
+///     use_window_scroll_with_options(UseWindowScrollOptions::default());
+///
+/// view! {
+///     <div
+///         style="position: fixed; top: 0; left: 0; height: 4px; background: red;"
+///         style:width=move || format!("{}%", progress_y.get() * 100.0)
+///     />
+/// }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// On the server this returns `Signal`s that are always `0.0`.
 pub fn use_window_scroll() -> (Signal<f64>, Signal<f64>) {
-    cfg_if! { if #[cfg(feature = "ssr")] {
-        let initial_x = 0.0;
-        let initial_y = 0.0;
-    } else {
-        let initial_x = window().scroll_x().unwrap_or_default();
-        let initial_y = window().scroll_y().unwrap_or_default();
-    }}
-    let (x, set_x) = signal(initial_x);
-    let (y, set_y) = signal(initial_y);
+    let UseWindowScrollReturn { x, y, .. } =
+        use_window_scroll_with_options(UseWindowScrollOptions::default());
+
+    (x, y)
+}
+
+/// Version of [`use_window_scroll`] that takes a `UseWindowScrollOptions`. See [`use_window_scroll`] for how to use.
+pub fn use_window_scroll_with_options(options: UseWindowScrollOptions) -> UseWindowScrollReturn {
+    let UseWindowScrollOptions { on_scroll } = options;
+
+    let (x, set_x) = signal(0.0);
+    let (y, set_y) = signal(0.0);
+    let (progress_x, set_progress_x) = signal(0.0);
+    let (progress_y, set_progress_y) = signal(0.0);
+
+    let update = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let window = window();
+
+            let scroll_x = window.scroll_x().unwrap_or_default();
+            let scroll_y = window.scroll_y().unwrap_or_default();
+
+            set_x.set(scroll_x);
+            set_y.set(scroll_y);
+
+            if let Some(element) = document().document_element() {
+                let max_x = (element.scroll_width() - element.client_width()) as f64;
+                let max_y = (element.scroll_height() - element.client_height()) as f64;
+
+                set_progress_x.set(progress(scroll_x, max_x));
+                set_progress_y.set(progress(scroll_y, max_y));
+            }
+        }
+    };
+
+    update();
 
     let _ = use_event_listener_with_options(
         use_window(),
         scroll,
-        move |_| {
-            set_x.set(window().scroll_x().unwrap_or_default());
-            set_y.set(window().scroll_y().unwrap_or_default());
+        move |event| {
+            update();
+
+            #[cfg(debug_assertions)]
+            let _z = leptos::reactive::diagnostics::SpecialNonReactiveZone::enter();
+
+            on_scroll(event);
         },
         UseEventListenerOptions::default()
             .capture(false)
             .passive(true),
     );
 
-    (x.into(), y.into())
+    // The maximum scroll distance changes with the size of the viewport
+    let _ = use_event_listener_with_options(
+        use_window(),
+        resize,
+        move |_| update(),
+        UseEventListenerOptions::default().passive(true),
+    );
+
+    UseWindowScrollReturn {
+        x: x.into(),
+        y: y.into(),
+        progress_x: progress_x.into(),
+        progress_y: progress_y.into(),
+    }
+}
+
+/// Scroll position relative to the maximum scroll distance. `0.0` if not scrollable.
+fn progress(position: f64, max: f64) -> f64 {
+    if max > 0.0 {
+        (position / max).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Options for [`use_window_scroll_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseWindowScrollOptions {
+    /// Called when the window is scrolled.
+    on_scroll: Arc<dyn Fn(web_sys::Event) + Send + Sync>,
+}
+
+impl Default for UseWindowScrollOptions {
+    fn default() -> Self {
+        Self {
+            on_scroll: Arc::new(|_| {}),
+        }
+    }
+}
+
+/// Return type of [`use_window_scroll_with_options`].
+pub struct UseWindowScrollReturn {
+    /// Horizontal scroll position in pixels
+    pub x: Signal<f64>,
+
+    /// Vertical scroll position in pixels
+    pub y: Signal<f64>,
+
+    /// Horizontal scroll position from `0.0` to `1.0` relative to the maximum scroll distance
+    pub progress_x: Signal<f64>,
+
+    /// Vertical scroll position from `0.0` to `1.0` relative to the maximum scroll distance
+    pub progress_y: Signal<f64>,
 }
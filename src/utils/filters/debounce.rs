@@ -19,6 +19,21 @@ pub fn debounce_filter<R>(
     ms: impl Into<Signal<f64>>,
     options: DebounceOptions,
 ) -> impl Fn(Arc<dyn Fn() -> R>) -> Arc<Mutex<Option<R>>> + Clone
+where
+    R: 'static,
+{
+    debounce_filter_with_cancel(ms, options).0
+}
+
+/// Like [`debounce_filter`] but also returns a function that drops a scheduled invocation.
+#[allow(clippy::type_complexity)]
+pub fn debounce_filter_with_cancel<R>(
+    ms: impl Into<Signal<f64>>,
+    options: DebounceOptions,
+) -> (
+    impl Fn(Arc<dyn Fn() -> R>) -> Arc<Mutex<Option<R>>> + Clone,
+    impl Fn() + Clone + Send + Sync,
+)
 where
     R: 'static,
 {
@@ -42,10 +57,20 @@ where
         }
     });
 
+    let cancel = {
+        let timer = Arc::clone(&timer);
+        let max_timer = Arc::clone(&max_timer);
+
+        move || {
+            clear_timeout(&timer);
+            clear_timeout(&max_timer);
+        }
+    };
+
     let ms = ms.into();
     let max_wait_signal = options.max_wait;
 
-    move |_invoke: Arc<dyn Fn() -> R>| {
+    let filter = move |_invoke: Arc<dyn Fn() -> R>| {
         let duration = ms.get_untracked();
         let max_duration = max_wait_signal.get_untracked();
 
@@ -105,5 +130,7 @@ where
         }}
 
         Arc::clone(&last_return_value)
-    }
+    };
+
+    (filter, cancel)
 }
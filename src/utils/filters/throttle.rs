@@ -30,6 +30,21 @@ pub fn throttle_filter<R>(
     ms: impl Into<Signal<f64>>,
     options: ThrottleOptions,
 ) -> impl Fn(Arc<dyn Fn() -> R>) -> Arc<Mutex<Option<R>>> + Clone
+where
+    R: 'static,
+{
+    throttle_filter_with_cancel(ms, options).0
+}
+
+/// Like [`throttle_filter`] but also returns a function that drops a scheduled trailing invocation.
+#[allow(clippy::type_complexity)]
+pub fn throttle_filter_with_cancel<R>(
+    ms: impl Into<Signal<f64>>,
+    options: ThrottleOptions,
+) -> (
+    impl Fn(Arc<dyn Fn() -> R>) -> Arc<Mutex<Option<R>>> + Clone,
+    impl Fn() + Clone + Send + Sync,
+)
 where
     R: 'static,
 {
@@ -49,9 +64,19 @@ where
 
     on_cleanup(clear.clone());
 
+    let cancel = {
+        let clear = clear.clone();
+        let is_leading = Arc::clone(&is_leading);
+
+        move || {
+            clear();
+            is_leading.store(true, std::sync::atomic::Ordering::Relaxed);
+        }
+    };
+
     let ms = ms.into();
 
-    move |mut _invoke: Arc<dyn Fn() -> R>| {
+    let filter = move |mut _invoke: Arc<dyn Fn() -> R>| {
         let duration = ms.get_untracked();
         let elapsed = now() - *last_exec.lock().unwrap();
 
@@ -119,5 +144,7 @@ where
         is_leading.store(false, std::sync::atomic::Ordering::Relaxed);
 
         Arc::clone(&last_return_value)
-    }
+    };
+
+    (filter, cancel)
 }
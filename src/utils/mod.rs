@@ -13,6 +13,7 @@ mod pausable;
 mod sendwrap_fn;
 mod signal_filtered;
 mod use_derive_signal;
+mod visibility_pause;
 
 pub use filters::*;
 #[cfg(all(
@@ -29,3 +30,4 @@ pub(crate) use js_value_from_to_string::*;
 pub use pausable::*;
 #[allow(unused_imports)]
 pub(crate) use signal_filtered::*;
+pub use visibility_pause::*;
@@ -103,4 +103,164 @@ macro_rules! signal_filtered_macro_impl{
     }};
 }
 
-pub(crate) use {signal_filtered, signal_filtered_local, signal_filtered_macro_impl};
+macro_rules! signal_filtered_with_controls {
+    (
+        $(#[$outer:meta])*
+        $filter_name:ident
+        #[$simple_func_doc:meta]
+        #[$options_doc:meta]
+
+    ) => { ::paste::paste! {
+        /// Return type of
+        #[$simple_func_doc]
+        /// and its variants.
+        pub struct [<Signal $filter_name:camel d Return>]<T, S, FlushFn, CancelFn>
+        where
+            T: 'static,
+            S: ::leptos::prelude::Storage<T>,
+            FlushFn: Fn() + Clone + Send + Sync,
+            CancelFn: Fn() + Clone + Send + Sync,
+        {
+            #[doc = "The " [<$filter_name d>] " value"]
+            pub value: Signal<T, S>,
+
+            /// `true` from the first change of the source until it has been applied to `value`
+            pub is_pending: Signal<bool>,
+
+            /// Applies a pending change to `value` immediately
+            pub flush: FlushFn,
+
+            /// Drops a pending change. `value` keeps its current value.
+            pub cancel: CancelFn,
+        }
+
+        $crate::utils::signal_filtered_with_controls_macro_impl!(
+            $(#[$outer])*,
+            #[$simple_func_doc],
+            #[$options_doc],
+            $filter_name,
+            [<$filter_name d>],
+            ::leptos::prelude::SyncStorage,
+            Send;Sync
+        );
+
+        $crate::utils::signal_filtered_with_controls_macro_impl!(
+            #[doc = "Version of"]
+            #[$simple_func_doc]
+            #[doc = "for values that are not `Send + Sync`."],
+            #[$simple_func_doc],
+            #[$options_doc],
+            $filter_name,
+            [<$filter_name d_local>],
+            ::leptos::prelude::LocalStorage,
+        );
+    }};
+}
+
+#[doc(hidden)]
+macro_rules! signal_filtered_with_controls_macro_impl {
+    (
+        $(#[$outer:meta])*
+        ,#[$simple_func_doc:meta]
+        ,#[$options_doc:meta]
+        ,$filter_name:ident
+        ,$fn_name:ident
+        ,$storage:ty
+        ,$($traits:ty);*
+    ) => { ::paste::paste! {
+        $(#[$outer])*
+        #[track_caller]
+        pub fn [<signal_ $fn_name _with_controls>]<S, T>(
+            value: S,
+            ms: impl Into<Signal<f64>> + 'static,
+        ) -> [<Signal $filter_name:camel d Return>]<
+            T,
+            $storage,
+            impl Fn() + Clone + Send + Sync,
+            impl Fn() + Clone + Send + Sync,
+        >
+        where
+            S: Into<Signal<T, $storage>>,
+            T: $($traits + )* Clone + 'static,
+        {
+            [<signal_ $fn_name _with_controls_and_options>](
+                value,
+                ms,
+                [<$filter_name:camel Options>]::default(),
+            )
+        }
+
+        /// Version of
+        #[$simple_func_doc]
+        /// that accepts
+        #[$options_doc]
+        #[doc="."]
+        #[track_caller]
+        pub fn [<signal_ $fn_name _with_controls_and_options>]<S, T>(
+            value: S,
+            ms: impl Into<Signal<f64>> + 'static,
+            options: [<$filter_name:camel Options>],
+        ) -> [<Signal $filter_name:camel d Return>]<
+            T,
+            $storage,
+            impl Fn() + Clone + Send + Sync,
+            impl Fn() + Clone + Send + Sync,
+        >
+        where
+            S: Into<Signal<T, $storage>>,
+            T: $($traits + )* Clone + 'static,
+        {
+            let value = value.into();
+
+            let (filtered, set_filtered) = ::leptos::prelude::RwSignal::<T, $storage>::new_with_storage(value.get_untracked()).split();
+            let (is_pending, set_pending) = signal(false);
+
+            let (filter, cancel_filter) =
+                $crate::utils::[<$filter_name _filter_with_cancel>](ms, options);
+
+            let apply = move || {
+                if is_pending.get_untracked() {
+                    set_pending.set(false);
+                    set_filtered.set(value.get_untracked());
+                }
+            };
+
+            let update = $crate::utils::create_filter_wrapper(::std::sync::Arc::new(filter), apply);
+
+            Effect::watch(
+                move || value.track(),
+                move |_, _, _| {
+                    set_pending.set(true);
+                    update();
+                },
+                false,
+            );
+
+            let flush = {
+                let cancel_filter = cancel_filter.clone();
+
+                move || {
+                    cancel_filter();
+                    apply();
+                }
+            };
+
+            let cancel = move || {
+                cancel_filter();
+                set_pending.set(false);
+            };
+
+            [<Signal $filter_name:camel d Return>] {
+                value: filtered.into(),
+                is_pending: is_pending.into(),
+                flush,
+                cancel,
+            }
+        }
+    }};
+}
+
+pub(crate) use {
+    signal_filtered, signal_filtered_local, signal_filtered_macro_impl,
+    signal_filtered_with_controls, signal_filtered_with_controls_macro_impl,
+};
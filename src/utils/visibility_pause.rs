@@ -0,0 +1,83 @@
+use default_struct_builder::DefaultBuilder;
+
+/// How a pausable function like [`fn@crate::use_interval_fn`], [`fn@crate::use_raf_fn`] or
+/// [`fn@crate::use_interval`] behaves while the document is hidden (e.g. the tab is in the background).
+///
+/// When the document becomes visible again, the timing is reset. An interval waits the full
+/// interval before the next call and the first animation frame reports a `delta` of `0`.
+/// With `catch_up` the time spent hidden is made up for instead. An interval calls the
+/// callback right away if at least one call was missed and the first animation frame reports
+/// the whole time since the last frame as `delta`.
+///
+/// ```
+/// # use leptos::prelude::*;
+/// # use leptos_use::{use_interval_fn_with_options, UseIntervalFnOptions};
+/// # use leptos_use::utils::UseVisibilityPause;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// use_interval_fn_with_options(
+///     || {
+///         // poll something
+///     },
+///     5000,
+///     UseIntervalFnOptions::default().visibility_pause(
+///         UseVisibilityPause::default()
+///             .pause_when_hidden(true)
+///             .catch_up(true),
+///     ),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+#[derive(DefaultBuilder, Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UseVisibilityPause {
+    /// Pause while the document is hidden and resume when it becomes visible again.
+    /// Only pauses that were caused by the document being hidden are resumed.
+    /// Defaults to `false`.
+    pub pause_when_hidden: bool,
+
+    /// Make up for the time spent hidden when resuming. Defaults to `false`.
+    pub catch_up: bool,
+}
+
+/// Pauses with `pause` when the document is hidden and resumes with `resume` when it becomes
+/// visible again. After resuming `catch_up` is called with the milliseconds spent hidden if
+/// the option `catch_up` is enabled.
+#[cfg(any(feature = "use_interval_fn", feature = "use_raf_fn"))]
+#[cfg_attr(feature = "ssr", allow(unused_variables))]
+pub(crate) fn use_visibility_pause(
+    options: UseVisibilityPause,
+    is_active: leptos::prelude::Signal<bool>,
+    pause: impl Fn() + 'static,
+    resume: impl Fn() + 'static,
+    catch_up: impl Fn(f64) + 'static,
+) {
+    #[cfg(not(feature = "ssr"))]
+    if options.pause_when_hidden {
+        use crate::core::now;
+        use crate::use_event_listener;
+        use leptos::ev::visibilitychange;
+        use leptos::prelude::*;
+        use std::cell::Cell;
+
+        // time when it was paused because of the document being hidden
+        let hidden_at = Cell::new(None::<f64>);
+
+        let _ = use_event_listener(document(), visibilitychange, move |_| {
+            if document().hidden() {
+                if is_active.get_untracked() {
+                    hidden_at.set(Some(now()));
+                    pause();
+                }
+            } else if let Some(hidden_at) = hidden_at.take() {
+                resume();
+
+                if options.catch_up {
+                    catch_up(now() - hidden_at);
+                }
+            }
+        });
+    }
+}
@@ -66,7 +66,7 @@ use leptos::prelude::*;
 ///
 /// On the server the callback
 /// will never be called except if you set `immediate` to `true` in which case the callback will be
-/// called exactly once.
+/// called exactly once. The returned stop function can still be called safely.
 ///
 /// ## See also
 ///
@@ -65,7 +65,7 @@ use default_struct_builder::DefaultBuilder;
 ///
 /// On the server the callback
 /// will never be called except if you set `immediate` to `true` in which case the callback will be
-/// called exactly once.
+/// called exactly once. The returned stop function can still be called safely.
 ///
 /// ## See also
 ///
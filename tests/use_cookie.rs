@@ -0,0 +1,93 @@
+#![cfg(all(feature = "ssr", feature = "use_cookie"))]
+
+use any_spawner::Executor;
+use codee::string::FromToStringCodec;
+use leptos::prelude::*;
+use leptos_use::{use_cookie_with_options, UseCookieOptions};
+use std::sync::{Arc, Mutex};
+
+/// Calls `use_cookie` like on the server with the given `Cookie` request header. `update` is
+/// called with the setter after the initial render. Returns the `Set-Cookie` headers that have
+/// been sent.
+async fn set_cookie_headers(
+    request_cookies: Option<&'static str>,
+    default_value: Option<u32>,
+    update: impl FnOnce(WriteSignal<Option<u32>>),
+) -> Vec<String> {
+    let _ = Executor::init_tokio();
+
+    let owner = Owner::new();
+    owner.set();
+
+    let headers = Arc::new(Mutex::new(vec![]));
+
+    tokio::task::LocalSet::new()
+        .run_until({
+            let headers = Arc::clone(&headers);
+
+            async move {
+                let (_, set_counter) = use_cookie_with_options::<u32, FromToStringCodec>(
+                    "counter",
+                    UseCookieOptions::<u32, _, _>::default()
+                        .default_value(default_value)
+                        .ssr_cookies_header_getter(move || request_cookies.map(str::to_string))
+                        .ssr_set_cookie(move |cookie| {
+                            headers.lock().unwrap().push(cookie.to_string());
+                        }),
+                );
+
+                settle().await;
+
+                update(set_counter);
+
+                settle().await;
+            }
+        })
+        .await;
+
+    drop(owner);
+
+    let headers = headers.lock().unwrap().clone();
+    headers
+}
+
+/// Gives the spawned effects the chance to run.
+async fn settle() {
+    for _ in 0..10 {
+        Executor::tick().await;
+    }
+}
+
+#[tokio::test]
+async fn default_value_sets_cookie() {
+    let headers = set_cookie_headers(None, Some(1), |_| {}).await;
+
+    assert_eq!(headers, vec!["counter=1".to_string()]);
+}
+
+#[tokio::test]
+async fn existing_cookie_is_not_set_again() {
+    let headers = set_cookie_headers(Some("counter=1"), Some(2), |_| {}).await;
+
+    assert!(headers.is_empty());
+}
+
+#[tokio::test]
+async fn unchanged_value_sends_no_set_cookie() {
+    let headers = set_cookie_headers(Some("counter=1"), None, |set_counter| {
+        set_counter.set(Some(1));
+    })
+    .await;
+
+    assert!(headers.is_empty());
+}
+
+#[tokio::test]
+async fn changed_value_sets_cookie() {
+    let headers = set_cookie_headers(Some("counter=1"), None, |set_counter| {
+        set_counter.set(Some(2));
+    })
+    .await;
+
+    assert_eq!(headers, vec!["counter=2".to_string()]);
+}
@@ -0,0 +1,145 @@
+#![cfg(all(feature = "use_websocket", not(feature = "ssr")))]
+
+use codee::string::FromToStringCodec;
+use leptos::prelude::*;
+use leptos_use::core::ConnectionReadyState;
+use leptos_use::{
+    use_websocket_with_options, ReconnectLimit, UseWebSocketOptions, UseWebSocketReturn,
+    WebSocketMessage, WebSocketTransport, WebSocketTransportHandlers,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Transport that records what is sent and lets the test fire the handlers by hand.
+#[derive(Clone, Default)]
+struct MockTransport {
+    state: Rc<RefCell<MockState>>,
+}
+
+thread_local! {
+    static TRANSPORT: MockTransport = MockTransport::default();
+}
+
+#[derive(Default)]
+struct MockState {
+    handlers: Option<WebSocketTransportHandlers>,
+    open: bool,
+    sent: Vec<String>,
+    urls: Vec<String>,
+}
+
+impl MockTransport {
+    fn with_handlers(&self, f: impl FnOnce(&WebSocketTransportHandlers)) {
+        let handlers = self
+            .state
+            .borrow_mut()
+            .handlers
+            .take()
+            .expect("not connected");
+        f(&handlers);
+        self.state.borrow_mut().handlers = Some(handlers);
+    }
+
+    fn server_opens(&self) {
+        self.state.borrow_mut().open = true;
+        self.with_handlers(|handlers| (handlers.on_open)(JsValue::NULL.unchecked_into()));
+    }
+
+    fn server_sends(&self, message: &str) {
+        self.with_handlers(|handlers| {
+            (handlers.on_message)(WebSocketMessage::Text(message.to_string()))
+        });
+    }
+}
+
+impl WebSocketTransport for MockTransport {
+    fn set_handlers(&self, handlers: WebSocketTransportHandlers) {
+        self.state.borrow_mut().handlers = Some(handlers);
+    }
+
+    fn send_text(&self, data: &str) -> Result<(), JsValue> {
+        self.state.borrow_mut().sent.push(data.to_string());
+        Ok(())
+    }
+
+    fn send_bytes(&self, _data: &[u8]) -> Result<(), JsValue> {
+        unreachable!("the string codec only sends text")
+    }
+
+    fn close(&self) -> Result<(), JsValue> {
+        let was_open = std::mem::take(&mut self.state.borrow_mut().open);
+        if was_open {
+            self.with_handlers(|handlers| (handlers.on_close)(JsValue::NULL.unchecked_into()));
+        }
+        Ok(())
+    }
+
+    fn is_open(&self) -> bool {
+        self.state.borrow().open
+    }
+}
+
+#[test]
+fn drives_use_websocket_through_a_mock_transport() {
+    let owner = Owner::new();
+    owner.set();
+
+    let transport = TRANSPORT.with(MockTransport::clone);
+
+    let UseWebSocketReturn {
+        ready_state,
+        message,
+        ws,
+        open,
+        close,
+        send,
+        ..
+    } = use_websocket_with_options::<String, String, FromToStringCodec, _, _>(
+        "ws://localhost:1234/socket",
+        UseWebSocketOptions::default()
+            .immediate(false)
+            .reconnect_limit(ReconnectLimit::Limited(0))
+            .socket_factory(|url, _| {
+                TRANSPORT.with(|transport| {
+                    transport.state.borrow_mut().urls.push(url.to_string());
+                    Ok(transport.clone())
+                })
+            }),
+    );
+
+    assert_eq!(ready_state.get_untracked(), ConnectionReadyState::Closed);
+
+    open();
+    assert_eq!(
+        transport.state.borrow().urls,
+        vec!["ws://localhost:1234/socket".to_string()]
+    );
+    assert_eq!(
+        ready_state.get_untracked(),
+        ConnectionReadyState::Connecting
+    );
+
+    // not sent before the connection is open
+    send(&"too early".to_string());
+    assert!(transport.state.borrow().sent.is_empty());
+
+    transport.server_opens();
+    assert_eq!(ready_state.get_untracked(), ConnectionReadyState::Open);
+    assert!(ws.get_untracked().is_none());
+
+    send(&"hello".to_string());
+    assert_eq!(transport.state.borrow().sent, vec!["hello".to_string()]);
+
+    transport.server_sends("world");
+    assert_eq!(message.get_untracked(), Some("world".to_string()));
+
+    close();
+    assert!(!transport.is_open());
+    assert_eq!(ready_state.get_untracked(), ConnectionReadyState::Closed);
+
+    send(&"too late".to_string());
+    assert_eq!(transport.state.borrow().sent, vec!["hello".to_string()]);
+
+    owner.unset();
+}